@@ -0,0 +1,87 @@
+use criterion::{
+    criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, BenchmarkId, Criterion,
+    Throughput,
+};
+use mycrc::{
+    catalog::{CRC_32_ISCSI, CRC_64_XZ},
+    Algorithm, CrcWidth, CreateTable, Hardware, Interleaved, Slice16, Slice8, CRC,
+};
+use std::hint::black_box;
+
+const SIZES: [usize; 3] = [64, 4 * 1024, 1024 * 1024];
+
+/// Bench the checksum of `bytes` with the table `B`.
+fn bench_table<T: CrcWidth, B: CreateTable<T>>(
+    group: &mut BenchmarkGroup<WallTime>,
+    name: &str,
+    algorithm: Algorithm<T>,
+    bytes: &[u8],
+) {
+    let mut crc = CRC::<T, B>::create(algorithm);
+    group.bench_with_input(BenchmarkId::new(name, bytes.len()), bytes, |b, bytes| {
+        b.iter(|| crc.checksum(black_box(bytes)))
+    });
+}
+
+/// Bench each table with `algorithm`.
+fn bench_tables<T: CrcWidth>(c: &mut Criterion, name: &str, algorithm: Algorithm<T>)
+where
+    Interleaved<T>: CreateTable<T>,
+    Hardware<T>: CreateTable<T>,
+{
+    let mut group = c.benchmark_group(name);
+    for size in SIZES {
+        let bytes = vec![0x5a; size];
+        group.throughput(Throughput::Bytes(size as u64));
+        bench_table::<T, [T; 256]>(&mut group, "byte", algorithm, &bytes);
+        bench_table::<T, Slice8<T>>(&mut group, "slice8", algorithm, &bytes);
+        bench_table::<T, Slice16<T>>(&mut group, "slice16", algorithm, &bytes);
+        bench_table::<T, Interleaved<T>>(&mut group, "interleaved", algorithm, &bytes);
+        bench_table::<T, Hardware<T>>(&mut group, "hardware", algorithm, &bytes);
+    }
+    group.finish();
+}
+
+/// Bench slicing-by-8 with byte reads against `u64` word reads.
+fn bench_words(c: &mut Criterion) {
+    let algorithm = CRC_64_XZ;
+    let tables =
+        Algorithm::<u64>::create_tables::<8>(algorithm.width, algorithm.poly, algorithm.refin);
+    let mut group = c.benchmark_group("CRC-64/XZ/slice8");
+    for size in SIZES {
+        let bytes = vec![0x5a; size];
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(BenchmarkId::new("bytes", size), &bytes, |b, bytes| {
+            b.iter(|| {
+                Algorithm::<u64>::calc_bytes_with_tables(
+                    algorithm.refin,
+                    algorithm.init,
+                    black_box(bytes),
+                    &tables,
+                )
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("words", size), &bytes, |b, bytes| {
+            b.iter(|| {
+                Algorithm::<u64>::calc_bytes_with_words(
+                    algorithm.refin,
+                    algorithm.init,
+                    black_box(bytes),
+                    &tables,
+                )
+            })
+        });
+    }
+    group.finish();
+}
+
+fn crc32(c: &mut Criterion) {
+    bench_tables(c, "CRC-32/ISCSI", CRC_32_ISCSI);
+}
+
+fn crc64(c: &mut Criterion) {
+    bench_tables(c, "CRC-64/XZ", CRC_64_XZ);
+}
+
+criterion_group!(benches, crc32, crc64, bench_words);
+criterion_main!(benches);
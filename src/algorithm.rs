@@ -1,19 +1,32 @@
-use core::mem;
+use crate::{gf2::Poly, ChecksumBytes, CrcWidth, ParamError, SelfTestError, CRC};
+use core::{convert::TryInto, mem};
 
 /// CRC algorithm.
+///
+/// With the `serde` feature, only the parameters are deserialized and `check` and `residue` are computed from them,
+/// so invalid parameters are an error.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Algorithm<T> {
     pub endian: Endian,
+    pub width: u8,
     pub poly: T,
     pub init: T,
     pub refin: bool,
     pub refout: bool,
     pub xorout: T,
+    pub check: T,
     pub residue: T,
 }
 
+/// Message used to compute `check` of [`Algorithm`].
+pub const CHECK_BYTES: &[u8] = b"123456789";
+
 /// Endianness
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Endian {
     /// big-endian (BE)
     Big,
@@ -23,9 +36,192 @@ pub enum Endian {
     Native,
 }
 
+impl Endian {
+    /// Returns `true` if the most significant byte is first, with [`Endian::Native`] of the target.
+    pub const fn is_big(self) -> bool {
+        match self {
+            Self::Big => true,
+            Self::Little => false,
+            Self::Native => cfg!(target_endian = "big"),
+        }
+    }
+}
+
+impl<T> Algorithm<T> {
+    /// Returns `true` if the checksum in `endian` bytes continues the bit order of the message,
+    /// i.e. big-endian unless `refout`.
+    ///
+    /// Only then the value after error-free bytes [message + checksum] is `residue`.
+    /// Otherwise the value depends on the message, so the checksum has to be compared instead.
+    pub const fn is_residue_endian(&self) -> bool {
+        self.endian.is_big() != self.refout
+    }
+}
+
+/// Algorithm of any width.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AnyAlgorithm {
+    /// `u8` algorithm
+    U8(Algorithm<u8>),
+    /// `u16` algorithm
+    U16(Algorithm<u16>),
+    /// `u32` algorithm
+    U32(Algorithm<u32>),
+    /// `u64` algorithm
+    U64(Algorithm<u64>),
+    /// `u128` algorithm
+    U128(Algorithm<u128>),
+}
+
+impl AnyAlgorithm {
+    /// Width of the algorithm.
+    pub const fn width(&self) -> u8 {
+        match self {
+            Self::U8(algorithm) => algorithm.width,
+            Self::U16(algorithm) => algorithm.width,
+            Self::U32(algorithm) => algorithm.width,
+            Self::U64(algorithm) => algorithm.width,
+            Self::U128(algorithm) => algorithm.width,
+        }
+    }
+
+    /// Check of the algorithm.
+    pub const fn check(&self) -> u128 {
+        match self {
+            Self::U8(algorithm) => algorithm.check as u128,
+            Self::U16(algorithm) => algorithm.check as u128,
+            Self::U32(algorithm) => algorithm.check as u128,
+            Self::U64(algorithm) => algorithm.check as u128,
+            Self::U128(algorithm) => algorithm.check,
+        }
+    }
+
+    /// Residue of the algorithm.
+    pub const fn residue(&self) -> u128 {
+        match self {
+            Self::U8(algorithm) => algorithm.residue as u128,
+            Self::U16(algorithm) => algorithm.residue as u128,
+            Self::U32(algorithm) => algorithm.residue as u128,
+            Self::U64(algorithm) => algorithm.residue as u128,
+            Self::U128(algorithm) => algorithm.residue,
+        }
+    }
+
+    /// Checksum of error-free bytes, see [`Algorithm::magic`].
+    pub const fn magic(&self) -> u128 {
+        match self {
+            Self::U8(algorithm) => algorithm.magic() as u128,
+            Self::U16(algorithm) => algorithm.magic() as u128,
+            Self::U32(algorithm) => algorithm.magic() as u128,
+            Self::U64(algorithm) => algorithm.magic() as u128,
+            Self::U128(algorithm) => algorithm.magic(),
+        }
+    }
+
+    /// The algorithm as `Algorithm<u64>`, e.g. for bindings of one type.
+    /// Returns `None` if it is `u128`.
+    pub fn to_u64(&self) -> Option<Algorithm<u64>> {
+        fn widen<T: Copy + Into<u64>>(algorithm: &Algorithm<T>) -> Algorithm<u64> {
+            Algorithm {
+                endian: algorithm.endian,
+                width: algorithm.width,
+                poly: algorithm.poly.into(),
+                init: algorithm.init.into(),
+                refin: algorithm.refin,
+                refout: algorithm.refout,
+                xorout: algorithm.xorout.into(),
+                check: algorithm.check.into(),
+                residue: algorithm.residue.into(),
+            }
+        }
+
+        match self {
+            Self::U8(algorithm) => Some(widen(algorithm)),
+            Self::U16(algorithm) => Some(widen(algorithm)),
+            Self::U32(algorithm) => Some(widen(algorithm)),
+            Self::U64(algorithm) => Some(*algorithm),
+            Self::U128(_) => None,
+        }
+    }
+
+    /// Checksum function.
+    /// Creates the table every time it is called.
+    pub fn checksum(&self, bytes: &[u8]) -> u128 {
+        fn checksum<T: CrcWidth>(algorithm: &Algorithm<T>, bytes: &[u8]) -> u128 {
+            let mut crc = CRC {
+                algorithm: *algorithm,
+                value: T::initialize(algorithm),
+                table: T::create_table(algorithm),
+                len: 0,
+            };
+            crc.checksum(bytes).into()
+        }
+
+        match self {
+            Self::U8(algorithm) => checksum(algorithm, bytes),
+            Self::U16(algorithm) => checksum(algorithm, bytes),
+            Self::U32(algorithm) => checksum(algorithm, bytes),
+            Self::U64(algorithm) => checksum(algorithm, bytes),
+            Self::U128(algorithm) => checksum(algorithm, bytes),
+        }
+    }
+}
+
+/// Polynomial notation
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Notation {
+    /// normal (implicit `x^width`, e.g. `0x04c11db7` for CRC-32)
+    Normal,
+    /// reversed (e.g. `0xedb88320` for CRC-32)
+    Reversed,
+    /// reciprocal (e.g. `0xdb710641` for CRC-32)
+    Reciprocal,
+    /// reversed reciprocal, also known as Koopman notation (implicit `+1`, e.g. `0x82608edb` for CRC-32)
+    Koopman,
+}
+
+/// Placement of the checksum in a frame, for [`CRC::verify_frame`].
+///
+/// The checksum covers the bytes of the frame other than the checksum itself.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ChecksumLayout {
+    /// Byte order of the checksum.
+    pub endian: Endian,
+    /// Offset of the checksum in the frame.
+    pub offset: usize,
+}
+
+/// Conventions of the register of a CRC peripheral, e.g. of a microcontroller.
+///
+/// The register is aligned to the least significant bit.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct HwRegister {
+    /// `x^(width - 1)` is in the least significant bit.
+    pub reflected: bool,
+    /// `xorout` is applied to the register, as to a checksum.
+    pub xorout: bool,
+}
+
+/// Error detection guaranteed by a polynomial, see [`Algorithm::burst_detection`].
+///
+/// Of the other bursts, an algorithm misses `2^-(max_burst - 1)` of those of `max_burst + 1` bits
+/// and `2^-max_burst` of longer ones.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BurstDetection {
+    /// All bursts of at most this many bits are detected, which is `width` if `poly` has the `x^0` term.
+    pub max_burst: u8,
+    /// All errors of an odd number of bits are detected, since `x + 1` divides the polynomial.
+    pub odd_errors: bool,
+}
+
 macro_rules! algorithm_impl {
     ( $( $t:ty ),* ) => ($(
         impl Algorithm<$t> {
+            /// Create algorithm of `<$t>::BITS` bits.
             pub const fn new(
                 endian: Endian,
                 poly: $t,
@@ -34,23 +230,37 @@ macro_rules! algorithm_impl {
                 refout: bool,
                 xorout: $t,
             ) -> (Self, $t, [$t; 256]) {
-                let init_value = Self::initialize(init, refin);
-                // 0 bytes checksum
-                let zero_bytes_checksum = Self::finalize_to_endian_bytes(Endian::Little, refin, refout, xorout, init_value);
+                Self::new_with_width(endian, <$t>::BITS as u8, poly, init, refin, refout, xorout)
+            }
+
+            /// Create algorithm of `width` bits.
+            pub const fn new_with_width(
+                endian: Endian,
+                width: u8,
+                poly: $t,
+                init: $t,
+                refin: bool,
+                refout: bool,
+                xorout: $t,
+            ) -> (Self, $t, [$t; 256]) {
+                let init_value = Self::initialize_with_width(width, init, refin);
                 // Create table
-                let table = Self::create_table(poly, refin);
-                // Caluculate residue.
-                let calc_value = Self::calc_bytes_with_values(refin, init_value, &zero_bytes_checksum, &table);
-                let residue = Self::optional_reflection(refin, refout, calc_value);
+                let table = Self::create_table_with_width(width, poly, refin);
+                // Calculate check.
+                let check = Self::calc_check(width, init_value, refin, refout, xorout, &table);
+                // Calculate residue.
+                let residue = Self::residue(width, poly, refout, xorout);
 
                 (
                     Self {
                         endian,
+                        width,
                         poly,
                         init,
                         refin,
                         refout,
                         xorout,
+                        check,
                         residue,
                     },
                     init_value,
@@ -58,7 +268,122 @@ macro_rules! algorithm_impl {
                 )
             }
 
-            // To endian bytes.
+            /// Fallible [`new_with_width`](Self::new_with_width) that rejects parameters which are not of a CRC of `width` bits.
+            ///
+            /// `check` and `residue` are computed from the parameters, so they always match them.
+            pub const fn try_new(
+                endian: Endian,
+                width: u8,
+                poly: $t,
+                init: $t,
+                refin: bool,
+                refout: bool,
+                xorout: $t,
+            ) -> Result<(Self, $t, [$t; 256]), ParamError> {
+                if width == 0 || width as u32 > <$t>::BITS {
+                    return Err(ParamError::Width(width));
+                }
+                let mask = Self::mask(width);
+                if poly == 0 {
+                    Err(ParamError::ZeroPoly)
+                } else if poly & 1 == 0 {
+                    Err(ParamError::EvenPoly)
+                } else if poly & !mask != 0 {
+                    Err(ParamError::WidePoly)
+                } else if init & !mask != 0 {
+                    Err(ParamError::WideInit)
+                } else if xorout & !mask != 0 {
+                    Err(ParamError::WideXorout)
+                } else {
+                    Ok(Self::new_with_width(endian, width, poly, init, refin, refout, xorout))
+                }
+            }
+
+            /// Create algorithm from a polynomial in any [`Notation`].
+            #[allow(clippy::too_many_arguments)]
+            pub const fn new_with_notation(
+                endian: Endian,
+                width: u8,
+                poly: $t,
+                notation: Notation,
+                init: $t,
+                refin: bool,
+                refout: bool,
+                xorout: $t,
+            ) -> (Self, $t, [$t; 256]) {
+                let poly = Self::poly_to_normal(width, poly, notation);
+                Self::new_with_width(endian, width, poly, init, refin, refout, xorout)
+            }
+
+            /// Polynomial in reversed notation.
+            pub const fn poly_reversed(&self) -> $t {
+                Self::reflect(self.width, self.poly)
+            }
+
+            /// Polynomial in reciprocal notation.
+            pub const fn poly_reciprocal(&self) -> $t {
+                Self::poly_from_normal(self.width, self.poly, Notation::Reciprocal)
+            }
+
+            /// Convert polynomial from normal notation to `notation`.
+            pub const fn poly_from_normal(width: u8, poly: $t, notation: Notation) -> $t {
+                match notation {
+                    Notation::Normal => poly,
+                    Notation::Reversed => Self::reflect(width, poly),
+                    Notation::Reciprocal => ((Self::reflect(width, poly) << 1) | 1) & Self::mask(width),
+                    Notation::Koopman => Self::to_koopman(width, poly),
+                }
+            }
+
+            /// Convert polynomial from `notation` to normal notation.
+            pub const fn poly_to_normal(width: u8, poly: $t, notation: Notation) -> $t {
+                match notation {
+                    Notation::Normal => poly,
+                    Notation::Reversed => Self::reflect(width, poly),
+                    Notation::Reciprocal => Self::reflect(width, Self::to_koopman(width, poly)),
+                    Notation::Koopman => Self::from_koopman(poly),
+                }
+            }
+
+            /// Reflect the least significant `width` bits of `value`.
+            pub const fn reflect(width: u8, value: $t) -> $t {
+                (value << Self::shift(width)).reverse_bits()
+            }
+
+            /// Mask of the least significant `width` bits.
+            pub const fn mask(width: u8) -> $t {
+                <$t>::MAX >> Self::shift(width)
+            }
+
+            /// Number of bits between `width` and the most significant bit of the register.
+            pub const fn shift(width: u8) -> u32 {
+                <$t>::BITS - width as u32
+            }
+
+            /// Convert polynomial from Koopman notation (implicit `+1`) to normal notation (implicit `x^width`).
+            ///
+            /// The width is the number of bits of `koopman`. See [`Self::koopman_width`].
+            pub const fn from_koopman(koopman: $t) -> $t {
+                let width = Self::koopman_width(koopman) as u32;
+                let poly = (koopman << 1) | 1;
+                if width < <$t>::BITS {
+                    poly & !(1 << width)
+                } else {
+                    poly
+                }
+            }
+
+            /// Width of polynomial in Koopman notation.
+            pub const fn koopman_width(koopman: $t) -> u8 {
+                (<$t>::BITS - koopman.leading_zeros()) as u8
+            }
+
+            /// Convert polynomial from normal notation to Koopman notation.
+            pub const fn to_koopman(width: u8, poly: $t) -> $t {
+                (poly >> 1) | (1 << (width - 1))
+            }
+
+            /// To endian bytes of `<$t>::BITS` bits.
             pub const fn to_endian_bytes(n: $t, endian: Endian) -> [u8; mem::size_of::<$t>()] {
                 match endian {
                     Endian::Big => n.to_be_bytes(),
@@ -67,37 +392,303 @@ macro_rules! algorithm_impl {
                 }
             }
 
-            /// Initialize value.
+            /// To endian bytes of `width` bits.
+            /// Returns `(width + 7) / 8` bytes.
+            pub const fn to_endian_bytes_with_width(n: $t, width: u8, endian: Endian) -> ChecksumBytes<{ mem::size_of::<$t>() }> {
+                let len = (width as usize).div_ceil(8);
+                let big = endian.is_big();
+
+                let mut bytes = [0; mem::size_of::<$t>()];
+                let mut i = 0;
+                while i < len {
+                    // `i`-th least significant byte.
+                    let byte = (n >> (8 * i)) as u8;
+                    if big {
+                        bytes[len - 1 - i] = byte;
+                    } else {
+                        bytes[i] = byte;
+                    }
+                    i += 1;
+                }
+
+                ChecksumBytes::new(bytes, len)
+            }
+
+            /// From endian bytes, the inverse of [`to_endian_bytes_with_width`](Self::to_endian_bytes_with_width).
+            ///
+            /// # Panics
+            /// Panics if `bytes` are longer than the type.
+            pub const fn from_endian_bytes(bytes: &[u8], endian: Endian) -> $t {
+                let len = bytes.len();
+                assert!(len <= mem::size_of::<$t>(), "bytes are longer than the type");
+                let big = endian.is_big();
+
+                let mut n = 0;
+                let mut i = 0;
+                while i < len {
+                    // `i`-th byte is the `k`-th least significant byte.
+                    let k = if big { len - 1 - i } else { i };
+                    n |= (bytes[i] as $t) << (8 * k);
+                    i += 1;
+                }
+                n
+            }
+
+            /// Initialize value of `<$t>::BITS` bits.
             pub const fn initialize(init: $t, refin: bool) -> $t {
+                Self::initialize_with_width(<$t>::BITS as u8, init, refin)
+            }
+
+            /// Initialize value.
+            ///
+            /// If `refin` is `true`, the value is reflected over `width` bits and kept in the least significant bits.
+            /// Otherwise, it is kept in the most significant bits.
+            pub const fn initialize_with_width(width: u8, init: $t, refin: bool) -> $t {
+                let value = init << Self::shift(width);
                 if refin {
-                    init.reverse_bits()
+                    value.reverse_bits()
                 } else {
-                    init
+                    value
                 }
             }
 
-            /// Optional reflection.
+            /// Optional reflection of `<$t>::BITS` bits.
             pub const fn optional_reflection(refin: bool, refout: bool, value: $t) -> $t {
-                if refin ^ refout {
+                Self::optional_reflection_with_width(<$t>::BITS as u8, refin, refout, value)
+            }
+
+            /// Optional reflection.
+            /// The result is aligned to the least significant bit.
+            pub const fn optional_reflection_with_width(width: u8, refin: bool, refout: bool, value: $t) -> $t {
+                let value = if refin ^ refout {
                     value.reverse_bits()
                 } else {
                     value
+                };
+                if refout {
+                    value
+                } else {
+                    value >> Self::shift(width)
                 }
             }
 
-            /// Finalize value.
+            /// Register of `width` bits from value, without `xorout`.
+            ///
+            /// The register is reflected (`x^(width - 1)` in the least significant bit) if `refin`,
+            /// and normal (`x^(width - 1)` in bit `width - 1`) otherwise.
+            /// Either way, it is aligned to the least significant bit.
+            pub const fn raw_value(width: u8, refin: bool, value: $t) -> $t {
+                if refin {
+                    value
+                } else {
+                    value >> Self::shift(width)
+                }
+            }
+
+            /// Inverse of [`raw_value`](Self::raw_value).
+            /// Bits above `width` are ignored.
+            pub const fn from_raw_value(width: u8, refin: bool, raw: $t) -> $t {
+                if refin {
+                    raw & Self::mask(width)
+                } else {
+                    raw << Self::shift(width)
+                }
+            }
+
+            /// Register of a peripheral with the conventions of `register` from value.
+            pub const fn to_hw_register(&self, value: $t, register: HwRegister) -> $t {
+                let raw = Self::raw_value(self.width, self.refin, value);
+                let raw = if register.reflected == self.refin {
+                    raw
+                } else {
+                    Self::reflect(self.width, raw)
+                };
+                raw ^ self.hw_xorout(register)
+            }
+
+            /// Inverse of [`to_hw_register`](Self::to_hw_register).
+            pub const fn from_hw_register(&self, hw_register: $t, register: HwRegister) -> $t {
+                let raw = (hw_register ^ self.hw_xorout(register)) & Self::mask(self.width);
+                let raw = if register.reflected == self.refin {
+                    raw
+                } else {
+                    Self::reflect(self.width, raw)
+                };
+                Self::from_raw_value(self.width, self.refin, raw)
+            }
+
+            /// `xorout` in the bit order of `register`.
+            const fn hw_xorout(&self, register: HwRegister) -> $t {
+                if !register.xorout {
+                    0
+                } else if register.reflected == self.refout {
+                    self.xorout
+                } else {
+                    Self::reflect(self.width, self.xorout)
+                }
+            }
+
+            /// Finalize value of `<$t>::BITS` bits.
             /// Change value to checksum.
             pub const fn finalize(refin: bool, refout: bool, xorout: $t, value: $t) -> $t {
-                Self::optional_reflection(refin, refout, value) ^ xorout
+                Self::finalize_with_width(<$t>::BITS as u8, refin, refout, xorout, value)
+            }
+
+            /// Finalize value.
+            /// Change value to checksum.
+            pub const fn finalize_with_width(width: u8, refin: bool, refout: bool, xorout: $t, value: $t) -> $t {
+                Self::optional_reflection_with_width(width, refin, refout, value) ^ xorout
+            }
+
+            /// Inverse of [`finalize_with_width`](Self::finalize_with_width).
+            /// Change checksum to value, so that the calculation can be resumed.
+            /// Bits of `checksum` outside `width` are ignored.
+            pub const fn unfinalize(width: u8, refin: bool, refout: bool, xorout: $t, checksum: $t) -> $t {
+                let value = (checksum ^ xorout) & Self::mask(width);
+                let value = if refout {
+                    value
+                } else {
+                    value << Self::shift(width)
+                };
+                if refin ^ refout {
+                    value.reverse_bits()
+                } else {
+                    value
+                }
+            }
+
+            /// Finalize value of `<$t>::BITS` bits to endian bytes.
+            pub const fn finalize_to_endian_bytes(endian: Endian, refin: bool, refout: bool, xorout: $t, value: $t) -> ChecksumBytes<{ mem::size_of::<$t>() }> {
+                Self::finalize_to_endian_bytes_with_width(endian, <$t>::BITS as u8, refin, refout, xorout, value)
             }
 
             /// Finalize to endian bytes.
-            pub const fn finalize_to_endian_bytes(endian: Endian, refin: bool, refout: bool, xorout: $t, value: $t) -> [u8; mem::size_of::<$t>()] {
-                let finalize = Self::finalize(refin, refout, xorout, value);
-                Self::to_endian_bytes(finalize, endian)
+            pub const fn finalize_to_endian_bytes_with_width(endian: Endian, width: u8, refin: bool, refout: bool, xorout: $t, value: $t) -> ChecksumBytes<{ mem::size_of::<$t>() }> {
+                let finalize = Self::finalize_with_width(width, refin, refout, xorout, value);
+                Self::to_endian_bytes_with_width(finalize, width, endian)
+            }
+
+            /// Calculate check.
+            /// The check is the checksum of [`CHECK_BYTES`].
+            pub const fn calc_check(width: u8, init_value: $t, refin: bool, refout: bool, xorout: $t, table: &[$t; 256]) -> $t {
+                let value = Self::calc_bytes_with_values(refin, init_value, CHECK_BYTES, table);
+                Self::finalize_with_width(width, refin, refout, xorout, value)
+            }
+
+            /// Assert that `check` is the checksum of [`CHECK_BYTES`].
+            ///
+            /// In const context, mis-typed parameters fail at compile time.
+            ///
+            /// # Panics
+            /// Panics if `check` is not equal to the checksum of [`CHECK_BYTES`].
+            pub const fn assert_check(self) -> Self {
+                let init_value = Self::initialize_with_width(self.width, self.init, self.refin);
+                let table = Self::create_table_with_width(self.width, self.poly, self.refin);
+                let check = Self::calc_check(self.width, init_value, self.refin, self.refout, self.xorout, &table);
+                assert!(check == self.check, "check is not equal to the checksum of CHECK_BYTES");
+                self
+            }
+
+            /// Recompute `check` and `residue`, and compare them with the fields.
+            ///
+            /// Useful for startup self-checks.
+            pub const fn self_test(&self) -> Result<(), SelfTestError<$t>> {
+                let init_value = Self::initialize_with_width(self.width, self.init, self.refin);
+                let table = Self::create_table_with_width(self.width, self.poly, self.refin);
+                let check = Self::calc_check(self.width, init_value, self.refin, self.refout, self.xorout, &table);
+                if check != self.check {
+                    return Err(SelfTestError::Check {
+                        expected: self.check,
+                        actual: check,
+                    });
+                }
+
+                let residue = Self::residue(self.width, self.poly, self.refout, self.xorout);
+                if residue != self.residue {
+                    return Err(SelfTestError::Residue {
+                        expected: self.residue,
+                        actual: residue,
+                    });
+                }
+
+                Ok(())
+            }
+
+            /// Checksum of error-free bytes [message + checksum], e.g. `0x2144df1c` for CRC-32/ISO-HDLC.
+            ///
+            /// This is `residue` with `xorout`, for verifying by recomputing the checksum over the whole bytes.
+            /// The checksum must be appended as of [`is_residue_endian`](Self::is_residue_endian), and `width` must be a multiple of 8.
+            pub const fn magic(&self) -> $t {
+                self.residue ^ self.xorout
+            }
+
+            /// Error detection guaranteed by the polynomial, e.g. bursts of up to 32 bits for CRC-32.
+            ///
+            /// A burst is the bits from the first to the last error.
+            pub const fn burst_detection(&self) -> BurstDetection {
+                // Bursts are `x^i` times a polynomial with the `x^0` term,
+                // which is a multiple of the polynomial only if it is of higher degree than the polynomial without `x^i` factors.
+                let max_burst = if self.poly == 0 {
+                    0
+                } else {
+                    self.width - self.poly.trailing_zeros() as u8
+                };
+                BurstDetection {
+                    max_burst,
+                    // The polynomial with the `x^width` term has an even number of terms.
+                    odd_errors: self.poly.count_ones() % 2 == 1,
+                }
+            }
+
+            /// Calculate residue.
+            /// The residue is the value after calculating error-free bytes [message + checksum], without `xorout`.
+            pub const fn residue(width: u8, poly: $t, refout: bool, xorout: $t) -> $t {
+                let shift = Self::shift(width);
+                let poly = poly << shift;
+                let mut value = xorout << shift;
+
+                let mut i = 0;
+                while i < width {
+                    if value >> (<$t>::BITS - 1) == 0 {
+                        value <<= 1;
+                    } else {
+                        value = (value << 1) ^ poly;
+                    }
+                    i += 1;
+                }
+
+                if refout {
+                    value.reverse_bits()
+                } else {
+                    value >> shift
+                }
             }
 
-            /// Caluculate byte with reciprocal polynomial.
+            /// Calculate bit with value.
+            ///
+            /// Bits are processed in the order they are given, regardless of `refin`.
+            /// `refin` only decides how the value is held (See [`Self::initialize`]).
+            pub const fn calc_bit_with_value(width: u8, poly: $t, refin: bool, value: $t, bit: bool) -> $t {
+                if refin {
+                    let feedback = (value & 1 == 1) ^ bit;
+                    let value = value >> 1;
+                    if feedback {
+                        value ^ Self::reflect(width, poly)
+                    } else {
+                        value
+                    }
+                } else {
+                    let feedback = (value >> (<$t>::BITS - 1) == 1) ^ bit;
+                    let value = value << 1;
+                    if feedback {
+                        value ^ (poly << Self::shift(width))
+                    } else {
+                        value
+                    }
+                }
+            }
+
+            /// Calculate byte with reciprocal polynomial.
             pub const fn calc_byte_with_reciprocal_poly(reciprocal_poly: $t, refin: bool, byte: u8) -> $t {
                 let mut value = if refin {
                     byte as $t
@@ -123,38 +714,1070 @@ macro_rules! algorithm_impl {
                 }
             }
 
-            /// Create table.
+            /// Table of the algorithm as contiguous bytes with each entry in `endian`, e.g. to load a CRC or DMA engine.
+            ///
+            /// Entries are of [`Self::create_table`], in all bytes of the type.
+            pub const fn table_as_bytes(&self, endian: Endian) -> [u8; 256 * <$t>::BITS as usize / 8] {
+                const SIZE: usize = <$t>::BITS as usize / 8;
+                let table = Self::create_table_with_width(self.width, self.poly, self.refin);
+                let mut bytes = [0; 256 * SIZE];
+                let mut i = 0;
+                while i < 256 {
+                    let entry = if endian.is_big() {
+                        table[i].to_be_bytes()
+                    } else {
+                        table[i].to_le_bytes()
+                    };
+                    let mut j = 0;
+                    while j < SIZE {
+                        bytes[i * SIZE + j] = entry[j];
+                        j += 1;
+                    }
+                    i += 1;
+                }
+                bytes
+            }
+
+            /// Create table of `<$t>::BITS` bits.
             pub const fn create_table(poly: $t, refin: bool) -> [$t; 256] {
+                Self::create_table_with_width(<$t>::BITS as u8, poly, refin)
+            }
+
+            /// Create table.
+            ///
+            /// Only the 8 entries of single bits are shifted, one shift each from the previous one.
+            /// The others are XORs of them, since `table[i ^ j] == table[i] ^ table[j]`.
+            pub const fn create_table_with_width(width: u8, poly: $t, refin: bool) -> [$t; 256] {
                 let mut table = [0; 256];
-                let reciprocal_poly = poly.reverse_bits();
+
+                if refin {
+                    let reciprocal_poly = (poly << Self::shift(width)).reverse_bits();
+                    let mut value = reciprocal_poly;
+                    let mut bit = 0x80;
+                    while bit != 0 {
+                        table[bit] = value;
+                        value = if value & 1 == 1 {
+                            (value >> 1) ^ reciprocal_poly
+                        } else {
+                            value >> 1
+                        };
+                        bit >>= 1;
+                    }
+                } else {
+                    let poly = poly << Self::shift(width);
+                    let mut value = poly;
+                    let mut bit = 0x01;
+                    while bit != 0x100 {
+                        table[bit] = value;
+                        value = if value >> (<$t>::BITS - 1) == 1 {
+                            (value << 1) ^ poly
+                        } else {
+                            value << 1
+                        };
+                        bit <<= 1;
+                    }
+                }
+
+                let mut bit = 0x02;
+                while bit != 0x100 {
+                    let mut i = 1;
+                    while i < bit {
+                        table[bit | i] = table[bit] ^ table[i];
+                        i += 1;
+                    }
+                    bit <<= 1;
+                }
+
+                table
+            }
+
+            /// Calculate byte with values.
+            pub const fn calc_byte_with_values(refin: bool, value: $t, byte: u8, table: &[$t; 256]) -> $t {
+                if refin {
+                    // Shift in two steps so that `u8` doesn't overflow.
+                    table[(value as usize ^ byte as usize) & 0xFF] ^ (value >> 4 >> 4)
+                } else {
+                    table[((value >> (mem::size_of::<$t>() * 8 - 8)) as usize ^ byte as usize) & 0xFF] ^ (value << 4 << 4)
+                }
+            }
+
+            /// Calculate bytes with values.
+            pub const fn calc_bytes_with_values(refin: bool, mut value: $t, bytes: &[u8], table: &[$t; 256]) -> $t {
+                let mut i = 0;
+                while i < bytes.len() {
+                    value = Self::calc_byte_with_values(refin, value, bytes[i], table);
+                    i += 1;
+                }
+                value
+            }
+
+            /// Create tables for slicing-by-`N`.
+            ///
+            /// `tables[k][i]` is the value of byte `i` followed by `k` zero bytes, so `tables[0]` is the table of [`create_table_with_width`](Self::create_table_with_width).
+            pub const fn create_tables<const N: usize>(width: u8, poly: $t, refin: bool) -> [[$t; 256]; N] {
+                let mut tables = [[0; 256]; N];
+                if N == 0 {
+                    return tables;
+                }
+                tables[0] = Self::create_table_with_width(width, poly, refin);
+
+                let mut k = 1;
+                while k < N {
+                    let mut i = 0;
+                    while i < 256 {
+                        tables[k][i] = Self::calc_bytes_with_values(refin, tables[k - 1][i], &[0], &tables[0]);
+                        i += 1;
+                    }
+                    k += 1;
+                }
+
+                tables
+            }
+
+            /// Calculate bytes with slicing-by-`N` tables, `N` bytes per iteration.
+            ///
+            /// `N` must be at least 1.
+            pub const fn calc_bytes_with_tables<const N: usize>(refin: bool, mut value: $t, bytes: &[u8], tables: &[[$t; 256]; N]) -> $t {
+                const SIZE: usize = mem::size_of::<$t>();
+
+                let mut i = 0;
+                while i + N <= bytes.len() {
+                    // Part of the value which is not consumed by `N` bytes.
+                    let mut next: $t = 0;
+                    if N < SIZE {
+                        next = if refin {
+                            value >> (8 * N)
+                        } else {
+                            value << (8 * N)
+                        };
+                    }
+
+                    let mut j = 0;
+                    while j < N {
+                        let mut byte = bytes[i + j];
+                        if j < SIZE {
+                            byte ^= if refin {
+                                (value >> (8 * j)) as u8
+                            } else {
+                                (value >> (8 * (SIZE - 1 - j))) as u8
+                            };
+                        }
+                        next ^= tables[N - 1 - j][byte as usize];
+                        j += 1;
+                    }
+
+                    value = next;
+                    i += N;
+                }
+
+                let (_, tail) = bytes.split_at(i);
+                Self::calc_bytes_with_values(refin, value, tail, &tables[0])
+            }
+
+            /// Calculate bytes with slicing-by-8 tables, reading the aligned middle as `u64` words.
+            ///
+            /// The unaligned head and the tail go through [`calc_bytes_with_values`](Self::calc_bytes_with_values).
+            pub fn calc_bytes_with_words(refin: bool, mut value: $t, bytes: &[u8], tables: &[[$t; 256]; 8]) -> $t {
+                let head = bytes.len().min(bytes.as_ptr().align_offset(mem::align_of::<u64>()));
+                let (head, bytes) = bytes.split_at(head);
+                value = Self::calc_bytes_with_values(refin, value, head, &tables[0]);
+
+                // `word` has byte `j` of the message in bits `8 * j..8 * j + 8`.
+                let lookup = |word: u64| {
+                    let mut value: $t = 0;
+                    let mut j = 0;
+                    while j < 8 {
+                        value ^= tables[7 - j][(word >> (8 * j)) as u8 as usize];
+                        j += 1;
+                    }
+                    value
+                };
+
+                let mut chunks = bytes.chunks_exact(8);
+                if refin {
+                    for chunk in &mut chunks {
+                        let word = u64::from_le_bytes(chunk.try_into().unwrap()) ^ value as u64;
+                        value = value.checked_shr(64).unwrap_or(0) ^ lookup(word);
+                    }
+                } else {
+                    // The first 8 bytes of the value, in the top bits.
+                    const SHIFT: u32 = 128 - <$t>::BITS;
+                    for chunk in &mut chunks {
+                        let word = u64::from_be_bytes(chunk.try_into().unwrap()) ^ ((value as u128) << SHIFT >> 64) as u64;
+                        value = value.checked_shl(64).unwrap_or(0) ^ lookup(word.swap_bytes());
+                    }
+                }
+
+                Self::calc_bytes_with_values(refin, value, chunks.remainder(), &tables[0])
+            }
+
+            /// Create table of 16 entries indexed by 4 bits.
+            pub const fn create_nibble_table(width: u8, poly: $t, refin: bool) -> [$t; 16] {
+                let mut table = [0; 16];
 
                 let mut i = 0;
                 while i < table.len() {
-                    table[i] = Self::calc_byte_with_reciprocal_poly(reciprocal_poly, refin, i as u8);
+                    let mut value = if refin {
+                        i as $t
+                    } else {
+                        (i as $t) << (<$t>::BITS - 4)
+                    };
+                    let mut j = 0;
+                    while j < 4 {
+                        value = Self::calc_bit_with_value(width, poly, refin, value, false);
+                        j += 1;
+                    }
+                    table[i] = value;
                     i += 1;
                 }
 
                 table
             }
 
-            /// Caluculate bytes with values.
-            pub const fn calc_bytes_with_values(refin: bool, mut value: $t, bytes: &[u8], table: &[$t; 256]) -> $t {
+            /// Calculate bytes with values and a table of 16 entries, two lookups per byte.
+            pub const fn calc_bytes_with_nibble_table(refin: bool, mut value: $t, bytes: &[u8], table: &[$t; 16]) -> $t {
                 let mut i = 0;
                 if refin {
                     while i < bytes.len() {
-                        value = table[(value as usize ^ bytes[i] as usize) & 0xFF] ^ (value >> 8);
+                        value ^= bytes[i] as $t;
+                        value = table[(value & 0xF) as usize] ^ (value >> 4);
+                        value = table[(value & 0xF) as usize] ^ (value >> 4);
                         i += 1;
                     }
                 } else {
                     while i < bytes.len() {
-                        value = table[((value >> (mem::size_of::<$t>() * 8 - 8)) as usize ^ bytes[i] as usize) & 0xFF] ^ (value << 8);
+                        value ^= (bytes[i] as $t) << (<$t>::BITS - 8);
+                        value = table[(value >> (<$t>::BITS - 4)) as usize] ^ (value << 4);
+                        value = table[(value >> (<$t>::BITS - 4)) as usize] ^ (value << 4);
                         i += 1;
                     }
                 }
                 value
             }
+
+            /// Calculate bytes bit by bit without a table.
+            pub const fn calc_bytes_bitwise(width: u8, poly: $t, refin: bool, mut value: $t, bytes: &[u8]) -> $t {
+                let mut i = 0;
+                while i < bytes.len() {
+                    let mut j = 0;
+                    while j < 8 {
+                        // Least significant bit first if `refin`.
+                        let bit = if refin {
+                            bytes[i] >> j & 1 == 1
+                        } else {
+                            bytes[i] >> (7 - j) & 1 == 1
+                        };
+                        value = Self::calc_bit_with_value(width, poly, refin, value, bit);
+                        j += 1;
+                    }
+                    i += 1;
+                }
+                value
+            }
+
+            /// Multiply `a` and `b` modulo the polynomial.
+            ///
+            /// Both are kept in the most significant bits as values of `refin == false`.
+            pub const fn mul_mod(width: u8, poly: $t, a: $t, b: $t) -> $t {
+                // Values in the most significant bits are polynomials modulo `x^shift` times the polynomial.
+                Poly(a).mul_mod(Poly(b), <$t>::BITS as u8, poly << Self::shift(width)).0
+            }
+
+            /// `x^(8 * len)` modulo the polynomial, in O(log `len`).
+            ///
+            /// It is kept in the most significant bits as values of `refin == false`.
+            pub const fn xpow8n(width: u8, poly: $t, len: usize) -> $t {
+                Poly::<$t>::xpow_mod(8 * len as u128, <$t>::BITS as u8, poly << Self::shift(width)).0
+            }
+
+            /// Multiply `value` by `xpow` from [`xpow8n`](Self::xpow8n).
+            /// The result is the value after calculating `len` zero bytes.
+            pub const fn mul_xpow(width: u8, poly: $t, refin: bool, value: $t, xpow: $t) -> $t {
+                if refin {
+                    Self::mul_mod(width, poly, value.reverse_bits(), xpow).reverse_bits()
+                } else {
+                    Self::mul_mod(width, poly, value, xpow)
+                }
+            }
+
+            /// Calculate `len` zero bytes with value, in O(log `len`).
+            pub const fn shift_zeros(width: u8, poly: $t, refin: bool, value: $t, len: usize) -> $t {
+                Self::mul_xpow(width, poly, refin, value, Self::xpow8n(width, poly, len))
+            }
+
+            /// Combine `value1` of the first bytes and `value2` of the following `len2` bytes into the value of both.
+            ///
+            /// `value2` must be calculated from a value of zero.
+            pub const fn combine_values(width: u8, poly: $t, refin: bool, value1: $t, value2: $t, len2: usize) -> $t {
+                Self::shift_zeros(width, poly, refin, value1, len2) ^ value2
+            }
+
+            /// Combine `checksum1` of the first bytes and `checksum2` of the following `len2` bytes
+            /// into the checksum of both, in O(log `len2`), like `crc32_combine` of zlib.
+            pub const fn combine(&self, checksum1: $t, checksum2: $t, len2: usize) -> $t {
+                let init = Self::initialize_with_width(self.width, self.init, self.refin);
+                let value1 = Self::unfinalize(self.width, self.refin, self.refout, self.xorout, checksum1);
+                let value2 = Self::unfinalize(self.width, self.refin, self.refout, self.xorout, checksum2);
+                // `value2` is calculated from `init` instead of zero.
+                let value = Self::combine_values(self.width, self.poly, self.refin, value1 ^ init, value2, len2);
+                Self::finalize_with_width(self.width, self.refin, self.refout, self.xorout, value)
+            }
+
+            /// Checksum of `len` bytes after the byte at `offset` is changed from `old_byte` to `new_byte`,
+            /// in O(log `len`) from the old checksum.
+            ///
+            /// # Panics
+            /// Panics if `offset` is not less than `len`.
+            pub const fn patch(&self, checksum: $t, offset: usize, old_byte: u8, new_byte: u8, len: usize) -> $t {
+                assert!(offset < len, "offset is not less than len");
+                // CRCs are affine, so the checksum changes by the CRC of the difference from zero,
+                // without `init` and `xorout`.
+                let diff = Self::calc_bytes_bitwise(self.width, self.poly, self.refin, 0, &[old_byte ^ new_byte]);
+                let diff = Self::shift_zeros(self.width, self.poly, self.refin, diff, len - offset - 1);
+                checksum ^ Self::optional_reflection_with_width(self.width, self.refin, self.refout, diff)
+            }
+
+            /// Checksum of `len` bytes after `zeros` zero bytes are prepended to them,
+            /// in O(log `len` + log `zeros`) from the old checksum.
+            ///
+            /// Only `init` matters, as the zero bytes shift it before the `len` bytes.
+            pub const fn prepend_zeros(&self, checksum: $t, len: usize, zeros: usize) -> $t {
+                let init = Self::initialize_with_width(self.width, self.init, self.refin);
+                let shifted = Self::shift_zeros(self.width, self.poly, self.refin, init, zeros);
+                let diff = Self::shift_zeros(self.width, self.poly, self.refin, init ^ shifted, len);
+                checksum ^ Self::optional_reflection_with_width(self.width, self.refin, self.refout, diff)
+            }
+
+            /// Overwrite `(width + 7) / 8` bytes of `bytes` at `offset` so that the checksum of `bytes` is `checksum`.
+            ///
+            /// Returns `false` and leaves `bytes` unchanged if no such bytes exist,
+            /// which only happens if the polynomial has no `x^0` term.
+            ///
+            /// # Panics
+            /// Panics if the bytes at `offset` are out of `bytes`.
+            pub fn forge(&self, bytes: &mut [u8], offset: usize, checksum: $t) -> bool {
+                let n = (self.width as usize).div_ceil(8);
+                let end = offset + n;
+                assert!(end <= bytes.len(), "forged bytes are out of bytes");
+
+                let table = Self::create_table_with_width(self.width, self.poly, self.refin);
+                let init = Self::initialize_with_width(self.width, self.init, self.refin);
+                let value = Self::calc_bytes_with_values(self.refin, init, bytes, &table);
+                let current = Self::finalize_with_width(self.width, self.refin, self.refout, self.xorout, value);
+
+                // Linear basis of the changes of the checksum by flipping bits of the forged bytes,
+                // indexed by the highest bit of the change. `u128` is the set of flipped bits.
+                let mut basis = [(0 as $t, 0u128); <$t>::BITS as usize];
+                let reduce = |basis: &[($t, u128)], mut change: $t, mut flips: u128| {
+                    while change != 0 {
+                        let bit = (<$t>::BITS - 1 - change.leading_zeros()) as usize;
+                        if basis[bit].0 == 0 {
+                            break;
+                        }
+                        change ^= basis[bit].0;
+                        flips ^= basis[bit].1;
+                    }
+                    (change, flips)
+                };
+                for i in 0..8 * n {
+                    let diff = Self::calc_bytes_bitwise(self.width, self.poly, self.refin, 0, &[1 << (i % 8)]);
+                    let diff = Self::shift_zeros(self.width, self.poly, self.refin, diff, bytes.len() - offset - i / 8 - 1);
+                    let change = Self::optional_reflection_with_width(self.width, self.refin, self.refout, diff);
+                    let (change, flips) = reduce(&basis, change, 1 << i);
+                    if change != 0 {
+                        basis[(<$t>::BITS - 1 - change.leading_zeros()) as usize] = (change, flips);
+                    }
+                }
+
+                let (change, flips) = reduce(&basis, current ^ checksum, 0);
+                if change != 0 {
+                    return false;
+                }
+                for (i, byte) in bytes[offset..end].iter_mut().enumerate() {
+                    *byte ^= (flips >> (8 * i)) as u8;
+                }
+                true
+            }
+
+            /// Returns `true` if `self` and `other` produce identical checksums for all messages.
+            ///
+            /// `endian` and `residue` are not compared.
+            pub fn is_equivalent_to(&self, other: &Self) -> bool {
+                if self.width != other.width {
+                    return false;
+                }
+
+                let algos = [self, other];
+                let tables = [
+                    Self::create_table_with_width(self.width, self.poly, self.refin),
+                    Self::create_table_with_width(other.width, other.poly, other.refin),
+                ];
+                let calc = |i: usize, value: $t, bytes: &[u8]| {
+                    Self::calc_bytes_with_values(algos[i].refin, value, bytes, &tables[i])
+                };
+                let finalize = |i: usize, value: $t| {
+                    let a = algos[i];
+                    Self::finalize_with_width(a.width, a.refin, a.refout, a.xorout, value)
+                };
+                let init = |i: usize| Self::initialize_with_width(algos[i].width, algos[i].init, algos[i].refin);
+
+                // Both algorithms are affine, and the state of the pair is `2 * width` bits.
+                // So it is enough to compare messages of zeros and messages with a single bit set in the first byte,
+                // up to `2 * width` bytes long.
+                let len = 2 * self.width as usize;
+
+                let mut values = [init(0), init(1)];
+                for _ in 0..=len {
+                    if finalize(0, values[0]) != finalize(1, values[1]) {
+                        return false;
+                    }
+                    values = [calc(0, values[0], &[0]), calc(1, values[1], &[0])];
+                }
+
+                for bit in 0..8 {
+                    let byte = [1 << bit];
+                    let mut values = [calc(0, init(0), &byte), calc(1, init(1), &byte)];
+                    for _ in 0..len {
+                        if finalize(0, values[0]) != finalize(1, values[1]) {
+                            return false;
+                        }
+                        values = [calc(0, values[0], &[0]), calc(1, values[1], &[0])];
+                    }
+                }
+
+                true
+            }
         }
     )*)
 }
 
-algorithm_impl!(u16, u32, u64, u128);
+algorithm_impl!(u8, u16, u32, u64, u128);
+
+macro_rules! any_algorithm_from_impl {
+    ( $( ($t:ty, $variant:ident) ),* ) => ($(
+        impl From<Algorithm<$t>> for AnyAlgorithm {
+            fn from(algorithm: Algorithm<$t>) -> Self {
+                Self::$variant(algorithm)
+            }
+        }
+    )*)
+}
+
+any_algorithm_from_impl!((u8, U8), (u16, U16), (u32, U32), (u64, U64), (u128, U128));
+
+#[cfg(feature = "arbitrary")]
+macro_rules! arbitrary_impl {
+    ( $( $t:ty ),* ) => ($(
+        /// Valid parameters of any `width`, with `check` and `residue` calculated from them.
+        impl<'a> arbitrary::Arbitrary<'a> for Algorithm<$t> {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                let endian = *u.choose(&[Endian::Big, Endian::Little, Endian::Native])?;
+                let width = u.int_in_range(1..=<$t>::BITS as u8)?;
+                let mask = Self::mask(width);
+                // The `x^0` term is set, as in every useful polynomial.
+                let poly = (<$t>::arbitrary(u)? & mask) | 1;
+                let init = <$t>::arbitrary(u)? & mask;
+                let refin = bool::arbitrary(u)?;
+                let refout = bool::arbitrary(u)?;
+                let xorout = <$t>::arbitrary(u)? & mask;
+                let (algorithm, _, _) = Self::new_with_width(endian, width, poly, init, refin, refout, xorout);
+                Ok(algorithm)
+            }
+        }
+    )*)
+}
+
+#[cfg(feature = "arbitrary")]
+arbitrary_impl!(u8, u16, u32, u64, u128);
+
+/// Algorithm of the `crc-catalog` crate, also used by the `crc` crate.
+///
+/// `endian` is little-endian if `refout`, otherwise big-endian, like the algorithms of [`catalog`](crate::catalog).
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// use mycrc::{catalog::CRC_32_ISCSI, Algorithm};
+///
+/// assert_eq!(Algorithm::from(&crc_catalog::CRC_32_ISCSI), CRC_32_ISCSI);
+/// assert_eq!(crc_catalog::Algorithm::from(CRC_32_ISCSI), crc_catalog::CRC_32_ISCSI);
+/// # }
+/// ```
+#[cfg(feature = "crc-catalog")]
+impl<T: crc_catalog::Width + Copy> From<&crc_catalog::Algorithm<T>> for Algorithm<T> {
+    fn from(algorithm: &crc_catalog::Algorithm<T>) -> Self {
+        Self {
+            endian: if algorithm.refout {
+                Endian::Little
+            } else {
+                Endian::Big
+            },
+            width: algorithm.width,
+            poly: algorithm.poly,
+            init: algorithm.init,
+            refin: algorithm.refin,
+            refout: algorithm.refout,
+            xorout: algorithm.xorout,
+            check: algorithm.check,
+            residue: algorithm.residue,
+        }
+    }
+}
+
+/// Algorithm of the `crc-catalog` crate, without `endian`.
+#[cfg(feature = "crc-catalog")]
+impl<T: crc_catalog::Width> From<Algorithm<T>> for crc_catalog::Algorithm<T> {
+    fn from(algorithm: Algorithm<T>) -> Self {
+        Self {
+            width: algorithm.width,
+            poly: algorithm.poly,
+            init: algorithm.init,
+            refin: algorithm.refin,
+            refout: algorithm.refout,
+            xorout: algorithm.xorout,
+            check: algorithm.check,
+            residue: algorithm.residue,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_bytes;
+
+    #[test]
+    fn to_u64() {
+        // CRC-16/ARC
+        let (algorithm, _, _) = Algorithm::<u16>::new(Endian::Little, 0x8005, 0, true, true, 0);
+        let wide = AnyAlgorithm::U16(algorithm).to_u64().unwrap();
+        assert_eq!(AnyAlgorithm::U64(wide).checksum(CHECK_BYTES), 0xbb3d);
+        assert_eq!(wide.residue, 0);
+
+        let (algorithm, _, _) =
+            Algorithm::<u128>::new_with_width(Endian::Little, 16, 0x8005, 0, true, true, 0);
+        assert_eq!(AnyAlgorithm::U128(algorithm).to_u64(), None);
+    }
+
+    #[test]
+    fn try_new() {
+        let try_new = |width, poly, init, xorout| {
+            Algorithm::<u16>::try_new(Endian::Big, width, poly, init, false, false, xorout)
+                .map(|(algorithm, _, _)| algorithm)
+        };
+        assert_eq!(
+            try_new(16, 0x1021, 0xffff, 0),
+            Ok(Algorithm::<u16>::new(Endian::Big, 0x1021, 0xffff, false, false, 0).0)
+        );
+        assert_eq!(try_new(0, 0x1021, 0, 0), Err(ParamError::Width(0)));
+        assert_eq!(try_new(17, 0x1021, 0, 0), Err(ParamError::Width(17)));
+        assert_eq!(try_new(16, 0, 0, 0), Err(ParamError::ZeroPoly));
+        assert_eq!(try_new(16, 0x8004, 0, 0), Err(ParamError::EvenPoly));
+        assert_eq!(try_new(12, 0x180f, 0, 0), Err(ParamError::WidePoly));
+        assert_eq!(try_new(12, 0x80f, 0x1000, 0), Err(ParamError::WideInit));
+        assert_eq!(try_new(12, 0x80f, 0, 0xffff), Err(ParamError::WideXorout));
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data: [u8; 4096] =
+            core::array::from_fn(|i| (i as u8).wrapping_mul(0x9d) ^ (i >> 8) as u8);
+        let mut u = Unstructured::new(&data);
+        for _ in 0..64 {
+            let algorithm = Algorithm::<u32>::arbitrary(&mut u).unwrap();
+            assert!((1..=32).contains(&algorithm.width));
+            assert_eq!(algorithm.poly & 1, 1);
+            assert!(algorithm.poly <= Algorithm::<u32>::mask(algorithm.width));
+            assert!(CRC::<u32>::from_algorithm(algorithm).self_test().is_ok());
+        }
+    }
+
+    #[test]
+    fn koopman() {
+        // CRC-8/SMBUS
+        assert_eq!(Algorithm::<u8>::from_koopman(0x83), 0x07);
+        assert_eq!(Algorithm::<u8>::to_koopman(8, 0x07), 0x83);
+        // CRC-16/XMODEM
+        assert_eq!(Algorithm::<u16>::from_koopman(0x8810), 0x1021);
+        // CRC-24/OPENPGP
+        assert_eq!(Algorithm::<u32>::koopman_width(0xc3267d), 24);
+        assert_eq!(Algorithm::<u32>::from_koopman(0xc3267d), 0x864cfb);
+        assert_eq!(Algorithm::<u32>::to_koopman(24, 0x864cfb), 0xc3267d);
+        // CRC-32/ISO-HDLC
+        assert_eq!(Algorithm::<u32>::from_koopman(0x82608edb), 0x04c11db7);
+        assert_eq!(Algorithm::<u32>::to_koopman(32, 0x04c11db7), 0x82608edb);
+        // CRC-64/XZ
+        assert_eq!(
+            Algorithm::<u64>::from_koopman(0xa17870f5d4f51b49),
+            0x42f0e1eba9ea3693
+        );
+    }
+
+    #[test]
+    fn notation() {
+        // CRC-32/ISO-HDLC
+        let (algo, _, _) = Algorithm::<u32>::new_with_notation(
+            Endian::Little,
+            32,
+            0xedb88320,
+            Notation::Reversed,
+            0xffffffff,
+            true,
+            true,
+            0xffffffff,
+        );
+        assert_eq!(algo.poly, 0x04c11db7);
+        assert_eq!(algo.poly_reversed(), 0xedb88320);
+        assert_eq!(algo.poly_reciprocal(), 0xdb710641);
+
+        let notations = [
+            (Notation::Normal, 0x04c11db7),
+            (Notation::Reversed, 0xedb88320),
+            (Notation::Reciprocal, 0xdb710641),
+            (Notation::Koopman, 0x82608edb),
+        ];
+        for (notation, poly) in notations {
+            assert_eq!(
+                Algorithm::<u32>::poly_from_normal(32, 0x04c11db7, notation),
+                poly
+            );
+            assert_eq!(
+                Algorithm::<u32>::poly_to_normal(32, poly, notation),
+                0x04c11db7
+            );
+        }
+
+        // CRC-15/CAN
+        let notations = [
+            (Notation::Normal, 0x4599),
+            (Notation::Reversed, 0x4cd1),
+            (Notation::Reciprocal, 0x19a3),
+            (Notation::Koopman, 0x62cc),
+        ];
+        for (notation, poly) in notations {
+            assert_eq!(
+                Algorithm::<u16>::poly_from_normal(15, 0x4599, notation),
+                poly
+            );
+            assert_eq!(Algorithm::<u16>::poly_to_normal(15, poly, notation), 0x4599);
+        }
+    }
+
+    #[test]
+    fn equivalence() {
+        // CRC-32/ISO-HDLC
+        let (algo, _, _) = Algorithm::<u32>::new(
+            Endian::Little,
+            0x04c11db7,
+            0xffffffff,
+            true,
+            true,
+            0xffffffff,
+        );
+        let other = Algorithm {
+            endian: Endian::Big,
+            residue: 0,
+            ..algo
+        };
+        assert!(algo.is_equivalent_to(&other));
+
+        // CRC-32/JAMCRC
+        let other = Algorithm { xorout: 0, ..algo };
+        assert!(!algo.is_equivalent_to(&other));
+        // CRC-32/BZIP2
+        let other = Algorithm {
+            refin: false,
+            refout: false,
+            ..algo
+        };
+        assert!(!algo.is_equivalent_to(&other));
+
+        // CRC-5/USB
+        let (algo, _, _) =
+            Algorithm::<u8>::new_with_width(Endian::Little, 5, 0x05, 0x1f, true, true, 0x1f);
+        assert!(algo.is_equivalent_to(&algo));
+        assert!(!algo.is_equivalent_to(&Algorithm { init: 0x1e, ..algo }));
+    }
+
+    const CRC_32_ISO_HDLC: Algorithm<u32> = Algorithm::<u32> {
+        endian: Endian::Little,
+        width: 32,
+        poly: 0x04c11db7,
+        init: 0xffffffff,
+        refin: true,
+        refout: true,
+        xorout: 0xffffffff,
+        check: 0xcbf43926,
+        residue: 0xdebb20e3,
+    }
+    .assert_check();
+
+    #[test]
+    fn check() {
+        let (algo, _, _) = Algorithm::<u32>::new(
+            Endian::Little,
+            0x04c11db7,
+            0xffffffff,
+            true,
+            true,
+            0xffffffff,
+        );
+        assert_eq!(algo, CRC_32_ISO_HDLC);
+    }
+
+    #[test]
+    fn burst_detection() {
+        assert_eq!(
+            CRC_32_ISO_HDLC.burst_detection(),
+            BurstDetection {
+                max_burst: 32,
+                odd_errors: false
+            }
+        );
+        // CRC-16/ARC
+        let (arc, _, _) = Algorithm::<u16>::new(Endian::Little, 0x8005, 0, true, true, 0);
+        assert_eq!(
+            arc.burst_detection(),
+            BurstDetection {
+                max_burst: 16,
+                odd_errors: true
+            }
+        );
+
+        // `x^8 + x^2 + x` misses the burst of itself.
+        let (algorithm, _, _) = Algorithm::<u8>::new(Endian::Big, 0x06, 0, false, false, 0);
+        assert_eq!(algorithm.burst_detection().max_burst, 7);
+        let mut crc = CRC::<u8>::create(algorithm);
+        let checksum = crc.checksum(&[0x12, 0x34, 0x56]);
+        for error in 1..=u16::MAX {
+            if 16 - error.leading_zeros() - error.trailing_zeros() <= 7 {
+                let [high, low] = error.to_be_bytes();
+                assert_ne!(crc.checksum(&[0x12, 0x34 ^ high, 0x56 ^ low]), checksum);
+            }
+        }
+        assert_eq!(crc.checksum(&[0x12, 0x34 ^ 0x01, 0x56 ^ 0x06]), checksum);
+    }
+
+    #[test]
+    fn magic() {
+        assert_eq!(CRC_32_ISO_HDLC.magic(), 0x2144df1c);
+        assert_eq!(AnyAlgorithm::U32(CRC_32_ISO_HDLC).magic(), 0x2144df1c);
+
+        // CRC-16/GENIBUS and CRC-16/KERMIT
+        let genibus = CRC::<u16>::new(Endian::Big, 0x1021, 0xffff, false, false, 0xffff);
+        let kermit = CRC::<u16>::new(Endian::Little, 0x1021, 0, true, true, 0);
+        for mut crc in [genibus, kermit] {
+            let checksum = crc.checksum_to_endian_bytes(CHECK_BYTES);
+            let bytes = [CHECK_BYTES, &checksum].concat();
+            assert_eq!(crc.checksum(&bytes), crc.algorithm.magic());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn assert_check() {
+        Algorithm::<u32> {
+            poly: 0x04c11db6,
+            ..CRC_32_ISO_HDLC
+        }
+        .assert_check();
+    }
+
+    #[test]
+    fn self_test() {
+        assert_eq!(CRC_32_ISO_HDLC.self_test(), Ok(()));
+
+        let algo = Algorithm {
+            init: 0xfffffffe,
+            ..CRC_32_ISO_HDLC
+        };
+        assert!(matches!(
+            algo.self_test(),
+            Err(SelfTestError::Check {
+                expected: 0xcbf43926,
+                ..
+            })
+        ));
+
+        let algo = Algorithm {
+            residue: 0,
+            ..CRC_32_ISO_HDLC
+        };
+        assert_eq!(
+            algo.self_test(),
+            Err(SelfTestError::Residue {
+                expected: 0,
+                actual: 0xdebb20e3
+            })
+        );
+    }
+
+    #[test]
+    fn shift_zeros() {
+        // (width, poly, refin) of CRC-3/GSM, CRC-8/ROHC, CRC-32/ISO-HDLC and CRC-64/WE
+        fn assert_zeros<T: CrcWidth>(algo: Algorithm<T>) {
+            let table = T::create_table(&algo);
+            let value = T::calc_bytes(&algo, T::initialize(&algo), b"mycrc", &table);
+            for len in 0..80 {
+                let zeros = T::calc_bytes(&algo, value, &[0; 80][..len], &table);
+                assert_eq!(T::shift_zeros(&algo, value, len), zeros);
+            }
+        }
+        assert_zeros(
+            Algorithm::<u8>::new_with_width(Endian::Big, 3, 0x3, 0x0, false, false, 0x7).0,
+        );
+        assert_zeros(Algorithm::<u8>::new(Endian::Little, 0x07, 0xff, true, true, 0x00).0);
+        assert_zeros(CRC_32_ISO_HDLC);
+        assert_zeros(
+            Algorithm::<u64>::new(Endian::Big, 0x42f0e1eba9ea3693, !0, false, false, !0).0,
+        );
+    }
+
+    #[test]
+    fn combine_values() {
+        let a = CRC_32_ISO_HDLC;
+        let table = Algorithm::<u32>::create_table_with_width(a.width, a.poly, a.refin);
+        let calc =
+            |value, bytes| Algorithm::<u32>::calc_bytes_with_values(a.refin, value, bytes, &table);
+
+        let init = Algorithm::<u32>::initialize_with_width(a.width, a.init, a.refin);
+        let (first, second) = CHECK_BYTES.split_at(4);
+        let value = Algorithm::<u32>::combine_values(
+            a.width,
+            a.poly,
+            a.refin,
+            calc(init, first),
+            calc(0, second),
+            second.len(),
+        );
+        assert_eq!(
+            Algorithm::<u32>::finalize_with_width(a.width, a.refin, a.refout, a.xorout, value),
+            a.check
+        );
+    }
+
+    #[test]
+    fn calc_bytes_with_words() {
+        let bytes: [u8; 100] = test_bytes();
+        macro_rules! assert_words {
+            ($t:ty, $width:expr, $poly:expr, $refin:expr, $value:expr) => {
+                let tables = Algorithm::<$t>::create_tables::<8>($width, $poly, $refin);
+                for start in 0..8 {
+                    for end in start..bytes.len() {
+                        assert_eq!(
+                            Algorithm::<$t>::calc_bytes_with_words(
+                                $refin,
+                                $value,
+                                &bytes[start..end],
+                                &tables
+                            ),
+                            Algorithm::<$t>::calc_bytes_with_tables(
+                                $refin,
+                                $value,
+                                &bytes[start..end],
+                                &tables
+                            ),
+                        );
+                    }
+                }
+            };
+        }
+        // CRC-8/SMBUS, CRC-12/UMTS, CRC-32/ISO-HDLC, CRC-64/XZ and CRC-82/DARC
+        assert_words!(u8, 8, 0x07, false, 0x5a);
+        assert_words!(u16, 12, 0x80f, false, 0xabc << 4);
+        assert_words!(u32, 32, 0x04c11db7, true, !0);
+        assert_words!(u64, 64, 0x42f0e1eba9ea3693, true, !0);
+        assert_words!(
+            u128,
+            82,
+            0x0308c0111011401440411,
+            true,
+            0x1234_5678_9abc_def0_1234
+        );
+    }
+
+    #[test]
+    fn combine() {
+        let bytes: [u8; 100] = test_bytes();
+        // CRC-32/BZIP2, CRC-24/BLE and CRC-31/PHILIPS
+        let algorithms = [
+            CRC_32_ISO_HDLC,
+            Algorithm::<u32>::new(Endian::Big, 0x04c11db7, !0, false, false, !0).0,
+            Algorithm::<u32>::new_with_width(Endian::Little, 24, 0x00065b, 0x555555, true, true, 0)
+                .0,
+            Algorithm::<u32>::new_with_width(
+                Endian::Big,
+                31,
+                0x04c11db7,
+                0x7fffffff,
+                false,
+                false,
+                0x7fffffff,
+            )
+            .0,
+        ];
+        for algorithm in algorithms {
+            let mut crc = CRC::<u32>::from_algorithm(algorithm);
+            for mid in [0, 1, 9, 64, 100] {
+                let (first, second) = bytes.split_at(mid);
+                let (checksum1, checksum2) = (crc.checksum(first), crc.checksum(second));
+                assert_eq!(
+                    algorithm.combine(checksum1, checksum2, second.len()),
+                    crc.checksum(&bytes),
+                    "{:?} {}",
+                    algorithm,
+                    mid
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn patch() {
+        let mut bytes: [u8; 100] = test_bytes();
+        // CRC-32/ISO-HDLC and CRC-31/PHILIPS
+        let algorithms = [
+            CRC_32_ISO_HDLC,
+            Algorithm::<u32>::new_with_width(
+                Endian::Big,
+                31,
+                0x04c11db7,
+                0x7fffffff,
+                false,
+                false,
+                0x7fffffff,
+            )
+            .0,
+        ];
+        for algorithm in algorithms {
+            let mut crc = CRC::<u32>::from_algorithm(algorithm);
+            for offset in [0, 1, 50, 99] {
+                let checksum = crc.checksum(&bytes);
+                let old_byte = bytes[offset];
+                bytes[offset] ^= 0xa7;
+                let patched =
+                    algorithm.patch(checksum, offset, old_byte, bytes[offset], bytes.len());
+                assert_eq!(patched, crc.checksum(&bytes), "{:?} {}", algorithm, offset);
+            }
+        }
+    }
+
+    #[test]
+    fn prepend_zeros() {
+        let mut bytes = [0; 40];
+        bytes[32..].copy_from_slice(b"12345678");
+        // CRC-32/ISO-HDLC and CRC-12/UMTS
+        let crc32 = CRC::<u32>::from_algorithm(CRC_32_ISO_HDLC);
+        let crc12 = CRC::<u16>::new_with_width(Endian::Big, 12, 0x80f, 0, false, true, 0);
+        for zeros in [0, 1, 31, 32] {
+            let mut crc = crc32;
+            let checksum = crc.checksum(&bytes[32..]);
+            let prepended = crc.algorithm.prepend_zeros(checksum, 8, zeros);
+            assert_eq!(prepended, crc.checksum(&bytes[32 - zeros..]), "{}", zeros);
+
+            let mut crc = crc12;
+            let checksum = crc.checksum(&bytes[32..]);
+            let prepended = crc.algorithm.prepend_zeros(checksum, 8, zeros);
+            assert_eq!(prepended, crc.checksum(&bytes[32 - zeros..]), "{}", zeros);
+        }
+    }
+
+    #[test]
+    fn forge() {
+        let mut bytes: [u8; 100] = test_bytes();
+        // CRC-32/ISO-HDLC, CRC-31/PHILIPS and CRC-5/USB
+        let algorithms = [
+            CRC_32_ISO_HDLC,
+            Algorithm::<u32>::new_with_width(
+                Endian::Big,
+                31,
+                0x04c11db7,
+                0x7fffffff,
+                false,
+                false,
+                0x7fffffff,
+            )
+            .0,
+            Algorithm::<u32>::new_with_width(Endian::Little, 5, 0x05, 0x1f, true, true, 0x1f).0,
+        ];
+        for algorithm in algorithms {
+            let mut crc = CRC::<u32>::from_algorithm(algorithm);
+            let n = (algorithm.width as usize).div_ceil(8);
+            for (offset, checksum) in [(0, 0), (50, 0x12345678), (100 - n, !0)] {
+                let checksum = checksum & Algorithm::<u32>::mask(algorithm.width);
+                assert!(algorithm.forge(&mut bytes, offset, checksum));
+                assert_eq!(crc.checksum(&bytes), checksum, "{:?} {}", algorithm, offset);
+            }
+        }
+
+        // No `x^0` term.
+        let algorithm = Algorithm::<u8>::new(Endian::Big, 0x06, 0, false, false, 0).0;
+        let mut crc = CRC::<u8>::from_algorithm(algorithm);
+        let checksum = crc.checksum(&bytes);
+        assert!(!algorithm.forge(&mut bytes, 0, 0x01));
+        assert_eq!(crc.checksum(&bytes), checksum);
+    }
+
+    #[test]
+    fn create_table() {
+        // (width, poly) of CRC-3/GSM, CRC-8/AUTOSAR, CRC-16/ARC, CRC-40/GSM and CRC-82/DARC
+        for refin in [false, true] {
+            macro_rules! assert_table {
+                ($t:ty, $width:expr, $poly:expr) => {
+                    let reciprocal_poly = (($poly as $t) << (<$t>::BITS - $width)).reverse_bits();
+                    let table = Algorithm::<$t>::create_table_with_width($width, $poly, refin);
+                    for (i, &entry) in table.iter().enumerate() {
+                        let byte = Algorithm::<$t>::calc_byte_with_reciprocal_poly(
+                            reciprocal_poly,
+                            refin,
+                            i as u8,
+                        );
+                        assert_eq!(entry, byte);
+                    }
+                };
+            }
+            assert_table!(u8, 3, 0x3);
+            assert_table!(u8, 8, 0x2f);
+            assert_table!(u16, 16, 0x8005);
+            assert_table!(u64, 40, 0x0004820009);
+            assert_table!(u128, 82, 0x0308c0111011401440411);
+        }
+    }
+
+    #[test]
+    fn table_as_bytes() {
+        let table = Algorithm::<u32>::create_table_with_width(32, 0x04c11db7, true);
+        let bytes = CRC_32_ISO_HDLC.table_as_bytes(Endian::Little);
+        assert_eq!(bytes.len(), 1024);
+        assert_eq!(bytes[4..8], [0x96, 0x30, 0x07, 0x77]);
+        assert_eq!(
+            CRC_32_ISO_HDLC.table_as_bytes(Endian::Big)[4..8],
+            [0x77, 0x07, 0x30, 0x96]
+        );
+        for (entry, chunk) in table.iter().zip(bytes.chunks(4)) {
+            assert_eq!(entry.to_le_bytes(), chunk);
+        }
+        let bytes = CRC_32_ISO_HDLC.table_as_bytes(Endian::Native);
+        assert_eq!(bytes[4..8], table[1].to_ne_bytes());
+    }
+
+    #[cfg(all(feature = "crc-catalog", feature = "catalog"))]
+    #[test]
+    fn crc_catalog() {
+        use crate::catalog::*;
+
+        assert_eq!(Algorithm::from(&crc_catalog::CRC_16_ARC), CRC_16_ARC);
+        assert_eq!(
+            Algorithm::from(&crc_catalog::CRC_24_OPENPGP),
+            CRC_24_OPENPGP
+        );
+        assert_eq!(Algorithm::from(&crc_catalog::CRC_82_DARC), CRC_82_DARC);
+        assert_eq!(
+            crc_catalog::Algorithm::from(CRC_64_XZ),
+            crc_catalog::CRC_64_XZ
+        );
+    }
+}
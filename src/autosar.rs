@@ -0,0 +1,128 @@
+use crate::catalog::{
+    CRC_16_ARC, CRC_16_IBM_3740, CRC_32_AUTOSAR, CRC_32_ISO_HDLC, CRC_64_XZ, CRC_8_AUTOSAR,
+    CRC_8_SAE_J1850,
+};
+use crate::Algorithm;
+
+/// 8-bit SAE J1850 CRC (`Crc_CalculateCRC8`).
+pub const CRC8: Algorithm<u8> = CRC_8_SAE_J1850;
+/// 8-bit 0x2F polynomial CRC (`Crc_CalculateCRC8H2F`).
+pub const CRC8H2F: Algorithm<u8> = CRC_8_AUTOSAR;
+/// 16-bit CCITT-FALSE CRC (`Crc_CalculateCRC16`).
+pub const CRC16: Algorithm<u16> = CRC_16_IBM_3740;
+/// 16-bit 0x8005 polynomial CRC (`Crc_CalculateCRC16ARC`).
+pub const CRC16ARC: Algorithm<u16> = CRC_16_ARC;
+/// 32-bit Ethernet CRC (`Crc_CalculateCRC32`).
+pub const CRC32: Algorithm<u32> = CRC_32_ISO_HDLC;
+/// 32-bit 0xF4ACFB13 polynomial CRC (`Crc_CalculateCRC32P4`).
+pub const CRC32P4: Algorithm<u32> = CRC_32_AUTOSAR;
+/// 64-bit ECMA CRC (`Crc_CalculateCRC64`).
+pub const CRC64: Algorithm<u64> = CRC_64_XZ;
+
+macro_rules! autosar_impl {
+    ( $( $(#[$doc:meta])* $name:ident, $table:ident, $t:ty, $algorithm:ident; )* ) => ($(
+        static $table: [$t; 256] =
+            Algorithm::<$t>::create_table_with_width($algorithm.width, $algorithm.poly, $algorithm.refin);
+
+        $(#[$doc])*
+        ///
+        /// If `is_first_call` is `true`, `start_value` is ignored and the value is initialized.
+        /// Otherwise, the calculation is resumed from `start_value`, the return value of the previous call.
+        pub fn $name(data: &[u8], start_value: $t, is_first_call: bool) -> $t {
+            let a = $algorithm;
+            let value = if is_first_call {
+                Algorithm::<$t>::initialize_with_width(a.width, a.init, a.refin)
+            } else {
+                Algorithm::<$t>::unfinalize(a.width, a.refin, a.refout, a.xorout, start_value)
+            };
+            let value = Algorithm::<$t>::calc_bytes_with_values(a.refin, value, data, &$table);
+            Algorithm::<$t>::finalize_with_width(a.width, a.refin, a.refout, a.xorout, value)
+        }
+    )*)
+}
+
+autosar_impl! {
+    /// `Crc_CalculateCRC8` with [`CRC8`].
+    calculate_crc8, CRC8_TABLE, u8, CRC8;
+    /// `Crc_CalculateCRC8H2F` with [`CRC8H2F`].
+    calculate_crc8h2f, CRC8H2F_TABLE, u8, CRC8H2F;
+    /// `Crc_CalculateCRC16` with [`CRC16`].
+    calculate_crc16, CRC16_TABLE, u16, CRC16;
+    /// `Crc_CalculateCRC16ARC` with [`CRC16ARC`].
+    calculate_crc16arc, CRC16ARC_TABLE, u16, CRC16ARC;
+    /// `Crc_CalculateCRC32` with [`CRC32`].
+    calculate_crc32, CRC32_TABLE, u32, CRC32;
+    /// `Crc_CalculateCRC32P4` with [`CRC32P4`].
+    calculate_crc32p4, CRC32P4_TABLE, u32, CRC32P4;
+    /// `Crc_CalculateCRC64` with [`CRC64`].
+    calculate_crc64, CRC64_TABLE, u64, CRC64;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Examples of the AUTOSAR Specification of CRC Routines.
+    const DATA: [&[u8]; 7] = [
+        &[0x00, 0x00, 0x00, 0x00],
+        &[0xf2, 0x01, 0x83],
+        &[0x0f, 0xaa, 0x00, 0x55],
+        &[0x00, 0xff, 0x55, 0x11],
+        &[0x33, 0x22, 0x55, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff],
+        &[0x92, 0x6b, 0x55],
+        &[0xff, 0xff, 0xff, 0xff],
+    ];
+
+    #[test]
+    fn examples() {
+        let crc8 = [0x59, 0x37, 0x79, 0xb8, 0xcb, 0x8c, 0x74];
+        let crc8h2f = [0x12, 0xc2, 0xc6, 0x77, 0x11, 0x33, 0x6c];
+        let crc16 = [0x84c0, 0xd374, 0x2023, 0xb8f9, 0xf53f, 0x0745, 0x1d0f];
+        let crc32 = [
+            0x2144df1c, 0x24ab9d77, 0xb6c9b287, 0x32a06212, 0xb0ae863d, 0x9cdea29b, 0xffffffff,
+        ];
+        let crc32p4 = [
+            0x6fb32240, 0x4f721a25, 0x20662df8, 0x9bd7996e, 0xa65a343d, 0xee688a78, 0xffffffff,
+        ];
+        for (i, data) in DATA.iter().enumerate() {
+            assert_eq!(calculate_crc8(data, 0xff, true), crc8[i]);
+            assert_eq!(calculate_crc8h2f(data, 0xff, true), crc8h2f[i]);
+            assert_eq!(calculate_crc16(data, 0xffff, true), crc16[i]);
+            assert_eq!(calculate_crc32(data, 0xffffffff, true), crc32[i]);
+            assert_eq!(calculate_crc32p4(data, 0xffffffff, true), crc32p4[i]);
+        }
+    }
+
+    #[test]
+    fn start_value() {
+        let (a, b) = crate::CHECK_BYTES.split_at(5);
+        assert_eq!(
+            calculate_crc8(b, calculate_crc8(a, 0, true), false),
+            CRC8.check
+        );
+        assert_eq!(
+            calculate_crc8h2f(b, calculate_crc8h2f(a, 0, true), false),
+            CRC8H2F.check
+        );
+        assert_eq!(
+            calculate_crc16(b, calculate_crc16(a, 0, true), false),
+            CRC16.check
+        );
+        assert_eq!(
+            calculate_crc16arc(b, calculate_crc16arc(a, 0, true), false),
+            CRC16ARC.check
+        );
+        assert_eq!(
+            calculate_crc32(b, calculate_crc32(a, 0, true), false),
+            CRC32.check
+        );
+        assert_eq!(
+            calculate_crc32p4(b, calculate_crc32p4(a, 0, true), false),
+            CRC32P4.check
+        );
+        assert_eq!(
+            calculate_crc64(b, calculate_crc64(a, 0, true), false),
+            CRC64.check
+        );
+    }
+}
@@ -0,0 +1,31 @@
+#[cfg(feature = "catalog")]
+use crate::catalog;
+use crate::{Algorithm, Endian, CRC};
+use std::string::{String, ToString};
+
+/// CRC of the algorithm of [`catalog::by_name`], or the message of the error.
+#[cfg(feature = "catalog")]
+pub(crate) fn by_name(name: &str) -> Result<CRC<u64>, &'static str> {
+    let algorithm = catalog::by_name(name)
+        .ok_or("unknown algorithm")?
+        .to_u64()
+        .ok_or("algorithm is wider than 64 bits")?;
+    Ok(CRC::<u64>::create(algorithm))
+}
+
+/// CRC of `width` bits, or the message of the [`ParamError`](crate::ParamError).
+/// The endian is little if `refout`, and big otherwise.
+pub(crate) fn with_params(
+    width: u8,
+    poly: u64,
+    init: u64,
+    refin: bool,
+    refout: bool,
+    xorout: u64,
+) -> Result<CRC<u64>, String> {
+    let endian = if refout { Endian::Little } else { Endian::Big };
+    let (algorithm, _, _) =
+        Algorithm::<u64>::try_new(endian, width, poly, init, refin, refout, xorout)
+            .map_err(|e| e.to_string())?;
+    Ok(CRC::<u64>::create(algorithm))
+}
@@ -0,0 +1,127 @@
+use crate::{Algorithm, CrcWidth, MergeError};
+use core::cmp::Ordering;
+use std::vec::Vec;
+
+/// Checksum of a block of an object.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Block<T> {
+    /// Offset of the block in the object.
+    pub offset: usize,
+    /// Length of the block.
+    pub len: usize,
+    /// Checksum of the bytes of the block.
+    pub checksum: T,
+}
+
+/// Checksums of blocks of an object, merged into the checksum of the object by [`CRC::combine`](crate::CRC::combine).
+///
+/// Blocks may be pushed in any order, e.g. as parts of a chunked upload are verified by different workers,
+/// so the whole object needs no second pass.
+///
+/// Requires the `std` feature.
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// use mycrc::{catalog::CRC_32_ISCSI, BlockCrcs, CRC};
+///
+/// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+/// let mut blocks = BlockCrcs::new(CRC_32_ISCSI);
+/// blocks.push(4, 5, crc32.checksum(b"56789"));
+/// blocks.push(0, 4, crc32.checksum(b"1234"));
+/// assert_eq!(blocks.merge(), Ok(0xe3069283));
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct BlockCrcs<T> {
+    algorithm: Algorithm<T>,
+    blocks: Vec<Block<T>>,
+}
+
+impl<T> BlockCrcs<T> {
+    /// Create an empty list of blocks of `algorithm`.
+    pub const fn new(algorithm: Algorithm<T>) -> Self {
+        Self {
+            algorithm,
+            blocks: Vec::new(),
+        }
+    }
+
+    /// Algorithm of the checksums.
+    pub const fn algorithm(&self) -> &Algorithm<T> {
+        &self.algorithm
+    }
+
+    /// Blocks in the order they are pushed.
+    pub fn blocks(&self) -> &[Block<T>] {
+        &self.blocks
+    }
+
+    /// Add the checksum of `len` bytes at `offset`.
+    pub fn push(&mut self, offset: usize, len: usize, checksum: T) {
+        self.blocks.push(Block {
+            offset,
+            len,
+            checksum,
+        });
+    }
+}
+
+impl<T: CrcWidth> BlockCrcs<T> {
+    /// Checksum of the object, in O(log `len`) for each block after sorting them by offset.
+    ///
+    /// Blocks must cover the object from offset 0 without gaps or overlaps.
+    /// The length of the object is the end of the last block.
+    pub fn merge(&self) -> Result<T, MergeError> {
+        let mut blocks = self.blocks.clone();
+        // Empty blocks first, so that they don't overlap a block at the same offset.
+        blocks.sort_unstable_by_key(|block| (block.offset, block.len));
+
+        let algorithm = &self.algorithm;
+        let mut checksum = T::finalize(algorithm, T::initialize(algorithm));
+        let mut end = 0;
+        for block in blocks {
+            match block.offset.cmp(&end) {
+                Ordering::Less => {
+                    return Err(MergeError::Overlap {
+                        offset: block.offset,
+                    })
+                }
+                Ordering::Greater => return Err(MergeError::Gap { offset: end }),
+                Ordering::Equal => {}
+            }
+            checksum = T::combine(algorithm, checksum, block.checksum, block.len);
+            end += block.len;
+        }
+        Ok(checksum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_bytes, Endian, CRC};
+
+    #[test]
+    fn merge() {
+        // CRC-64/XZ
+        let mut crc = CRC::<u64>::new(Endian::Little, 0x42f0e1eba9ea3693, !0, true, true, !0);
+        let bytes: [u8; 100] = test_bytes();
+
+        let mut blocks = BlockCrcs::new(crc.algorithm);
+        assert_eq!(blocks.merge(), Ok(crc.checksum(&[])));
+        for (offset, len) in [(60, 40), (0, 10), (10, 0), (30, 30), (10, 20)] {
+            blocks.push(offset, len, crc.checksum(&bytes[offset..offset + len]));
+        }
+        assert_eq!(blocks.blocks().len(), 5);
+        assert_eq!(blocks.merge(), Ok(crc.checksum(&bytes)));
+
+        blocks.push(90, 20, 0);
+        assert_eq!(blocks.merge(), Err(MergeError::Overlap { offset: 90 }));
+
+        let mut blocks = BlockCrcs::new(crc.algorithm);
+        blocks.push(0, 10, crc.checksum(&bytes[..10]));
+        blocks.push(20, 10, crc.checksum(&bytes[20..30]));
+        assert_eq!(blocks.merge(), Err(MergeError::Gap { offset: 10 }));
+    }
+}
@@ -0,0 +1,51 @@
+use core::ops::Deref;
+
+/// Checksum bytes.
+///
+/// Only the first [`len`](Self::len) bytes are used, so that a checksum whose `width` is narrower than the storage type
+/// (e.g. CRC-24 stored in `u32`) is emitted with exactly `(width + 7) / 8` bytes.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ChecksumBytes<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> ChecksumBytes<N> {
+    /// Create checksum bytes from the first `len` bytes of `bytes`.
+    ///
+    /// # Panics
+    /// Panics if `len` is greater than `N`.
+    pub const fn new(bytes: [u8; N], len: usize) -> Self {
+        assert!(len <= N, "len must not be greater than N");
+        Self { bytes, len }
+    }
+
+    /// Bytes as slice.
+    pub const fn as_slice(&self) -> &[u8] {
+        self.bytes.split_at(self.len).0
+    }
+
+    /// Number of bytes.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no bytes.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for ChecksumBytes<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl<const N: usize> Deref for ChecksumBytes<N> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
@@ -0,0 +1,258 @@
+use crate::{CrcWidth, Endian, Table, CRC};
+
+/// Number of consecutive equal bits after which a stuff bit is inserted.
+pub const STUFF_WIDTH: u8 = 5;
+
+/// CAN FD CRC (CRC-17/CAN-FD or CRC-21/CAN-FD) over the bits of a frame.
+///
+/// Bits from SOF to the end of the data field are given without stuff bits.
+/// Dynamic stuff bits are inserted as the CAN controller does and are included in the CRC.
+/// [`CanFdCrc::finalize`] then includes the stuff count (Gray-coded count and parity bit).
+///
+/// ISO 11898-1:2015 initializes the register with `1 << (width - 1)`, which can be set with [`CRC::with_init`].
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// use mycrc::{can::CanFdCrc, catalog::CRC_17_CAN_FD, CRC};
+///
+/// let mut crc = CRC::<u32>::from_algorithm(CRC_17_CAN_FD);
+/// crc.with_init(1 << 16);
+///
+/// let mut can = CanFdCrc::new(&mut crc);
+/// // SOF
+/// can.push_bit(false);
+/// // Identifier
+/// can.push_bits(0x123, 11);
+/// // ...
+/// let crc17 = can.finalize();
+/// # }
+/// ```
+#[derive(Debug, Eq, Hash, PartialEq)]
+pub struct CanFdCrc<'a, T, B = [T; 256]> {
+    crc: &'a mut CRC<T, B>,
+    last: bool,
+    run: u8,
+    stuff_count: u8,
+}
+
+impl<'a, T: CrcWidth, B: Table<T>> CanFdCrc<'a, T, B> {
+    /// Start a frame with the current value of `crc`.
+    pub fn new(crc: &'a mut CRC<T, B>) -> Self {
+        Self {
+            crc,
+            last: false,
+            run: 0,
+            stuff_count: 0,
+        }
+    }
+
+    /// Push a bit without stuff bits.
+    /// A stuff bit is inserted after [`STUFF_WIDTH`] equal bits.
+    pub fn push_bit(&mut self, bit: bool) -> &mut Self {
+        self.push_stuffed_bit(bit);
+        if self.run == STUFF_WIDTH {
+            self.push_stuffed_bit(!bit);
+            self.stuff_count = self.stuff_count.wrapping_add(1);
+        }
+        self
+    }
+
+    /// Push the least significant `len` bits of `value`, MSB first.
+    pub fn push_bits(&mut self, value: u64, len: u32) -> &mut Self {
+        for i in (0..len).rev() {
+            self.push_bit(value >> i & 1 == 1);
+        }
+        self
+    }
+
+    /// Number of dynamic stuff bits inserted so far.
+    pub fn stuff_count(&self) -> u8 {
+        self.stuff_count
+    }
+
+    /// Calculate the stuff count field and finalize value.
+    ///
+    /// The stuff count field is the 3-bit Gray-coded stuff count (modulo 8) followed by an even parity bit.
+    pub fn finalize(self) -> T {
+        let field = stuff_count_field(self.stuff_count);
+        for i in (0..4).rev() {
+            self.crc.calc_bit(field >> i & 1 == 1);
+        }
+        self.crc.finalize()
+    }
+
+    fn push_stuffed_bit(&mut self, bit: bool) {
+        if self.run != 0 && bit == self.last {
+            self.run += 1;
+        } else {
+            self.run = 1;
+        }
+        self.last = bit;
+        self.crc.calc_bit(bit);
+    }
+}
+
+/// CRC-15/CAN of classic CAN.
+pub const CRC_15: CRC<u16> =
+    CRC::<u16>::new_with_width(Endian::Big, 15, 0x4599, 0x0000, false, false, 0x0000);
+
+/// Classic CAN data or remote frame, from SOF to the end of the data field.
+///
+/// ```
+/// use mycrc::can::ClassicFrame;
+///
+/// let frame = ClassicFrame::new(0x123, &[0xde, 0xad]);
+/// assert_eq!(frame.crc15(), 0x0b6e);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ClassicFrame<'a> {
+    /// 11-bit base identifier, or 29-bit identifier if `extended`.
+    pub id: u32,
+    /// Extended frame format.
+    pub extended: bool,
+    /// Remote frame, which has no data field.
+    pub remote: bool,
+    /// Data length code, which may be 9 to 15 for 8 bytes.
+    pub dlc: u8,
+    /// Data field, ignored for remote frames.
+    pub data: &'a [u8],
+}
+
+impl<'a> ClassicFrame<'a> {
+    /// Data frame in the base frame format, whose DLC is the length of `data`.
+    ///
+    /// # Panics
+    /// Panics if `data` is longer than 8 bytes.
+    pub fn new(id: u32, data: &'a [u8]) -> Self {
+        assert!(data.len() <= 8, "data is longer than 8 bytes");
+        Self {
+            id,
+            extended: false,
+            remote: false,
+            dlc: data.len() as u8,
+            data,
+        }
+    }
+
+    /// Calculate the bits from SOF to the end of the data field, without stuff bits as the CRC field is defined.
+    pub fn calc<'c, T: CrcWidth, B: Table<T>>(&self, crc: &'c mut CRC<T, B>) -> &'c mut CRC<T, B> {
+        // SOF
+        crc.calc_bit(false);
+        if self.extended {
+            // Base identifier, SRR and IDE
+            crc.calc_bits_msb((self.id >> 18) as u64, 11)
+                .calc_bits([true, true])
+                // Identifier extension, RTR, r1 and r0
+                .calc_bits_msb((self.id & 0x3ffff) as u64, 18)
+                .calc_bits([self.remote, false, false]);
+        } else {
+            // Identifier, RTR, IDE and r0
+            crc.calc_bits_msb(self.id as u64, 11)
+                .calc_bits([self.remote, false, false]);
+        }
+        crc.calc_bits_msb(self.dlc as u64, 4);
+        if !self.remote {
+            crc.calc_bytes(self.data);
+        }
+        crc
+    }
+
+    /// CRC field, the CRC-15 of the frame.
+    pub fn crc15(&self) -> u16 {
+        let mut crc = CRC_15;
+        self.calc(crc.initialize()).finalize()
+    }
+}
+
+/// Stuff count field of CAN FD.
+///
+/// Returns the 3-bit Gray-coded `stuff_count` (modulo 8) followed by an even parity bit, in the least significant 4 bits.
+pub const fn stuff_count_field(stuff_count: u8) -> u8 {
+    let count = stuff_count % 8;
+    let gray = count ^ (count >> 1);
+    let parity = (gray.count_ones() % 2) as u8;
+    gray << 1 | parity
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // CRC-17/CAN-FD
+    const CRC_17: CRC<u32> =
+        CRC::<u32>::new_with_width(Endian::Big, 17, 0x1685b, 0x00000, false, false, 0x00000);
+
+    #[test]
+    fn stuff_count_field() {
+        // (stuff count, Gray code + parity)
+        let fields = [
+            (0, 0b0000),
+            (1, 0b0011),
+            (2, 0b0110),
+            (3, 0b0101),
+            (4, 0b1100),
+            (5, 0b1111),
+            (6, 0b1010),
+            (7, 0b1001),
+            (8, 0b0000),
+        ];
+        for (count, field) in fields {
+            assert_eq!(super::stuff_count_field(count), field);
+        }
+    }
+
+    #[test]
+    fn stuffing() {
+        let mut crc = CRC_17;
+        let mut can = CanFdCrc::new(&mut crc);
+        // 0 0 0 0 0 [1] 1 1 1 1 [0] 0
+        can.push_bits(0b00_0001_1110, 10);
+        assert_eq!(can.stuff_count(), 2);
+        let crc17 = can.finalize();
+
+        let mut expected = CRC_17;
+        let bits = [0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0];
+        expected.calc_bits(bits.iter().map(|&bit| bit == 1));
+        // Stuff count 2
+        expected.calc_bits([false, true, true, false]);
+        assert_eq!(crc17, expected.finalize());
+    }
+
+    #[test]
+    fn classic_frame() {
+        // SOF, ID 0x123, RTR, IDE, r0, DLC 2 and data
+        let bits = "0 00100100011 0 0 0 0010 1101111010101101";
+        let mut expected = CRC_15;
+        expected.calc_bits(bits.chars().filter(|&c| c != ' ').map(|c| c == '1'));
+        let frame = ClassicFrame::new(0x123, &[0xde, 0xad]);
+        assert_eq!(frame.crc15(), expected.finalize());
+
+        // SOF, base ID, SRR, IDE, ID extension, RTR, r1, r0 and DLC
+        let bits = "0 10010001101 1 1 000101011001111000 1 0 0 1000";
+        let mut expected = CRC_15;
+        expected.calc_bits(bits.chars().filter(|&c| c != ' ').map(|c| c == '1'));
+        let frame = ClassicFrame {
+            id: 0x12345678,
+            extended: true,
+            remote: true,
+            dlc: 8,
+            data: &[0xff; 8],
+        };
+        assert_eq!(frame.crc15(), expected.finalize());
+    }
+
+    #[test]
+    fn no_stuffing() {
+        let mut crc = CRC_17;
+        let mut can = CanFdCrc::new(&mut crc);
+        can.push_bits(0b0101_0011_0110, 12);
+        assert_eq!(can.stuff_count(), 0);
+        let crc17 = can.finalize();
+
+        let mut expected = CRC_17;
+        expected.calc_bits((0..12).rev().map(|i| 0b0101_0011_0110 >> i & 1 == 1));
+        expected.calc_bits([false; 4]);
+        assert_eq!(crc17, expected.finalize());
+    }
+}
@@ -0,0 +1,635 @@
+use crate::AnyAlgorithm;
+
+/// CRC-10 algorithms
+pub mod crc10;
+/// CRC-11 algorithms
+pub mod crc11;
+/// CRC-12 algorithms
+pub mod crc12;
+/// CRC-13 algorithms
+pub mod crc13;
+/// CRC-14 algorithms
+pub mod crc14;
+/// CRC-15 algorithms
+pub mod crc15;
+/// CRC-16 algorithms
+pub mod crc16;
+/// CRC-17 algorithms
+pub mod crc17;
+/// CRC-21 algorithms
+pub mod crc21;
+/// CRC-24 algorithms
+pub mod crc24;
+/// CRC-3 algorithms
+pub mod crc3;
+/// CRC-30 algorithms
+pub mod crc30;
+/// CRC-31 algorithms
+pub mod crc31;
+/// CRC-32 algorithms
+pub mod crc32;
+/// CRC-4 algorithms
+pub mod crc4;
+/// CRC-40 algorithms
+pub mod crc40;
+/// CRC-5 algorithms
+pub mod crc5;
+/// CRC-6 algorithms
+pub mod crc6;
+/// CRC-64 algorithms
+pub mod crc64;
+/// CRC-7 algorithms
+pub mod crc7;
+/// CRC-8 algorithms
+pub mod crc8;
+/// CRC-82 algorithms
+pub mod crc82;
+/// Precomputed `static` tables of popular algorithms
+pub mod tables;
+
+pub use self::crc10::*;
+pub use self::crc11::*;
+pub use self::crc12::*;
+pub use self::crc13::*;
+pub use self::crc14::*;
+pub use self::crc15::*;
+pub use self::crc16::*;
+pub use self::crc17::*;
+pub use self::crc21::*;
+pub use self::crc24::*;
+pub use self::crc3::*;
+pub use self::crc30::*;
+pub use self::crc31::*;
+pub use self::crc32::*;
+pub use self::crc4::*;
+pub use self::crc40::*;
+pub use self::crc5::*;
+pub use self::crc6::*;
+pub use self::crc64::*;
+pub use self::crc7::*;
+pub use self::crc8::*;
+pub use self::crc82::*;
+
+/// All algorithms in the catalogue as `(name, algorithm, check, residue)`.
+///
+/// `check` is the checksum of `b"123456789"`.
+///
+/// ```
+/// use mycrc::catalog;
+///
+/// for (name, algorithm, check, _residue) in catalog::ALL {
+///     assert_eq!(algorithm.checksum(b"123456789"), *check, "{}", name);
+/// }
+/// ```
+pub const ALL: &[(&str, AnyAlgorithm, u128, u128)] = &[
+    ("CRC-3/GSM", AnyAlgorithm::U8(CRC_3_GSM), 0x4, 0x2),
+    ("CRC-3/ROHC", AnyAlgorithm::U8(CRC_3_ROHC), 0x6, 0x0),
+    ("CRC-4/G-704", AnyAlgorithm::U8(CRC_4_G_704), 0x7, 0x0),
+    (
+        "CRC-4/INTERLAKEN",
+        AnyAlgorithm::U8(CRC_4_INTERLAKEN),
+        0xb,
+        0x2,
+    ),
+    (
+        "CRC-5/EPC-C1G2",
+        AnyAlgorithm::U8(CRC_5_EPC_C1G2),
+        0x00,
+        0x00,
+    ),
+    ("CRC-5/G-704", AnyAlgorithm::U8(CRC_5_G_704), 0x07, 0x00),
+    ("CRC-5/USB", AnyAlgorithm::U8(CRC_5_USB), 0x19, 0x06),
+    (
+        "CRC-6/CDMA2000-A",
+        AnyAlgorithm::U8(CRC_6_CDMA2000_A),
+        0x0d,
+        0x00,
+    ),
+    (
+        "CRC-6/CDMA2000-B",
+        AnyAlgorithm::U8(CRC_6_CDMA2000_B),
+        0x3b,
+        0x00,
+    ),
+    ("CRC-6/DARC", AnyAlgorithm::U8(CRC_6_DARC), 0x26, 0x00),
+    ("CRC-6/G-704", AnyAlgorithm::U8(CRC_6_G_704), 0x06, 0x00),
+    ("CRC-6/GSM", AnyAlgorithm::U8(CRC_6_GSM), 0x13, 0x3a),
+    ("CRC-7/MMC", AnyAlgorithm::U8(CRC_7_MMC), 0x75, 0x00),
+    ("CRC-7/ROHC", AnyAlgorithm::U8(CRC_7_ROHC), 0x53, 0x00),
+    ("CRC-7/UMTS", AnyAlgorithm::U8(CRC_7_UMTS), 0x61, 0x00),
+    ("CRC-8/AUTOSAR", AnyAlgorithm::U8(CRC_8_AUTOSAR), 0xdf, 0x42),
+    (
+        "CRC-8/BLUETOOTH",
+        AnyAlgorithm::U8(CRC_8_BLUETOOTH),
+        0x26,
+        0x00,
+    ),
+    (
+        "CRC-8/CDMA2000",
+        AnyAlgorithm::U8(CRC_8_CDMA2000),
+        0xda,
+        0x00,
+    ),
+    ("CRC-8/DARC", AnyAlgorithm::U8(CRC_8_DARC), 0x15, 0x00),
+    ("CRC-8/DVB-S2", AnyAlgorithm::U8(CRC_8_DVB_S2), 0xbc, 0x00),
+    ("CRC-8/GSM-A", AnyAlgorithm::U8(CRC_8_GSM_A), 0x37, 0x00),
+    ("CRC-8/GSM-B", AnyAlgorithm::U8(CRC_8_GSM_B), 0x94, 0x53),
+    ("CRC-8/HITAG", AnyAlgorithm::U8(CRC_8_HITAG), 0xb4, 0x00),
+    ("CRC-8/I-432-1", AnyAlgorithm::U8(CRC_8_I_432_1), 0xa1, 0xac),
+    ("CRC-8/I-CODE", AnyAlgorithm::U8(CRC_8_I_CODE), 0x7e, 0x00),
+    ("CRC-8/LTE", AnyAlgorithm::U8(CRC_8_LTE), 0xea, 0x00),
+    (
+        "CRC-8/MAXIM-DOW",
+        AnyAlgorithm::U8(CRC_8_MAXIM_DOW),
+        0xa1,
+        0x00,
+    ),
+    (
+        "CRC-8/MIFARE-MAD",
+        AnyAlgorithm::U8(CRC_8_MIFARE_MAD),
+        0x99,
+        0x00,
+    ),
+    ("CRC-8/NRSC-5", AnyAlgorithm::U8(CRC_8_NRSC_5), 0xf7, 0x00),
+    (
+        "CRC-8/OPENSAFETY",
+        AnyAlgorithm::U8(CRC_8_OPENSAFETY),
+        0x3e,
+        0x00,
+    ),
+    ("CRC-8/ROHC", AnyAlgorithm::U8(CRC_8_ROHC), 0xd0, 0x00),
+    (
+        "CRC-8/SAE-J1850",
+        AnyAlgorithm::U8(CRC_8_SAE_J1850),
+        0x4b,
+        0xc4,
+    ),
+    ("CRC-8/SMBUS", AnyAlgorithm::U8(CRC_8_SMBUS), 0xf4, 0x00),
+    (
+        "CRC-8/TECH-3250",
+        AnyAlgorithm::U8(CRC_8_TECH_3250),
+        0x97,
+        0x00,
+    ),
+    ("CRC-8/WCDMA", AnyAlgorithm::U8(CRC_8_WCDMA), 0x25, 0x00),
+    ("CRC-10/ATM", AnyAlgorithm::U16(CRC_10_ATM), 0x199, 0x000),
+    (
+        "CRC-10/CDMA2000",
+        AnyAlgorithm::U16(CRC_10_CDMA2000),
+        0x233,
+        0x000,
+    ),
+    ("CRC-10/GSM", AnyAlgorithm::U16(CRC_10_GSM), 0x12a, 0x0c6),
+    (
+        "CRC-11/FLEXRAY",
+        AnyAlgorithm::U16(CRC_11_FLEXRAY),
+        0x5a3,
+        0x000,
+    ),
+    ("CRC-11/UMTS", AnyAlgorithm::U16(CRC_11_UMTS), 0x061, 0x000),
+    (
+        "CRC-12/CDMA2000",
+        AnyAlgorithm::U16(CRC_12_CDMA2000),
+        0xd4d,
+        0x000,
+    ),
+    ("CRC-12/DECT", AnyAlgorithm::U16(CRC_12_DECT), 0xf5b, 0x000),
+    ("CRC-12/GSM", AnyAlgorithm::U16(CRC_12_GSM), 0xb34, 0x178),
+    ("CRC-12/UMTS", AnyAlgorithm::U16(CRC_12_UMTS), 0xdaf, 0x000),
+    ("CRC-13/BBC", AnyAlgorithm::U16(CRC_13_BBC), 0x04fa, 0x0000),
+    (
+        "CRC-14/DARC",
+        AnyAlgorithm::U16(CRC_14_DARC),
+        0x082d,
+        0x0000,
+    ),
+    ("CRC-14/GSM", AnyAlgorithm::U16(CRC_14_GSM), 0x30ae, 0x031e),
+    ("CRC-15/CAN", AnyAlgorithm::U16(CRC_15_CAN), 0x059e, 0x0000),
+    (
+        "CRC-15/MPT1327",
+        AnyAlgorithm::U16(CRC_15_MPT1327),
+        0x2566,
+        0x6815,
+    ),
+    ("CRC-16/ARC", AnyAlgorithm::U16(CRC_16_ARC), 0xbb3d, 0x0000),
+    (
+        "CRC-16/CDMA2000",
+        AnyAlgorithm::U16(CRC_16_CDMA2000),
+        0x4c06,
+        0x0000,
+    ),
+    ("CRC-16/CMS", AnyAlgorithm::U16(CRC_16_CMS), 0xaee7, 0x0000),
+    (
+        "CRC-16/DDS-110",
+        AnyAlgorithm::U16(CRC_16_DDS_110),
+        0x9ecf,
+        0x0000,
+    ),
+    (
+        "CRC-16/DECT-R",
+        AnyAlgorithm::U16(CRC_16_DECT_R),
+        0x007e,
+        0x0589,
+    ),
+    (
+        "CRC-16/DECT-X",
+        AnyAlgorithm::U16(CRC_16_DECT_X),
+        0x007f,
+        0x0000,
+    ),
+    ("CRC-16/DNP", AnyAlgorithm::U16(CRC_16_DNP), 0xea82, 0x66c5),
+    (
+        "CRC-16/EN-13757",
+        AnyAlgorithm::U16(CRC_16_EN_13757),
+        0xc2b7,
+        0xa366,
+    ),
+    (
+        "CRC-16/GENIBUS",
+        AnyAlgorithm::U16(CRC_16_GENIBUS),
+        0xd64e,
+        0x1d0f,
+    ),
+    ("CRC-16/GSM", AnyAlgorithm::U16(CRC_16_GSM), 0xce3c, 0x1d0f),
+    (
+        "CRC-16/IBM-3740",
+        AnyAlgorithm::U16(CRC_16_IBM_3740),
+        0x29b1,
+        0x0000,
+    ),
+    (
+        "CRC-16/IBM-SDLC",
+        AnyAlgorithm::U16(CRC_16_IBM_SDLC),
+        0x906e,
+        0xf0b8,
+    ),
+    (
+        "CRC-16/ISO-IEC-14443-3-A",
+        AnyAlgorithm::U16(CRC_16_ISO_IEC_14443_3_A),
+        0xbf05,
+        0x0000,
+    ),
+    (
+        "CRC-16/KERMIT",
+        AnyAlgorithm::U16(CRC_16_KERMIT),
+        0x2189,
+        0x0000,
+    ),
+    (
+        "CRC-16/LJ1200",
+        AnyAlgorithm::U16(CRC_16_LJ1200),
+        0xbdf4,
+        0x0000,
+    ),
+    ("CRC-16/M17", AnyAlgorithm::U16(CRC_16_M17), 0x772b, 0x0000),
+    (
+        "CRC-16/MAXIM-DOW",
+        AnyAlgorithm::U16(CRC_16_MAXIM_DOW),
+        0x44c2,
+        0xb001,
+    ),
+    (
+        "CRC-16/MCRF4XX",
+        AnyAlgorithm::U16(CRC_16_MCRF4XX),
+        0x6f91,
+        0x0000,
+    ),
+    (
+        "CRC-16/MODBUS",
+        AnyAlgorithm::U16(CRC_16_MODBUS),
+        0x4b37,
+        0x0000,
+    ),
+    (
+        "CRC-16/NRSC-5",
+        AnyAlgorithm::U16(CRC_16_NRSC_5),
+        0xa066,
+        0x0000,
+    ),
+    (
+        "CRC-16/OPENSAFETY-A",
+        AnyAlgorithm::U16(CRC_16_OPENSAFETY_A),
+        0x5d38,
+        0x0000,
+    ),
+    (
+        "CRC-16/OPENSAFETY-B",
+        AnyAlgorithm::U16(CRC_16_OPENSAFETY_B),
+        0x20fe,
+        0x0000,
+    ),
+    (
+        "CRC-16/PROFIBUS",
+        AnyAlgorithm::U16(CRC_16_PROFIBUS),
+        0xa819,
+        0xe394,
+    ),
+    (
+        "CRC-16/RIELLO",
+        AnyAlgorithm::U16(CRC_16_RIELLO),
+        0x63d0,
+        0x0000,
+    ),
+    (
+        "CRC-16/SPI-FUJITSU",
+        AnyAlgorithm::U16(CRC_16_SPI_FUJITSU),
+        0xe5cc,
+        0x0000,
+    ),
+    (
+        "CRC-16/T10-DIF",
+        AnyAlgorithm::U16(CRC_16_T10_DIF),
+        0xd0db,
+        0x0000,
+    ),
+    (
+        "CRC-16/TELEDISK",
+        AnyAlgorithm::U16(CRC_16_TELEDISK),
+        0x0fb3,
+        0x0000,
+    ),
+    (
+        "CRC-16/TMS37157",
+        AnyAlgorithm::U16(CRC_16_TMS37157),
+        0x26b1,
+        0x0000,
+    ),
+    (
+        "CRC-16/UMTS",
+        AnyAlgorithm::U16(CRC_16_UMTS),
+        0xfee8,
+        0x0000,
+    ),
+    ("CRC-16/USB", AnyAlgorithm::U16(CRC_16_USB), 0xb4c8, 0xb001),
+    (
+        "CRC-16/XMODEM",
+        AnyAlgorithm::U16(CRC_16_XMODEM),
+        0x31c3,
+        0x0000,
+    ),
+    (
+        "CRC-17/CAN-FD",
+        AnyAlgorithm::U32(CRC_17_CAN_FD),
+        0x04f03,
+        0x00000,
+    ),
+    (
+        "CRC-21/CAN-FD",
+        AnyAlgorithm::U32(CRC_21_CAN_FD),
+        0x0ed841,
+        0x000000,
+    ),
+    (
+        "CRC-24/BLE",
+        AnyAlgorithm::U32(CRC_24_BLE),
+        0xc25a56,
+        0x000000,
+    ),
+    (
+        "CRC-24/FLEXRAY-A",
+        AnyAlgorithm::U32(CRC_24_FLEXRAY_A),
+        0x7979bd,
+        0x000000,
+    ),
+    (
+        "CRC-24/FLEXRAY-B",
+        AnyAlgorithm::U32(CRC_24_FLEXRAY_B),
+        0x1f23b8,
+        0x000000,
+    ),
+    (
+        "CRC-24/INTERLAKEN",
+        AnyAlgorithm::U32(CRC_24_INTERLAKEN),
+        0xb4f3e6,
+        0x144e63,
+    ),
+    (
+        "CRC-24/LTE-A",
+        AnyAlgorithm::U32(CRC_24_LTE_A),
+        0xcde703,
+        0x000000,
+    ),
+    (
+        "CRC-24/LTE-B",
+        AnyAlgorithm::U32(CRC_24_LTE_B),
+        0x23ef52,
+        0x000000,
+    ),
+    (
+        "CRC-24/OPENPGP",
+        AnyAlgorithm::U32(CRC_24_OPENPGP),
+        0x21cf02,
+        0x000000,
+    ),
+    (
+        "CRC-24/OS-9",
+        AnyAlgorithm::U32(CRC_24_OS_9),
+        0x200fa5,
+        0x800fe3,
+    ),
+    (
+        "CRC-30/CDMA",
+        AnyAlgorithm::U32(CRC_30_CDMA),
+        0x04c34abf,
+        0x34efa55a,
+    ),
+    (
+        "CRC-31/PHILIPS",
+        AnyAlgorithm::U32(CRC_31_PHILIPS),
+        0x0ce9e46c,
+        0x4eaf26f1,
+    ),
+    (
+        "CRC-32/AIXM",
+        AnyAlgorithm::U32(CRC_32_AIXM),
+        0x3010bf7f,
+        0x00000000,
+    ),
+    (
+        "CRC-32/AUTOSAR",
+        AnyAlgorithm::U32(CRC_32_AUTOSAR),
+        0x1697d06a,
+        0x904cddbf,
+    ),
+    (
+        "CRC-32/BASE91-D",
+        AnyAlgorithm::U32(CRC_32_BASE91_D),
+        0x87315576,
+        0x45270551,
+    ),
+    (
+        "CRC-32/BZIP2",
+        AnyAlgorithm::U32(CRC_32_BZIP2),
+        0xfc891918,
+        0xc704dd7b,
+    ),
+    (
+        "CRC-32/CD-ROM-EDC",
+        AnyAlgorithm::U32(CRC_32_CD_ROM_EDC),
+        0x6ec2edc4,
+        0x00000000,
+    ),
+    (
+        "CRC-32/CKSUM",
+        AnyAlgorithm::U32(CRC_32_CKSUM),
+        0x765e7680,
+        0xc704dd7b,
+    ),
+    (
+        "CRC-32/ISCSI",
+        AnyAlgorithm::U32(CRC_32_ISCSI),
+        0xe3069283,
+        0xb798b438,
+    ),
+    (
+        "CRC-32/ISO-HDLC",
+        AnyAlgorithm::U32(CRC_32_ISO_HDLC),
+        0xcbf43926,
+        0xdebb20e3,
+    ),
+    (
+        "CRC-32/JAMCRC",
+        AnyAlgorithm::U32(CRC_32_JAMCRC),
+        0x340bc6d9,
+        0x00000000,
+    ),
+    (
+        "CRC-32/MEF",
+        AnyAlgorithm::U32(CRC_32_MEF),
+        0xd2c22f51,
+        0x00000000,
+    ),
+    (
+        "CRC-32/MPEG-2",
+        AnyAlgorithm::U32(CRC_32_MPEG_2),
+        0x0376e6e7,
+        0x00000000,
+    ),
+    (
+        "CRC-32/XFER",
+        AnyAlgorithm::U32(CRC_32_XFER),
+        0xbd0be338,
+        0x00000000,
+    ),
+    (
+        "CRC-40/GSM",
+        AnyAlgorithm::U64(CRC_40_GSM),
+        0xd4164fc646,
+        0xc4ff8071ff,
+    ),
+    (
+        "CRC-64/ECMA-182",
+        AnyAlgorithm::U64(CRC_64_ECMA_182),
+        0x6c40df5f0b497347,
+        0x0000000000000000,
+    ),
+    (
+        "CRC-64/GO-ISO",
+        AnyAlgorithm::U64(CRC_64_GO_ISO),
+        0xb90956c775a41001,
+        0x5300000000000000,
+    ),
+    (
+        "CRC-64/MS",
+        AnyAlgorithm::U64(CRC_64_MS),
+        0x75d4b74f024eceea,
+        0x0000000000000000,
+    ),
+    (
+        "CRC-64/NVME",
+        AnyAlgorithm::U64(CRC_64_NVME),
+        0xae8b14860a799888,
+        0xf310303b2b6f6e42,
+    ),
+    (
+        "CRC-64/REDIS",
+        AnyAlgorithm::U64(CRC_64_REDIS),
+        0xe9c6d914c4b8d9ca,
+        0x0000000000000000,
+    ),
+    (
+        "CRC-64/WE",
+        AnyAlgorithm::U64(CRC_64_WE),
+        0x62ec59e3f1a4f00a,
+        0xfcacbebd5931a992,
+    ),
+    (
+        "CRC-64/XZ",
+        AnyAlgorithm::U64(CRC_64_XZ),
+        0x995dc9bbdf1939fa,
+        0x49958c9abd7d353f,
+    ),
+    (
+        "CRC-82/DARC",
+        AnyAlgorithm::U128(CRC_82_DARC),
+        0x09ea83f625023801fd612,
+        0x000000000000000000000,
+    ),
+];
+
+/// Aliases and algorithms.
+const ALIASES: &[(&str, AnyAlgorithm)] = &[
+    ("CRC-16/CCITT-FALSE", AnyAlgorithm::U16(CRC_16_CCITT_FALSE)),
+    ("CRC-16/AUTOSAR", AnyAlgorithm::U16(CRC_16_AUTOSAR)),
+    ("CRC-16/CCITT", AnyAlgorithm::U16(CRC_16_CCITT)),
+    ("CRC-16/ZMODEM", AnyAlgorithm::U16(CRC_16_ZMODEM)),
+    ("CRC-16/X-25", AnyAlgorithm::U16(CRC_16_X_25)),
+    ("CRC-16/IBM", AnyAlgorithm::U16(CRC_16_IBM)),
+    ("CRC-16/BUYPASS", AnyAlgorithm::U16(CRC_16_BUYPASS)),
+    ("CRC-64", AnyAlgorithm::U64(CRC_64)),
+    ("CRC-64/GO-ECMA", AnyAlgorithm::U64(CRC_64_GO_ECMA)),
+];
+
+/// Find an algorithm by name (e.g. `"CRC-32/ISO-HDLC"`), ignoring ASCII case.
+///
+/// Aliases defined in this module (e.g. `"CRC-16/CCITT-FALSE"`) are also accepted.
+///
+/// ```
+/// use mycrc::{catalog, AnyAlgorithm, CRC};
+///
+/// let algorithm = catalog::by_name("crc-32/iso-hdlc").unwrap();
+/// assert_eq!(algorithm, AnyAlgorithm::U32(catalog::CRC_32_ISO_HDLC));
+///
+/// if let AnyAlgorithm::U32(algorithm) = algorithm {
+///     let mut crc = CRC::<u32>::from_algorithm(algorithm);
+///     assert_eq!(crc.checksum(b"123456789"), 0xcbf43926);
+/// }
+/// ```
+pub fn by_name(name: &str) -> Option<AnyAlgorithm> {
+    let all = ALL.iter().map(|&(name, algorithm, _, _)| (name, algorithm));
+    all.chain(ALIASES.iter().copied())
+        .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        .map(|(_, algorithm)| algorithm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn by_name() {
+        assert_eq!(
+            super::by_name("CRC-16/CCITT-FALSE"),
+            Some(AnyAlgorithm::U16(CRC_16_IBM_3740))
+        );
+        assert_eq!(
+            super::by_name("crc-82/darc"),
+            Some(AnyAlgorithm::U128(CRC_82_DARC))
+        );
+        assert_eq!(super::by_name("CRC-32/UNKNOWN"), None);
+
+        for (name, algorithm) in ALIASES {
+            assert_eq!(super::by_name(name), Some(*algorithm));
+        }
+    }
+
+    #[test]
+    fn all() {
+        assert_eq!(ALL.len(), 113);
+        for (name, algorithm, check, residue) in ALL {
+            assert_eq!(super::by_name(name), Some(*algorithm));
+            assert_eq!(algorithm.checksum(b"123456789"), *check, "{}", name);
+            assert_eq!(algorithm.residue(), *residue, "{}", name);
+        }
+    }
+}
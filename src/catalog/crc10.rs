@@ -0,0 +1,72 @@
+use crate::{Algorithm, Endian};
+
+/// [CRC-10/ATM](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-10-atm)
+pub const CRC_10_ATM: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 10,
+    poly: 0x233,
+    init: 0x000,
+    refin: false,
+    refout: false,
+    xorout: 0x000,
+    check: 0x199,
+    residue: 0x000,
+};
+
+/// [CRC-10/CDMA2000](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-10-cdma2000)
+pub const CRC_10_CDMA2000: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 10,
+    poly: 0x3d9,
+    init: 0x3ff,
+    refin: false,
+    refout: false,
+    xorout: 0x000,
+    check: 0x233,
+    residue: 0x000,
+};
+
+/// [CRC-10/GSM](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-10-gsm)
+pub const CRC_10_GSM: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 10,
+    poly: 0x175,
+    init: 0x000,
+    refin: false,
+    refout: false,
+    xorout: 0x3ff,
+    check: 0x12a,
+    residue: 0x0c6,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn check() {
+        // (Algorithm, check)
+        let algos = [
+            (CRC_10_ATM, 0x199),
+            (CRC_10_CDMA2000, 0x233),
+            (CRC_10_GSM, 0x12a),
+        ];
+        for (algo, check) in algos {
+            let mut crc = CRC::<u16>::from_algorithm(algo);
+            assert_eq!(crc.checksum(b"123456789"), check);
+
+            // Check residue.
+            let crc_new = CRC::<u16>::new_with_width(
+                algo.endian,
+                algo.width,
+                algo.poly,
+                algo.init,
+                algo.refin,
+                algo.refout,
+                algo.xorout,
+            );
+            assert_eq!(algo, crc_new.algorithm);
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{Algorithm, Endian};
+
+/// [CRC-11/FLEXRAY](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-11-flexray)
+pub const CRC_11_FLEXRAY: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 11,
+    poly: 0x385,
+    init: 0x01a,
+    refin: false,
+    refout: false,
+    xorout: 0x000,
+    check: 0x5a3,
+    residue: 0x000,
+};
+
+/// [CRC-11/UMTS](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-11-umts)
+pub const CRC_11_UMTS: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 11,
+    poly: 0x307,
+    init: 0x000,
+    refin: false,
+    refout: false,
+    xorout: 0x000,
+    check: 0x061,
+    residue: 0x000,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn check() {
+        // (Algorithm, check)
+        let algos = [(CRC_11_FLEXRAY, 0x5a3), (CRC_11_UMTS, 0x061)];
+        for (algo, check) in algos {
+            let mut crc = CRC::<u16>::from_algorithm(algo);
+            assert_eq!(crc.checksum(b"123456789"), check);
+
+            // Check residue.
+            let crc_new = CRC::<u16>::new_with_width(
+                algo.endian,
+                algo.width,
+                algo.poly,
+                algo.init,
+                algo.refin,
+                algo.refout,
+                algo.xorout,
+            );
+            assert_eq!(algo, crc_new.algorithm);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{Algorithm, Endian};
+
+/// [CRC-12/CDMA2000](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-12-cdma2000)
+pub const CRC_12_CDMA2000: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 12,
+    poly: 0xf13,
+    init: 0xfff,
+    refin: false,
+    refout: false,
+    xorout: 0x000,
+    check: 0xd4d,
+    residue: 0x000,
+};
+
+/// [CRC-12/DECT](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-12-dect)
+pub const CRC_12_DECT: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 12,
+    poly: 0x80f,
+    init: 0x000,
+    refin: false,
+    refout: false,
+    xorout: 0x000,
+    check: 0xf5b,
+    residue: 0x000,
+};
+
+/// [CRC-12/GSM](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-12-gsm)
+pub const CRC_12_GSM: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 12,
+    poly: 0xd31,
+    init: 0x000,
+    refin: false,
+    refout: false,
+    xorout: 0xfff,
+    check: 0xb34,
+    residue: 0x178,
+};
+
+/// [CRC-12/UMTS](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-12-umts)
+pub const CRC_12_UMTS: Algorithm<u16> = Algorithm {
+    endian: Endian::Little,
+    width: 12,
+    poly: 0x80f,
+    init: 0x000,
+    refin: false,
+    refout: true,
+    xorout: 0x000,
+    check: 0xdaf,
+    residue: 0x000,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn check() {
+        // (Algorithm, check)
+        let algos = [
+            (CRC_12_CDMA2000, 0xd4d),
+            (CRC_12_DECT, 0xf5b),
+            (CRC_12_GSM, 0xb34),
+            (CRC_12_UMTS, 0xdaf),
+        ];
+        for (algo, check) in algos {
+            let mut crc = CRC::<u16>::from_algorithm(algo);
+            assert_eq!(crc.checksum(b"123456789"), check);
+
+            // Check residue.
+            let crc_new = CRC::<u16>::new_with_width(
+                algo.endian,
+                algo.width,
+                algo.poly,
+                algo.init,
+                algo.refin,
+                algo.refout,
+                algo.xorout,
+            );
+            assert_eq!(algo, crc_new.algorithm);
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{Algorithm, Endian};
+
+/// [CRC-13/BBC](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-13-bbc)
+pub const CRC_13_BBC: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 13,
+    poly: 0x1cf5,
+    init: 0x0000,
+    refin: false,
+    refout: false,
+    xorout: 0x0000,
+    check: 0x04fa,
+    residue: 0x0000,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn check() {
+        // (Algorithm, check)
+        let algos = [(CRC_13_BBC, 0x04fa)];
+        for (algo, check) in algos {
+            let mut crc = CRC::<u16>::from_algorithm(algo);
+            assert_eq!(crc.checksum(b"123456789"), check);
+
+            // Check residue.
+            let crc_new = CRC::<u16>::new_with_width(
+                algo.endian,
+                algo.width,
+                algo.poly,
+                algo.init,
+                algo.refin,
+                algo.refout,
+                algo.xorout,
+            );
+            assert_eq!(algo, crc_new.algorithm);
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{Algorithm, Endian};
+
+/// [CRC-14/DARC](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-14-darc)
+pub const CRC_14_DARC: Algorithm<u16> = Algorithm {
+    endian: Endian::Little,
+    width: 14,
+    poly: 0x0805,
+    init: 0x0000,
+    refin: true,
+    refout: true,
+    xorout: 0x0000,
+    check: 0x082d,
+    residue: 0x0000,
+};
+
+/// [CRC-14/GSM](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-14-gsm)
+pub const CRC_14_GSM: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 14,
+    poly: 0x202d,
+    init: 0x0000,
+    refin: false,
+    refout: false,
+    xorout: 0x3fff,
+    check: 0x30ae,
+    residue: 0x031e,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn check() {
+        // (Algorithm, check)
+        let algos = [(CRC_14_DARC, 0x082d), (CRC_14_GSM, 0x30ae)];
+        for (algo, check) in algos {
+            let mut crc = CRC::<u16>::from_algorithm(algo);
+            assert_eq!(crc.checksum(b"123456789"), check);
+
+            // Check residue.
+            let crc_new = CRC::<u16>::new_with_width(
+                algo.endian,
+                algo.width,
+                algo.poly,
+                algo.init,
+                algo.refin,
+                algo.refout,
+                algo.xorout,
+            );
+            assert_eq!(algo, crc_new.algorithm);
+        }
+    }
+}
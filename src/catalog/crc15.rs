@@ -0,0 +1,55 @@
+use crate::{Algorithm, Endian};
+
+/// [CRC-15/CAN](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-15-can)
+pub const CRC_15_CAN: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 15,
+    poly: 0x4599,
+    init: 0x0000,
+    refin: false,
+    refout: false,
+    xorout: 0x0000,
+    check: 0x059e,
+    residue: 0x0000,
+};
+
+/// [CRC-15/MPT1327](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-15-mpt1327)
+pub const CRC_15_MPT1327: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 15,
+    poly: 0x6815,
+    init: 0x0000,
+    refin: false,
+    refout: false,
+    xorout: 0x0001,
+    check: 0x2566,
+    residue: 0x6815,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn check() {
+        // (Algorithm, check)
+        let algos = [(CRC_15_CAN, 0x059e), (CRC_15_MPT1327, 0x2566)];
+        for (algo, check) in algos {
+            let mut crc = CRC::<u16>::from_algorithm(algo);
+            assert_eq!(crc.checksum(b"123456789"), check);
+
+            // Check residue.
+            let crc_new = CRC::<u16>::new_with_width(
+                algo.endian,
+                algo.width,
+                algo.poly,
+                algo.init,
+                algo.refin,
+                algo.refout,
+                algo.xorout,
+            );
+            assert_eq!(algo, crc_new.algorithm);
+        }
+    }
+}
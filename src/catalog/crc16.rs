@@ -0,0 +1,483 @@
+use crate::{Algorithm, Endian};
+
+/// [CRC-16/ARC](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-arc)
+pub const CRC_16_ARC: Algorithm<u16> = Algorithm {
+    endian: Endian::Little,
+    width: 16,
+    poly: 0x8005,
+    init: 0x0000,
+    refin: true,
+    refout: true,
+    xorout: 0x0000,
+    check: 0xbb3d,
+    residue: 0x0000,
+};
+
+/// [CRC-16/CDMA2000](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-cdma2000)
+pub const CRC_16_CDMA2000: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 16,
+    poly: 0xc867,
+    init: 0xffff,
+    refin: false,
+    refout: false,
+    xorout: 0x0000,
+    check: 0x4c06,
+    residue: 0x0000,
+};
+
+/// [CRC-16/CMS](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-cms)
+pub const CRC_16_CMS: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 16,
+    poly: 0x8005,
+    init: 0xffff,
+    refin: false,
+    refout: false,
+    xorout: 0x0000,
+    check: 0xaee7,
+    residue: 0x0000,
+};
+
+/// [CRC-16/DDS-110](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-dds-110)
+pub const CRC_16_DDS_110: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 16,
+    poly: 0x8005,
+    init: 0x800d,
+    refin: false,
+    refout: false,
+    xorout: 0x0000,
+    check: 0x9ecf,
+    residue: 0x0000,
+};
+
+/// [CRC-16/DECT-R](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-dect-r)
+pub const CRC_16_DECT_R: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 16,
+    poly: 0x0589,
+    init: 0x0000,
+    refin: false,
+    refout: false,
+    xorout: 0x0001,
+    check: 0x007e,
+    residue: 0x0589,
+};
+
+/// [CRC-16/DECT-X](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-dect-x)
+pub const CRC_16_DECT_X: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 16,
+    poly: 0x0589,
+    init: 0x0000,
+    refin: false,
+    refout: false,
+    xorout: 0x0000,
+    check: 0x007f,
+    residue: 0x0000,
+};
+
+/// [CRC-16/DNP](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-dnp)
+pub const CRC_16_DNP: Algorithm<u16> = Algorithm {
+    endian: Endian::Little,
+    width: 16,
+    poly: 0x3d65,
+    init: 0x0000,
+    refin: true,
+    refout: true,
+    xorout: 0xffff,
+    check: 0xea82,
+    residue: 0x66c5,
+};
+
+/// [CRC-16/EN-13757](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-en-13757)
+pub const CRC_16_EN_13757: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 16,
+    poly: 0x3d65,
+    init: 0x0000,
+    refin: false,
+    refout: false,
+    xorout: 0xffff,
+    check: 0xc2b7,
+    residue: 0xa366,
+};
+
+/// [CRC-16/GENIBUS](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-genibus)
+pub const CRC_16_GENIBUS: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 16,
+    poly: 0x1021,
+    init: 0xffff,
+    refin: false,
+    refout: false,
+    xorout: 0xffff,
+    check: 0xd64e,
+    residue: 0x1d0f,
+};
+
+/// [CRC-16/GSM](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-gsm)
+pub const CRC_16_GSM: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 16,
+    poly: 0x1021,
+    init: 0x0000,
+    refin: false,
+    refout: false,
+    xorout: 0xffff,
+    check: 0xce3c,
+    residue: 0x1d0f,
+};
+
+/// [CRC-16/IBM-3740](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-ibm-3740)
+pub const CRC_16_IBM_3740: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 16,
+    poly: 0x1021,
+    init: 0xffff,
+    refin: false,
+    refout: false,
+    xorout: 0x0000,
+    check: 0x29b1,
+    residue: 0x0000,
+};
+
+/// [CRC-16/IBM-SDLC](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-ibm-sdlc)
+pub const CRC_16_IBM_SDLC: Algorithm<u16> = Algorithm {
+    endian: Endian::Little,
+    width: 16,
+    poly: 0x1021,
+    init: 0xffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffff,
+    check: 0x906e,
+    residue: 0xf0b8,
+};
+
+/// [CRC-16/ISO-IEC-14443-3-A](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-iso-iec-14443-3-a)
+pub const CRC_16_ISO_IEC_14443_3_A: Algorithm<u16> = Algorithm {
+    endian: Endian::Little,
+    width: 16,
+    poly: 0x1021,
+    init: 0xc6c6,
+    refin: true,
+    refout: true,
+    xorout: 0x0000,
+    check: 0xbf05,
+    residue: 0x0000,
+};
+
+/// [CRC-16/KERMIT](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-kermit)
+pub const CRC_16_KERMIT: Algorithm<u16> = Algorithm {
+    endian: Endian::Little,
+    width: 16,
+    poly: 0x1021,
+    init: 0x0000,
+    refin: true,
+    refout: true,
+    xorout: 0x0000,
+    check: 0x2189,
+    residue: 0x0000,
+};
+
+/// [CRC-16/LJ1200](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-lj1200)
+pub const CRC_16_LJ1200: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 16,
+    poly: 0x6f63,
+    init: 0x0000,
+    refin: false,
+    refout: false,
+    xorout: 0x0000,
+    check: 0xbdf4,
+    residue: 0x0000,
+};
+
+/// [CRC-16/M17](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-m17)
+pub const CRC_16_M17: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 16,
+    poly: 0x5935,
+    init: 0xffff,
+    refin: false,
+    refout: false,
+    xorout: 0x0000,
+    check: 0x772b,
+    residue: 0x0000,
+};
+
+/// [CRC-16/MAXIM-DOW](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-maxim-dow)
+pub const CRC_16_MAXIM_DOW: Algorithm<u16> = Algorithm {
+    endian: Endian::Little,
+    width: 16,
+    poly: 0x8005,
+    init: 0x0000,
+    refin: true,
+    refout: true,
+    xorout: 0xffff,
+    check: 0x44c2,
+    residue: 0xb001,
+};
+
+/// [CRC-16/MCRF4XX](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-mcrf4xx)
+pub const CRC_16_MCRF4XX: Algorithm<u16> = Algorithm {
+    endian: Endian::Little,
+    width: 16,
+    poly: 0x1021,
+    init: 0xffff,
+    refin: true,
+    refout: true,
+    xorout: 0x0000,
+    check: 0x6f91,
+    residue: 0x0000,
+};
+
+/// [CRC-16/MODBUS](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-modbus)
+pub const CRC_16_MODBUS: Algorithm<u16> = Algorithm {
+    endian: Endian::Little,
+    width: 16,
+    poly: 0x8005,
+    init: 0xffff,
+    refin: true,
+    refout: true,
+    xorout: 0x0000,
+    check: 0x4b37,
+    residue: 0x0000,
+};
+
+/// [CRC-16/NRSC-5](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-nrsc-5)
+pub const CRC_16_NRSC_5: Algorithm<u16> = Algorithm {
+    endian: Endian::Little,
+    width: 16,
+    poly: 0x080b,
+    init: 0xffff,
+    refin: true,
+    refout: true,
+    xorout: 0x0000,
+    check: 0xa066,
+    residue: 0x0000,
+};
+
+/// [CRC-16/OPENSAFETY-A](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-opensafety-a)
+pub const CRC_16_OPENSAFETY_A: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 16,
+    poly: 0x5935,
+    init: 0x0000,
+    refin: false,
+    refout: false,
+    xorout: 0x0000,
+    check: 0x5d38,
+    residue: 0x0000,
+};
+
+/// [CRC-16/OPENSAFETY-B](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-opensafety-b)
+pub const CRC_16_OPENSAFETY_B: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 16,
+    poly: 0x755b,
+    init: 0x0000,
+    refin: false,
+    refout: false,
+    xorout: 0x0000,
+    check: 0x20fe,
+    residue: 0x0000,
+};
+
+/// [CRC-16/PROFIBUS](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-profibus)
+pub const CRC_16_PROFIBUS: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 16,
+    poly: 0x1dcf,
+    init: 0xffff,
+    refin: false,
+    refout: false,
+    xorout: 0xffff,
+    check: 0xa819,
+    residue: 0xe394,
+};
+
+/// [CRC-16/RIELLO](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-riello)
+pub const CRC_16_RIELLO: Algorithm<u16> = Algorithm {
+    endian: Endian::Little,
+    width: 16,
+    poly: 0x1021,
+    init: 0xb2aa,
+    refin: true,
+    refout: true,
+    xorout: 0x0000,
+    check: 0x63d0,
+    residue: 0x0000,
+};
+
+/// [CRC-16/SPI-FUJITSU](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-spi-fujitsu)
+pub const CRC_16_SPI_FUJITSU: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 16,
+    poly: 0x1021,
+    init: 0x1d0f,
+    refin: false,
+    refout: false,
+    xorout: 0x0000,
+    check: 0xe5cc,
+    residue: 0x0000,
+};
+
+/// [CRC-16/T10-DIF](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-t10-dif)
+pub const CRC_16_T10_DIF: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 16,
+    poly: 0x8bb7,
+    init: 0x0000,
+    refin: false,
+    refout: false,
+    xorout: 0x0000,
+    check: 0xd0db,
+    residue: 0x0000,
+};
+
+/// [CRC-16/TELEDISK](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-teledisk)
+pub const CRC_16_TELEDISK: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 16,
+    poly: 0xa097,
+    init: 0x0000,
+    refin: false,
+    refout: false,
+    xorout: 0x0000,
+    check: 0x0fb3,
+    residue: 0x0000,
+};
+
+/// [CRC-16/TMS37157](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-tms37157)
+pub const CRC_16_TMS37157: Algorithm<u16> = Algorithm {
+    endian: Endian::Little,
+    width: 16,
+    poly: 0x1021,
+    init: 0x89ec,
+    refin: true,
+    refout: true,
+    xorout: 0x0000,
+    check: 0x26b1,
+    residue: 0x0000,
+};
+
+/// [CRC-16/UMTS](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-umts)
+pub const CRC_16_UMTS: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 16,
+    poly: 0x8005,
+    init: 0x0000,
+    refin: false,
+    refout: false,
+    xorout: 0x0000,
+    check: 0xfee8,
+    residue: 0x0000,
+};
+
+/// [CRC-16/USB](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-usb)
+pub const CRC_16_USB: Algorithm<u16> = Algorithm {
+    endian: Endian::Little,
+    width: 16,
+    poly: 0x8005,
+    init: 0xffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffff,
+    check: 0xb4c8,
+    residue: 0xb001,
+};
+
+/// [CRC-16/XMODEM](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-16-xmodem)
+pub const CRC_16_XMODEM: Algorithm<u16> = Algorithm {
+    endian: Endian::Big,
+    width: 16,
+    poly: 0x1021,
+    init: 0x0000,
+    refin: false,
+    refout: false,
+    xorout: 0x0000,
+    check: 0x31c3,
+    residue: 0x0000,
+};
+
+/// Alias of [`CRC_16_IBM_3740`].
+pub const CRC_16_CCITT_FALSE: Algorithm<u16> = CRC_16_IBM_3740;
+/// Alias of [`CRC_16_IBM_3740`].
+pub const CRC_16_AUTOSAR: Algorithm<u16> = CRC_16_IBM_3740;
+/// Alias of [`CRC_16_KERMIT`].
+pub const CRC_16_CCITT: Algorithm<u16> = CRC_16_KERMIT;
+/// Alias of [`CRC_16_XMODEM`].
+pub const CRC_16_ZMODEM: Algorithm<u16> = CRC_16_XMODEM;
+/// Alias of [`CRC_16_IBM_SDLC`].
+pub const CRC_16_X_25: Algorithm<u16> = CRC_16_IBM_SDLC;
+/// Alias of [`CRC_16_ARC`].
+pub const CRC_16_IBM: Algorithm<u16> = CRC_16_ARC;
+/// Alias of [`CRC_16_UMTS`].
+pub const CRC_16_BUYPASS: Algorithm<u16> = CRC_16_UMTS;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn check() {
+        // (Algorithm, check)
+        let algos = [
+            (CRC_16_ARC, 0xbb3d),
+            (CRC_16_CDMA2000, 0x4c06),
+            (CRC_16_CMS, 0xaee7),
+            (CRC_16_DDS_110, 0x9ecf),
+            (CRC_16_DECT_R, 0x007e),
+            (CRC_16_DECT_X, 0x007f),
+            (CRC_16_DNP, 0xea82),
+            (CRC_16_EN_13757, 0xc2b7),
+            (CRC_16_GENIBUS, 0xd64e),
+            (CRC_16_GSM, 0xce3c),
+            (CRC_16_IBM_3740, 0x29b1),
+            (CRC_16_IBM_SDLC, 0x906e),
+            (CRC_16_ISO_IEC_14443_3_A, 0xbf05),
+            (CRC_16_KERMIT, 0x2189),
+            (CRC_16_LJ1200, 0xbdf4),
+            (CRC_16_M17, 0x772b),
+            (CRC_16_MAXIM_DOW, 0x44c2),
+            (CRC_16_MCRF4XX, 0x6f91),
+            (CRC_16_MODBUS, 0x4b37),
+            (CRC_16_NRSC_5, 0xa066),
+            (CRC_16_OPENSAFETY_A, 0x5d38),
+            (CRC_16_OPENSAFETY_B, 0x20fe),
+            (CRC_16_PROFIBUS, 0xa819),
+            (CRC_16_RIELLO, 0x63d0),
+            (CRC_16_SPI_FUJITSU, 0xe5cc),
+            (CRC_16_T10_DIF, 0xd0db),
+            (CRC_16_TELEDISK, 0x0fb3),
+            (CRC_16_TMS37157, 0x26b1),
+            (CRC_16_UMTS, 0xfee8),
+            (CRC_16_USB, 0xb4c8),
+            (CRC_16_XMODEM, 0x31c3),
+        ];
+        for (algo, check) in algos {
+            let mut crc = CRC::<u16>::from_algorithm(algo);
+            assert_eq!(crc.checksum(b"123456789"), check);
+
+            let checksum = crc.checksum_to_endian_bytes(b"123456789");
+            let bytes = [b"123456789", &checksum[..]].concat();
+            assert!(crc.is_error_free_bytes(&bytes));
+
+            // Check residue.
+            let crc_new = CRC::<u16>::new_with_width(
+                algo.endian,
+                algo.width,
+                algo.poly,
+                algo.init,
+                algo.refin,
+                algo.refout,
+                algo.xorout,
+            );
+            assert_eq!(algo, crc_new.algorithm);
+        }
+    }
+}
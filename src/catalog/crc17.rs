@@ -0,0 +1,42 @@
+use crate::{Algorithm, Endian};
+
+/// [CRC-17/CAN-FD](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-17-can-fd)
+pub const CRC_17_CAN_FD: Algorithm<u32> = Algorithm {
+    endian: Endian::Big,
+    width: 17,
+    poly: 0x1685b,
+    init: 0x00000,
+    refin: false,
+    refout: false,
+    xorout: 0x00000,
+    check: 0x04f03,
+    residue: 0x00000,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn check() {
+        // (Algorithm, check)
+        let algos = [(CRC_17_CAN_FD, 0x04f03)];
+        for (algo, check) in algos {
+            let mut crc = CRC::<u32>::from_algorithm(algo);
+            assert_eq!(crc.checksum(b"123456789"), check);
+
+            // Check residue.
+            let crc_new = CRC::<u32>::new_with_width(
+                algo.endian,
+                algo.width,
+                algo.poly,
+                algo.init,
+                algo.refin,
+                algo.refout,
+                algo.xorout,
+            );
+            assert_eq!(algo, crc_new.algorithm);
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{Algorithm, Endian};
+
+/// [CRC-21/CAN-FD](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-21-can-fd)
+pub const CRC_21_CAN_FD: Algorithm<u32> = Algorithm {
+    endian: Endian::Big,
+    width: 21,
+    poly: 0x102899,
+    init: 0x000000,
+    refin: false,
+    refout: false,
+    xorout: 0x000000,
+    check: 0x0ed841,
+    residue: 0x000000,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn check() {
+        // (Algorithm, check)
+        let algos = [(CRC_21_CAN_FD, 0x0ed841)];
+        for (algo, check) in algos {
+            let mut crc = CRC::<u32>::from_algorithm(algo);
+            assert_eq!(crc.checksum(b"123456789"), check);
+
+            // Check residue.
+            let crc_new = CRC::<u32>::new_with_width(
+                algo.endian,
+                algo.width,
+                algo.poly,
+                algo.init,
+                algo.refin,
+                algo.refout,
+                algo.xorout,
+            );
+            assert_eq!(algo, crc_new.algorithm);
+        }
+    }
+}
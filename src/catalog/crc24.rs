@@ -0,0 +1,142 @@
+use crate::{Algorithm, Endian};
+
+/// [CRC-24/BLE](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-24-ble)
+pub const CRC_24_BLE: Algorithm<u32> = Algorithm {
+    endian: Endian::Little,
+    width: 24,
+    poly: 0x00065b,
+    init: 0x555555,
+    refin: true,
+    refout: true,
+    xorout: 0x000000,
+    check: 0xc25a56,
+    residue: 0x000000,
+};
+
+/// [CRC-24/FLEXRAY-A](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-24-flexray-a)
+pub const CRC_24_FLEXRAY_A: Algorithm<u32> = Algorithm {
+    endian: Endian::Big,
+    width: 24,
+    poly: 0x5d6dcb,
+    init: 0xfedcba,
+    refin: false,
+    refout: false,
+    xorout: 0x000000,
+    check: 0x7979bd,
+    residue: 0x000000,
+};
+
+/// [CRC-24/FLEXRAY-B](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-24-flexray-b)
+pub const CRC_24_FLEXRAY_B: Algorithm<u32> = Algorithm {
+    endian: Endian::Big,
+    width: 24,
+    poly: 0x5d6dcb,
+    init: 0xabcdef,
+    refin: false,
+    refout: false,
+    xorout: 0x000000,
+    check: 0x1f23b8,
+    residue: 0x000000,
+};
+
+/// [CRC-24/INTERLAKEN](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-24-interlaken)
+pub const CRC_24_INTERLAKEN: Algorithm<u32> = Algorithm {
+    endian: Endian::Big,
+    width: 24,
+    poly: 0x328b63,
+    init: 0xffffff,
+    refin: false,
+    refout: false,
+    xorout: 0xffffff,
+    check: 0xb4f3e6,
+    residue: 0x144e63,
+};
+
+/// [CRC-24/LTE-A](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-24-lte-a)
+pub const CRC_24_LTE_A: Algorithm<u32> = Algorithm {
+    endian: Endian::Big,
+    width: 24,
+    poly: 0x864cfb,
+    init: 0x000000,
+    refin: false,
+    refout: false,
+    xorout: 0x000000,
+    check: 0xcde703,
+    residue: 0x000000,
+};
+
+/// [CRC-24/LTE-B](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-24-lte-b)
+pub const CRC_24_LTE_B: Algorithm<u32> = Algorithm {
+    endian: Endian::Big,
+    width: 24,
+    poly: 0x800063,
+    init: 0x000000,
+    refin: false,
+    refout: false,
+    xorout: 0x000000,
+    check: 0x23ef52,
+    residue: 0x000000,
+};
+
+/// [CRC-24/OPENPGP](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-24-openpgp)
+pub const CRC_24_OPENPGP: Algorithm<u32> = Algorithm {
+    endian: Endian::Big,
+    width: 24,
+    poly: 0x864cfb,
+    init: 0xb704ce,
+    refin: false,
+    refout: false,
+    xorout: 0x000000,
+    check: 0x21cf02,
+    residue: 0x000000,
+};
+
+/// [CRC-24/OS-9](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-24-os-9)
+pub const CRC_24_OS_9: Algorithm<u32> = Algorithm {
+    endian: Endian::Big,
+    width: 24,
+    poly: 0x800063,
+    init: 0xffffff,
+    refin: false,
+    refout: false,
+    xorout: 0xffffff,
+    check: 0x200fa5,
+    residue: 0x800fe3,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn check() {
+        // (Algorithm, check)
+        let algos = [
+            (CRC_24_BLE, 0xc25a56),
+            (CRC_24_FLEXRAY_A, 0x7979bd),
+            (CRC_24_FLEXRAY_B, 0x1f23b8),
+            (CRC_24_INTERLAKEN, 0xb4f3e6),
+            (CRC_24_LTE_A, 0xcde703),
+            (CRC_24_LTE_B, 0x23ef52),
+            (CRC_24_OPENPGP, 0x21cf02),
+            (CRC_24_OS_9, 0x200fa5),
+        ];
+        for (algo, check) in algos {
+            let mut crc = CRC::<u32>::from_algorithm(algo);
+            assert_eq!(crc.checksum(b"123456789"), check);
+
+            // Check residue.
+            let crc_new = CRC::<u32>::new_with_width(
+                algo.endian,
+                algo.width,
+                algo.poly,
+                algo.init,
+                algo.refin,
+                algo.refout,
+                algo.xorout,
+            );
+            assert_eq!(algo, crc_new.algorithm);
+        }
+    }
+}
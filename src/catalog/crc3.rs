@@ -0,0 +1,55 @@
+use crate::{Algorithm, Endian};
+
+/// [CRC-3/GSM](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-3-gsm)
+pub const CRC_3_GSM: Algorithm<u8> = Algorithm {
+    endian: Endian::Big,
+    width: 3,
+    poly: 0x3,
+    init: 0x0,
+    refin: false,
+    refout: false,
+    xorout: 0x7,
+    check: 0x4,
+    residue: 0x2,
+};
+
+/// [CRC-3/ROHC](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-3-rohc)
+pub const CRC_3_ROHC: Algorithm<u8> = Algorithm {
+    endian: Endian::Little,
+    width: 3,
+    poly: 0x3,
+    init: 0x7,
+    refin: true,
+    refout: true,
+    xorout: 0x0,
+    check: 0x6,
+    residue: 0x0,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn check() {
+        // (Algorithm, check)
+        let algos = [(CRC_3_GSM, 0x4), (CRC_3_ROHC, 0x6)];
+        for (algo, check) in algos {
+            let mut crc = CRC::<u8>::from_algorithm(algo);
+            assert_eq!(crc.checksum(b"123456789"), check);
+
+            // Check residue.
+            let crc_new = CRC::<u8>::new_with_width(
+                algo.endian,
+                algo.width,
+                algo.poly,
+                algo.init,
+                algo.refin,
+                algo.refout,
+                algo.xorout,
+            );
+            assert_eq!(algo, crc_new.algorithm);
+        }
+    }
+}
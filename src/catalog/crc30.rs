@@ -0,0 +1,42 @@
+use crate::{Algorithm, Endian};
+
+/// [CRC-30/CDMA](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-30-cdma)
+pub const CRC_30_CDMA: Algorithm<u32> = Algorithm {
+    endian: Endian::Big,
+    width: 30,
+    poly: 0x2030b9c7,
+    init: 0x3fffffff,
+    refin: false,
+    refout: false,
+    xorout: 0x3fffffff,
+    check: 0x04c34abf,
+    residue: 0x34efa55a,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn check() {
+        // (Algorithm, check)
+        let algos = [(CRC_30_CDMA, 0x04c34abf)];
+        for (algo, check) in algos {
+            let mut crc = CRC::<u32>::from_algorithm(algo);
+            assert_eq!(crc.checksum(b"123456789"), check);
+
+            // Check residue.
+            let crc_new = CRC::<u32>::new_with_width(
+                algo.endian,
+                algo.width,
+                algo.poly,
+                algo.init,
+                algo.refin,
+                algo.refout,
+                algo.xorout,
+            );
+            assert_eq!(algo, crc_new.algorithm);
+        }
+    }
+}
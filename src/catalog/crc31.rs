@@ -0,0 +1,42 @@
+use crate::{Algorithm, Endian};
+
+/// [CRC-31/PHILIPS](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-31-philips)
+pub const CRC_31_PHILIPS: Algorithm<u32> = Algorithm {
+    endian: Endian::Big,
+    width: 31,
+    poly: 0x04c11db7,
+    init: 0x7fffffff,
+    refin: false,
+    refout: false,
+    xorout: 0x7fffffff,
+    check: 0x0ce9e46c,
+    residue: 0x4eaf26f1,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn check() {
+        // (Algorithm, check)
+        let algos = [(CRC_31_PHILIPS, 0x0ce9e46c)];
+        for (algo, check) in algos {
+            let mut crc = CRC::<u32>::from_algorithm(algo);
+            assert_eq!(crc.checksum(b"123456789"), check);
+
+            // Check residue.
+            let crc_new = CRC::<u32>::new_with_width(
+                algo.endian,
+                algo.width,
+                algo.poly,
+                algo.init,
+                algo.refin,
+                algo.refout,
+                algo.xorout,
+            );
+            assert_eq!(algo, crc_new.algorithm);
+        }
+    }
+}
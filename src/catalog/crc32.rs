@@ -0,0 +1,198 @@
+use crate::{Algorithm, Endian};
+
+/// [CRC-32/AIXM](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-32-aixm)
+pub const CRC_32_AIXM: Algorithm<u32> = Algorithm {
+    endian: Endian::Big,
+    width: 32,
+    poly: 0x814141ab,
+    init: 0x00000000,
+    refin: false,
+    refout: false,
+    xorout: 0x00000000,
+    check: 0x3010bf7f,
+    residue: 0x00000000,
+};
+
+/// [CRC-32/AUTOSAR](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-32-autosar)
+pub const CRC_32_AUTOSAR: Algorithm<u32> = Algorithm {
+    endian: Endian::Little,
+    width: 32,
+    poly: 0xf4acfb13,
+    init: 0xffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffffffff,
+    check: 0x1697d06a,
+    residue: 0x904cddbf,
+};
+
+/// [CRC-32/BASE91-D](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-32-base91-d)
+pub const CRC_32_BASE91_D: Algorithm<u32> = Algorithm {
+    endian: Endian::Little,
+    width: 32,
+    poly: 0xa833982b,
+    init: 0xffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffffffff,
+    check: 0x87315576,
+    residue: 0x45270551,
+};
+
+/// [CRC-32/BZIP2](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-32-bzip2)
+pub const CRC_32_BZIP2: Algorithm<u32> = Algorithm {
+    endian: Endian::Big,
+    width: 32,
+    poly: 0x04c11db7,
+    init: 0xffffffff,
+    refin: false,
+    refout: false,
+    xorout: 0xffffffff,
+    check: 0xfc891918,
+    residue: 0xc704dd7b,
+};
+
+/// [CRC-32/CD-ROM-EDC](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-32-cd-rom-edc)
+pub const CRC_32_CD_ROM_EDC: Algorithm<u32> = Algorithm {
+    endian: Endian::Little,
+    width: 32,
+    poly: 0x8001801b,
+    init: 0x00000000,
+    refin: true,
+    refout: true,
+    xorout: 0x00000000,
+    check: 0x6ec2edc4,
+    residue: 0x00000000,
+};
+
+/// [CRC-32/CKSUM](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-32-cksum)
+pub const CRC_32_CKSUM: Algorithm<u32> = Algorithm {
+    endian: Endian::Big,
+    width: 32,
+    poly: 0x04c11db7,
+    init: 0x00000000,
+    refin: false,
+    refout: false,
+    xorout: 0xffffffff,
+    check: 0x765e7680,
+    residue: 0xc704dd7b,
+};
+
+/// [CRC-32/ISCSI](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-32-iscsi)
+pub const CRC_32_ISCSI: Algorithm<u32> = Algorithm {
+    endian: Endian::Little,
+    width: 32,
+    poly: 0x1edc6f41,
+    init: 0xffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffffffff,
+    check: 0xe3069283,
+    residue: 0xb798b438,
+};
+
+/// [CRC-32/ISO-HDLC](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-32-iso-hdlc)
+pub const CRC_32_ISO_HDLC: Algorithm<u32> = Algorithm {
+    endian: Endian::Little,
+    width: 32,
+    poly: 0x04c11db7,
+    init: 0xffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffffffff,
+    check: 0xcbf43926,
+    residue: 0xdebb20e3,
+};
+
+/// [CRC-32/JAMCRC](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-32-jamcrc)
+pub const CRC_32_JAMCRC: Algorithm<u32> = Algorithm {
+    endian: Endian::Little,
+    width: 32,
+    poly: 0x04c11db7,
+    init: 0xffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0x00000000,
+    check: 0x340bc6d9,
+    residue: 0x00000000,
+};
+
+/// [CRC-32/MEF](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-32-mef)
+pub const CRC_32_MEF: Algorithm<u32> = Algorithm {
+    endian: Endian::Little,
+    width: 32,
+    poly: 0x741b8cd7,
+    init: 0xffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0x00000000,
+    check: 0xd2c22f51,
+    residue: 0x00000000,
+};
+
+/// [CRC-32/MPEG-2](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-32-mpeg-2)
+pub const CRC_32_MPEG_2: Algorithm<u32> = Algorithm {
+    endian: Endian::Big,
+    width: 32,
+    poly: 0x04c11db7,
+    init: 0xffffffff,
+    refin: false,
+    refout: false,
+    xorout: 0x00000000,
+    check: 0x0376e6e7,
+    residue: 0x00000000,
+};
+
+/// [CRC-32/XFER](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-32-xfer)
+pub const CRC_32_XFER: Algorithm<u32> = Algorithm {
+    endian: Endian::Big,
+    width: 32,
+    poly: 0x000000af,
+    init: 0x00000000,
+    refin: false,
+    refout: false,
+    xorout: 0x00000000,
+    check: 0xbd0be338,
+    residue: 0x00000000,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn check() {
+        // (Algorithm, check)
+        let algos = [
+            (CRC_32_AIXM, 0x3010bf7f),
+            (CRC_32_AUTOSAR, 0x1697d06a),
+            (CRC_32_BASE91_D, 0x87315576),
+            (CRC_32_BZIP2, 0xfc891918),
+            (CRC_32_CD_ROM_EDC, 0x6ec2edc4),
+            (CRC_32_CKSUM, 0x765e7680),
+            (CRC_32_ISCSI, 0xe3069283),
+            (CRC_32_ISO_HDLC, 0xcbf43926),
+            (CRC_32_JAMCRC, 0x340bc6d9),
+            (CRC_32_MEF, 0xd2c22f51),
+            (CRC_32_MPEG_2, 0x0376e6e7),
+            (CRC_32_XFER, 0xbd0be338),
+        ];
+        for (algo, check) in algos {
+            let mut crc = CRC::<u32>::from_algorithm(algo);
+            assert_eq!(crc.checksum(b"123456789"), check);
+
+            // Check residue.
+            let crc_new = CRC::<u32>::new_with_width(
+                algo.endian,
+                algo.width,
+                algo.poly,
+                algo.init,
+                algo.refin,
+                algo.refout,
+                algo.xorout,
+            );
+            assert_eq!(algo, crc_new.algorithm);
+        }
+    }
+}
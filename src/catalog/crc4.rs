@@ -0,0 +1,55 @@
+use crate::{Algorithm, Endian};
+
+/// [CRC-4/G-704](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-4-g-704)
+pub const CRC_4_G_704: Algorithm<u8> = Algorithm {
+    endian: Endian::Little,
+    width: 4,
+    poly: 0x3,
+    init: 0x0,
+    refin: true,
+    refout: true,
+    xorout: 0x0,
+    check: 0x7,
+    residue: 0x0,
+};
+
+/// [CRC-4/INTERLAKEN](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-4-interlaken)
+pub const CRC_4_INTERLAKEN: Algorithm<u8> = Algorithm {
+    endian: Endian::Big,
+    width: 4,
+    poly: 0x3,
+    init: 0xf,
+    refin: false,
+    refout: false,
+    xorout: 0xf,
+    check: 0xb,
+    residue: 0x2,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn check() {
+        // (Algorithm, check)
+        let algos = [(CRC_4_G_704, 0x7), (CRC_4_INTERLAKEN, 0xb)];
+        for (algo, check) in algos {
+            let mut crc = CRC::<u8>::from_algorithm(algo);
+            assert_eq!(crc.checksum(b"123456789"), check);
+
+            // Check residue.
+            let crc_new = CRC::<u8>::new_with_width(
+                algo.endian,
+                algo.width,
+                algo.poly,
+                algo.init,
+                algo.refin,
+                algo.refout,
+                algo.xorout,
+            );
+            assert_eq!(algo, crc_new.algorithm);
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{Algorithm, Endian};
+
+/// [CRC-40/GSM](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-40-gsm)
+pub const CRC_40_GSM: Algorithm<u64> = Algorithm {
+    endian: Endian::Big,
+    width: 40,
+    poly: 0x0004820009,
+    init: 0x0000000000,
+    refin: false,
+    refout: false,
+    xorout: 0xffffffffff,
+    check: 0xd4164fc646,
+    residue: 0xc4ff8071ff,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn check() {
+        // (Algorithm, check)
+        let algos = [(CRC_40_GSM, 0xd4164fc646)];
+        for (algo, check) in algos {
+            let mut crc = CRC::<u64>::from_algorithm(algo);
+            assert_eq!(crc.checksum(b"123456789"), check);
+
+            // Check residue.
+            let crc_new = CRC::<u64>::new_with_width(
+                algo.endian,
+                algo.width,
+                algo.poly,
+                algo.init,
+                algo.refin,
+                algo.refout,
+                algo.xorout,
+            );
+            assert_eq!(algo, crc_new.algorithm);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{Algorithm, Endian};
+
+/// [CRC-5/EPC-C1G2](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-5-epc-c1g2)
+pub const CRC_5_EPC_C1G2: Algorithm<u8> = Algorithm {
+    endian: Endian::Big,
+    width: 5,
+    poly: 0x09,
+    init: 0x09,
+    refin: false,
+    refout: false,
+    xorout: 0x00,
+    check: 0x00,
+    residue: 0x00,
+};
+
+/// [CRC-5/G-704](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-5-g-704)
+pub const CRC_5_G_704: Algorithm<u8> = Algorithm {
+    endian: Endian::Little,
+    width: 5,
+    poly: 0x15,
+    init: 0x00,
+    refin: true,
+    refout: true,
+    xorout: 0x00,
+    check: 0x07,
+    residue: 0x00,
+};
+
+/// [CRC-5/USB](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-5-usb)
+pub const CRC_5_USB: Algorithm<u8> = Algorithm {
+    endian: Endian::Little,
+    width: 5,
+    poly: 0x05,
+    init: 0x1f,
+    refin: true,
+    refout: true,
+    xorout: 0x1f,
+    check: 0x19,
+    residue: 0x06,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn check() {
+        // (Algorithm, check)
+        let algos = [
+            (CRC_5_EPC_C1G2, 0x00),
+            (CRC_5_G_704, 0x07),
+            (CRC_5_USB, 0x19),
+        ];
+        for (algo, check) in algos {
+            let mut crc = CRC::<u8>::from_algorithm(algo);
+            assert_eq!(crc.checksum(b"123456789"), check);
+
+            // Check residue.
+            let crc_new = CRC::<u8>::new_with_width(
+                algo.endian,
+                algo.width,
+                algo.poly,
+                algo.init,
+                algo.refin,
+                algo.refout,
+                algo.xorout,
+            );
+            assert_eq!(algo, crc_new.algorithm);
+        }
+    }
+}
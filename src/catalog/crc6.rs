@@ -0,0 +1,100 @@
+use crate::{Algorithm, Endian};
+
+/// [CRC-6/CDMA2000-A](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-6-cdma2000-a)
+pub const CRC_6_CDMA2000_A: Algorithm<u8> = Algorithm {
+    endian: Endian::Big,
+    width: 6,
+    poly: 0x27,
+    init: 0x3f,
+    refin: false,
+    refout: false,
+    xorout: 0x00,
+    check: 0x0d,
+    residue: 0x00,
+};
+
+/// [CRC-6/CDMA2000-B](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-6-cdma2000-b)
+pub const CRC_6_CDMA2000_B: Algorithm<u8> = Algorithm {
+    endian: Endian::Big,
+    width: 6,
+    poly: 0x07,
+    init: 0x3f,
+    refin: false,
+    refout: false,
+    xorout: 0x00,
+    check: 0x3b,
+    residue: 0x00,
+};
+
+/// [CRC-6/DARC](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-6-darc)
+pub const CRC_6_DARC: Algorithm<u8> = Algorithm {
+    endian: Endian::Little,
+    width: 6,
+    poly: 0x19,
+    init: 0x00,
+    refin: true,
+    refout: true,
+    xorout: 0x00,
+    check: 0x26,
+    residue: 0x00,
+};
+
+/// [CRC-6/G-704](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-6-g-704)
+pub const CRC_6_G_704: Algorithm<u8> = Algorithm {
+    endian: Endian::Little,
+    width: 6,
+    poly: 0x03,
+    init: 0x00,
+    refin: true,
+    refout: true,
+    xorout: 0x00,
+    check: 0x06,
+    residue: 0x00,
+};
+
+/// [CRC-6/GSM](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-6-gsm)
+pub const CRC_6_GSM: Algorithm<u8> = Algorithm {
+    endian: Endian::Big,
+    width: 6,
+    poly: 0x2f,
+    init: 0x00,
+    refin: false,
+    refout: false,
+    xorout: 0x3f,
+    check: 0x13,
+    residue: 0x3a,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn check() {
+        // (Algorithm, check)
+        let algos = [
+            (CRC_6_CDMA2000_A, 0x0d),
+            (CRC_6_CDMA2000_B, 0x3b),
+            (CRC_6_DARC, 0x26),
+            (CRC_6_G_704, 0x06),
+            (CRC_6_GSM, 0x13),
+        ];
+        for (algo, check) in algos {
+            let mut crc = CRC::<u8>::from_algorithm(algo);
+            assert_eq!(crc.checksum(b"123456789"), check);
+
+            // Check residue.
+            let crc_new = CRC::<u8>::new_with_width(
+                algo.endian,
+                algo.width,
+                algo.poly,
+                algo.init,
+                algo.refin,
+                algo.refout,
+                algo.xorout,
+            );
+            assert_eq!(algo, crc_new.algorithm);
+        }
+    }
+}
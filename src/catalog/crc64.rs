@@ -0,0 +1,137 @@
+use crate::{Algorithm, Endian};
+
+/// [CRC-64/ECMA-182](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-64-ecma-182)
+pub const CRC_64_ECMA_182: Algorithm<u64> = Algorithm {
+    endian: Endian::Big,
+    width: 64,
+    poly: 0x42f0e1eba9ea3693,
+    init: 0x0000000000000000,
+    refin: false,
+    refout: false,
+    xorout: 0x0000000000000000,
+    check: 0x6c40df5f0b497347,
+    residue: 0x0000000000000000,
+};
+
+/// [CRC-64/GO-ISO](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-64-go-iso)
+pub const CRC_64_GO_ISO: Algorithm<u64> = Algorithm {
+    endian: Endian::Little,
+    width: 64,
+    poly: 0x000000000000001b,
+    init: 0xffffffffffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffffffffffffffff,
+    check: 0xb90956c775a41001,
+    residue: 0x5300000000000000,
+};
+
+/// [CRC-64/MS](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-64-ms)
+pub const CRC_64_MS: Algorithm<u64> = Algorithm {
+    endian: Endian::Little,
+    width: 64,
+    poly: 0x259c84cba6426349,
+    init: 0xffffffffffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0x0000000000000000,
+    check: 0x75d4b74f024eceea,
+    residue: 0x0000000000000000,
+};
+
+/// [CRC-64/NVME](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-64-nvme)
+pub const CRC_64_NVME: Algorithm<u64> = Algorithm {
+    endian: Endian::Little,
+    width: 64,
+    poly: 0xad93d23594c93659,
+    init: 0xffffffffffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffffffffffffffff,
+    check: 0xae8b14860a799888,
+    residue: 0xf310303b2b6f6e42,
+};
+
+/// [CRC-64/REDIS](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-64-redis)
+pub const CRC_64_REDIS: Algorithm<u64> = Algorithm {
+    endian: Endian::Little,
+    width: 64,
+    poly: 0xad93d23594c935a9,
+    init: 0x0000000000000000,
+    refin: true,
+    refout: true,
+    xorout: 0x0000000000000000,
+    check: 0xe9c6d914c4b8d9ca,
+    residue: 0x0000000000000000,
+};
+
+/// [CRC-64/WE](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-64-we)
+pub const CRC_64_WE: Algorithm<u64> = Algorithm {
+    endian: Endian::Big,
+    width: 64,
+    poly: 0x42f0e1eba9ea3693,
+    init: 0xffffffffffffffff,
+    refin: false,
+    refout: false,
+    xorout: 0xffffffffffffffff,
+    check: 0x62ec59e3f1a4f00a,
+    residue: 0xfcacbebd5931a992,
+};
+
+/// [CRC-64/XZ](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-64-xz)
+pub const CRC_64_XZ: Algorithm<u64> = Algorithm {
+    endian: Endian::Little,
+    width: 64,
+    poly: 0x42f0e1eba9ea3693,
+    init: 0xffffffffffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffffffffffffffff,
+    check: 0x995dc9bbdf1939fa,
+    residue: 0x49958c9abd7d353f,
+};
+
+/// Alias of [`CRC_64_ECMA_182`].
+pub const CRC_64: Algorithm<u64> = CRC_64_ECMA_182;
+/// Alias of [`CRC_64_XZ`].
+pub const CRC_64_GO_ECMA: Algorithm<u64> = CRC_64_XZ;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn check() {
+        // (Algorithm, check)
+        let algos = [
+            (CRC_64_ECMA_182, 0x6c40df5f0b497347),
+            (CRC_64_GO_ISO, 0xb90956c775a41001),
+            (CRC_64_MS, 0x75d4b74f024eceea),
+            (CRC_64_NVME, 0xae8b14860a799888),
+            (CRC_64_REDIS, 0xe9c6d914c4b8d9ca),
+            (CRC_64_WE, 0x62ec59e3f1a4f00a),
+            (CRC_64_XZ, 0x995dc9bbdf1939fa),
+        ];
+        for (algo, check) in algos {
+            let mut crc = CRC::<u64>::from_algorithm(algo);
+            assert_eq!(crc.checksum(b"123456789"), check);
+
+            let checksum = crc.checksum_to_endian_bytes(b"123456789");
+            let bytes = [b"123456789", &checksum[..]].concat();
+            assert!(crc.is_error_free_bytes(&bytes));
+
+            // Check residue.
+            let crc_new = CRC::<u64>::new_with_width(
+                algo.endian,
+                algo.width,
+                algo.poly,
+                algo.init,
+                algo.refin,
+                algo.refout,
+                algo.xorout,
+            );
+            assert_eq!(algo, crc_new.algorithm);
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{Algorithm, Endian};
+
+/// [CRC-7/MMC](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-7-mmc)
+pub const CRC_7_MMC: Algorithm<u8> = Algorithm {
+    endian: Endian::Big,
+    width: 7,
+    poly: 0x09,
+    init: 0x00,
+    refin: false,
+    refout: false,
+    xorout: 0x00,
+    check: 0x75,
+    residue: 0x00,
+};
+
+/// [CRC-7/ROHC](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-7-rohc)
+pub const CRC_7_ROHC: Algorithm<u8> = Algorithm {
+    endian: Endian::Little,
+    width: 7,
+    poly: 0x4f,
+    init: 0x7f,
+    refin: true,
+    refout: true,
+    xorout: 0x00,
+    check: 0x53,
+    residue: 0x00,
+};
+
+/// [CRC-7/UMTS](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-7-umts)
+pub const CRC_7_UMTS: Algorithm<u8> = Algorithm {
+    endian: Endian::Big,
+    width: 7,
+    poly: 0x45,
+    init: 0x00,
+    refin: false,
+    refout: false,
+    xorout: 0x00,
+    check: 0x61,
+    residue: 0x00,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn check() {
+        // (Algorithm, check)
+        let algos = [(CRC_7_MMC, 0x75), (CRC_7_ROHC, 0x53), (CRC_7_UMTS, 0x61)];
+        for (algo, check) in algos {
+            let mut crc = CRC::<u8>::from_algorithm(algo);
+            assert_eq!(crc.checksum(b"123456789"), check);
+
+            // Check residue.
+            let crc_new = CRC::<u8>::new_with_width(
+                algo.endian,
+                algo.width,
+                algo.poly,
+                algo.init,
+                algo.refin,
+                algo.refout,
+                algo.xorout,
+            );
+            assert_eq!(algo, crc_new.algorithm);
+        }
+    }
+}
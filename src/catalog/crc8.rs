@@ -0,0 +1,310 @@
+use crate::{Algorithm, Endian};
+
+/// [CRC-8/AUTOSAR](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-8-autosar)
+pub const CRC_8_AUTOSAR: Algorithm<u8> = Algorithm {
+    endian: Endian::Big,
+    width: 8,
+    poly: 0x2f,
+    init: 0xff,
+    refin: false,
+    refout: false,
+    xorout: 0xff,
+    check: 0xdf,
+    residue: 0x42,
+};
+
+/// [CRC-8/BLUETOOTH](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-8-bluetooth)
+pub const CRC_8_BLUETOOTH: Algorithm<u8> = Algorithm {
+    endian: Endian::Little,
+    width: 8,
+    poly: 0xa7,
+    init: 0x00,
+    refin: true,
+    refout: true,
+    xorout: 0x00,
+    check: 0x26,
+    residue: 0x00,
+};
+
+/// [CRC-8/CDMA2000](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-8-cdma2000)
+pub const CRC_8_CDMA2000: Algorithm<u8> = Algorithm {
+    endian: Endian::Big,
+    width: 8,
+    poly: 0x9b,
+    init: 0xff,
+    refin: false,
+    refout: false,
+    xorout: 0x00,
+    check: 0xda,
+    residue: 0x00,
+};
+
+/// [CRC-8/DARC](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-8-darc)
+pub const CRC_8_DARC: Algorithm<u8> = Algorithm {
+    endian: Endian::Little,
+    width: 8,
+    poly: 0x39,
+    init: 0x00,
+    refin: true,
+    refout: true,
+    xorout: 0x00,
+    check: 0x15,
+    residue: 0x00,
+};
+
+/// [CRC-8/DVB-S2](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-8-dvb-s2)
+pub const CRC_8_DVB_S2: Algorithm<u8> = Algorithm {
+    endian: Endian::Big,
+    width: 8,
+    poly: 0xd5,
+    init: 0x00,
+    refin: false,
+    refout: false,
+    xorout: 0x00,
+    check: 0xbc,
+    residue: 0x00,
+};
+
+/// [CRC-8/GSM-A](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-8-gsm-a)
+pub const CRC_8_GSM_A: Algorithm<u8> = Algorithm {
+    endian: Endian::Big,
+    width: 8,
+    poly: 0x1d,
+    init: 0x00,
+    refin: false,
+    refout: false,
+    xorout: 0x00,
+    check: 0x37,
+    residue: 0x00,
+};
+
+/// [CRC-8/GSM-B](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-8-gsm-b)
+pub const CRC_8_GSM_B: Algorithm<u8> = Algorithm {
+    endian: Endian::Big,
+    width: 8,
+    poly: 0x49,
+    init: 0x00,
+    refin: false,
+    refout: false,
+    xorout: 0xff,
+    check: 0x94,
+    residue: 0x53,
+};
+
+/// [CRC-8/HITAG](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-8-hitag)
+pub const CRC_8_HITAG: Algorithm<u8> = Algorithm {
+    endian: Endian::Big,
+    width: 8,
+    poly: 0x1d,
+    init: 0xff,
+    refin: false,
+    refout: false,
+    xorout: 0x00,
+    check: 0xb4,
+    residue: 0x00,
+};
+
+/// [CRC-8/I-432-1](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-8-i-432-1)
+pub const CRC_8_I_432_1: Algorithm<u8> = Algorithm {
+    endian: Endian::Big,
+    width: 8,
+    poly: 0x07,
+    init: 0x00,
+    refin: false,
+    refout: false,
+    xorout: 0x55,
+    check: 0xa1,
+    residue: 0xac,
+};
+
+/// [CRC-8/I-CODE](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-8-i-code)
+pub const CRC_8_I_CODE: Algorithm<u8> = Algorithm {
+    endian: Endian::Big,
+    width: 8,
+    poly: 0x1d,
+    init: 0xfd,
+    refin: false,
+    refout: false,
+    xorout: 0x00,
+    check: 0x7e,
+    residue: 0x00,
+};
+
+/// [CRC-8/LTE](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-8-lte)
+pub const CRC_8_LTE: Algorithm<u8> = Algorithm {
+    endian: Endian::Big,
+    width: 8,
+    poly: 0x9b,
+    init: 0x00,
+    refin: false,
+    refout: false,
+    xorout: 0x00,
+    check: 0xea,
+    residue: 0x00,
+};
+
+/// [CRC-8/MAXIM-DOW](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-8-maxim-dow)
+pub const CRC_8_MAXIM_DOW: Algorithm<u8> = Algorithm {
+    endian: Endian::Little,
+    width: 8,
+    poly: 0x31,
+    init: 0x00,
+    refin: true,
+    refout: true,
+    xorout: 0x00,
+    check: 0xa1,
+    residue: 0x00,
+};
+
+/// [CRC-8/MIFARE-MAD](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-8-mifare-mad)
+pub const CRC_8_MIFARE_MAD: Algorithm<u8> = Algorithm {
+    endian: Endian::Big,
+    width: 8,
+    poly: 0x1d,
+    init: 0xc7,
+    refin: false,
+    refout: false,
+    xorout: 0x00,
+    check: 0x99,
+    residue: 0x00,
+};
+
+/// [CRC-8/NRSC-5](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-8-nrsc-5)
+pub const CRC_8_NRSC_5: Algorithm<u8> = Algorithm {
+    endian: Endian::Big,
+    width: 8,
+    poly: 0x31,
+    init: 0xff,
+    refin: false,
+    refout: false,
+    xorout: 0x00,
+    check: 0xf7,
+    residue: 0x00,
+};
+
+/// [CRC-8/OPENSAFETY](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-8-opensafety)
+pub const CRC_8_OPENSAFETY: Algorithm<u8> = Algorithm {
+    endian: Endian::Big,
+    width: 8,
+    poly: 0x2f,
+    init: 0x00,
+    refin: false,
+    refout: false,
+    xorout: 0x00,
+    check: 0x3e,
+    residue: 0x00,
+};
+
+/// [CRC-8/ROHC](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-8-rohc)
+pub const CRC_8_ROHC: Algorithm<u8> = Algorithm {
+    endian: Endian::Little,
+    width: 8,
+    poly: 0x07,
+    init: 0xff,
+    refin: true,
+    refout: true,
+    xorout: 0x00,
+    check: 0xd0,
+    residue: 0x00,
+};
+
+/// [CRC-8/SAE-J1850](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-8-sae-j1850)
+pub const CRC_8_SAE_J1850: Algorithm<u8> = Algorithm {
+    endian: Endian::Big,
+    width: 8,
+    poly: 0x1d,
+    init: 0xff,
+    refin: false,
+    refout: false,
+    xorout: 0xff,
+    check: 0x4b,
+    residue: 0xc4,
+};
+
+/// [CRC-8/SMBUS](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-8-smbus)
+pub const CRC_8_SMBUS: Algorithm<u8> = Algorithm {
+    endian: Endian::Big,
+    width: 8,
+    poly: 0x07,
+    init: 0x00,
+    refin: false,
+    refout: false,
+    xorout: 0x00,
+    check: 0xf4,
+    residue: 0x00,
+};
+
+/// [CRC-8/TECH-3250](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-8-tech-3250)
+pub const CRC_8_TECH_3250: Algorithm<u8> = Algorithm {
+    endian: Endian::Little,
+    width: 8,
+    poly: 0x1d,
+    init: 0xff,
+    refin: true,
+    refout: true,
+    xorout: 0x00,
+    check: 0x97,
+    residue: 0x00,
+};
+
+/// [CRC-8/WCDMA](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-8-wcdma)
+pub const CRC_8_WCDMA: Algorithm<u8> = Algorithm {
+    endian: Endian::Little,
+    width: 8,
+    poly: 0x9b,
+    init: 0x00,
+    refin: true,
+    refout: true,
+    xorout: 0x00,
+    check: 0x25,
+    residue: 0x00,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn check() {
+        // (Algorithm, check)
+        let algos = [
+            (CRC_8_AUTOSAR, 0xdf),
+            (CRC_8_BLUETOOTH, 0x26),
+            (CRC_8_CDMA2000, 0xda),
+            (CRC_8_DARC, 0x15),
+            (CRC_8_DVB_S2, 0xbc),
+            (CRC_8_GSM_A, 0x37),
+            (CRC_8_GSM_B, 0x94),
+            (CRC_8_HITAG, 0xb4),
+            (CRC_8_I_432_1, 0xa1),
+            (CRC_8_I_CODE, 0x7e),
+            (CRC_8_LTE, 0xea),
+            (CRC_8_MAXIM_DOW, 0xa1),
+            (CRC_8_MIFARE_MAD, 0x99),
+            (CRC_8_NRSC_5, 0xf7),
+            (CRC_8_OPENSAFETY, 0x3e),
+            (CRC_8_ROHC, 0xd0),
+            (CRC_8_SAE_J1850, 0x4b),
+            (CRC_8_SMBUS, 0xf4),
+            (CRC_8_TECH_3250, 0x97),
+            (CRC_8_WCDMA, 0x25),
+        ];
+        for (algo, check) in algos {
+            let mut crc = CRC::<u8>::from_algorithm(algo);
+            assert_eq!(crc.checksum(b"123456789"), check);
+
+            // Check residue.
+            let crc_new = CRC::<u8>::new_with_width(
+                algo.endian,
+                algo.width,
+                algo.poly,
+                algo.init,
+                algo.refin,
+                algo.refout,
+                algo.xorout,
+            );
+            assert_eq!(algo, crc_new.algorithm);
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{Algorithm, Endian};
+
+/// [CRC-82/DARC](https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-82-darc)
+pub const CRC_82_DARC: Algorithm<u128> = Algorithm {
+    endian: Endian::Little,
+    width: 82,
+    poly: 0x0308c0111011401440411,
+    init: 0x000000000000000000000,
+    refin: true,
+    refout: true,
+    xorout: 0x000000000000000000000,
+    check: 0x09ea83f625023801fd612,
+    residue: 0x000000000000000000000,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn check() {
+        // (Algorithm, check)
+        let algos = [(CRC_82_DARC, 0x09ea83f625023801fd612)];
+        for (algo, check) in algos {
+            let mut crc = CRC::<u128>::from_algorithm(algo);
+            assert_eq!(crc.checksum(b"123456789"), check);
+
+            // Check residue.
+            let crc_new = CRC::<u128>::new_with_width(
+                algo.endian,
+                algo.width,
+                algo.poly,
+                algo.init,
+                algo.refin,
+                algo.refout,
+                algo.xorout,
+            );
+            assert_eq!(algo, crc_new.algorithm);
+        }
+    }
+}
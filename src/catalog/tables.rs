@@ -0,0 +1,52 @@
+use crate::Algorithm;
+
+macro_rules! static_table {
+    ( $( $(#[$meta:meta])* $name:ident: $t:ty ),* $(,)? ) => ($(
+        $(#[$meta])*
+        pub static $name: [$t; 256] =
+            Algorithm::<$t>::create_table_with_width(super::$name.width, super::$name.poly, super::$name.refin);
+    )*)
+}
+
+static_table! {
+    /// Table of [`CRC_16_IBM_3740`](super::CRC_16_IBM_3740), also known as CRC-16/CCITT-FALSE.
+    CRC_16_IBM_3740: u16,
+    /// Table of [`CRC_16_KERMIT`](super::CRC_16_KERMIT), also known as CRC-16/CCITT.
+    CRC_16_KERMIT: u16,
+    /// Table of [`CRC_32_ISCSI`](super::CRC_32_ISCSI), also known as CRC-32C.
+    CRC_32_ISCSI: u32,
+    /// Table of [`CRC_32_ISO_HDLC`](super::CRC_32_ISO_HDLC).
+    CRC_32_ISO_HDLC: u32,
+    /// Table of [`CRC_64_XZ`](super::CRC_64_XZ).
+    CRC_64_XZ: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{catalog, CRC};
+
+    #[test]
+    fn tables() {
+        assert_eq!(
+            CRC::<u16>::with_table(catalog::CRC_16_IBM_3740, CRC_16_IBM_3740),
+            CRC::<u16>::from_algorithm(catalog::CRC_16_IBM_3740)
+        );
+        assert_eq!(
+            CRC::<u16>::with_table(catalog::CRC_16_KERMIT, CRC_16_KERMIT),
+            CRC::<u16>::from_algorithm(catalog::CRC_16_KERMIT)
+        );
+        assert_eq!(
+            CRC::<u32>::with_table(catalog::CRC_32_ISCSI, CRC_32_ISCSI),
+            CRC::<u32>::from_algorithm(catalog::CRC_32_ISCSI)
+        );
+        assert_eq!(
+            CRC::<u32>::with_table(catalog::CRC_32_ISO_HDLC, CRC_32_ISO_HDLC),
+            CRC::<u32>::from_algorithm(catalog::CRC_32_ISO_HDLC)
+        );
+        assert_eq!(
+            CRC::<u64>::with_table(catalog::CRC_64_XZ, CRC_64_XZ),
+            CRC::<u64>::from_algorithm(catalog::CRC_64_XZ)
+        );
+    }
+}
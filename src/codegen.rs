@@ -0,0 +1,160 @@
+//! Tables rendered as source code, to embed the same tables in firmware written in other languages.
+//!
+//! Tables are of [`CrcWidth::create_tables`], so `N` of 1 is the table of `[T; 256]` and 8 is of [`Slice8`](crate::Slice8).
+//! Requires the `std` feature.
+//!
+//! ```
+//! use mycrc::{codegen, Algorithm, Endian};
+//!
+//! // CRC-32/ISCSI
+//! let (crc32c, _, _) = Algorithm::<u32>::new(Endian::Little, 0x1edc6f41, !0, true, true, !0);
+//! let header = codegen::c_header::<u32, 1>("crc32c_table", &crc32c);
+//! assert!(header.contains("static const uint32_t crc32c_table[256] = {\n    0x00000000, 0xf26b8303,"));
+//! let source = codegen::rust_static::<u32, 8>("CRC32C_TABLES", &crc32c);
+//! assert!(source.contains("pub static CRC32C_TABLES: [[u32; 256]; 8] = [\n    [\n        0x00000000,"));
+//! ```
+
+use crate::{Algorithm, CrcWidth};
+use core::{
+    fmt::{LowerHex, Write},
+    mem,
+};
+use std::{format, string::String};
+
+/// Entries per line.
+const ENTRIES_PER_LINE: usize = 8;
+
+/// Comment on the parameters of `algorithm`.
+fn describe<T: LowerHex>(algorithm: &Algorithm<T>) -> String {
+    let digits = (algorithm.width as usize).div_ceil(4);
+    format!(
+        "width={} poly={:#0w$x} init={:#0w$x} refin={} refout={} xorout={:#0w$x}",
+        algorithm.width,
+        algorithm.poly,
+        algorithm.init,
+        algorithm.refin,
+        algorithm.refout,
+        algorithm.xorout,
+        w = digits + 2,
+    )
+}
+
+/// Write the entries of `tables`, in `open` and `close` for each table if more than one.
+fn write_tables<T: LowerHex, const N: usize>(
+    out: &mut String,
+    tables: &[[T; 256]; N],
+    (open, close): (char, char),
+) {
+    let digits = 2 * mem::size_of::<T>();
+    let (indent, nested) = if N == 1 {
+        ("    ", false)
+    } else {
+        ("        ", true)
+    };
+    for table in tables {
+        if nested {
+            out.push_str("    ");
+            out.push(open);
+            out.push('\n');
+        }
+        for line in table.chunks(ENTRIES_PER_LINE) {
+            out.push_str(indent);
+            for (i, entry) in line.iter().enumerate() {
+                let separator = if i + 1 == line.len() { ",\n" } else { ", " };
+                let _ = write!(out, "{entry:#0w$x}{separator}", w = digits + 2);
+            }
+        }
+        if nested {
+            out.push_str("    ");
+            out.push(close);
+            out.push_str(",\n");
+        }
+    }
+}
+
+/// C header that defines `name` as a `static const` array of the tables of `algorithm`.
+///
+/// # Panics
+/// Panics if `T` is `u128`, which C has no literals of.
+pub fn c_header<T: CrcWidth + LowerHex, const N: usize>(
+    name: &str,
+    algorithm: &Algorithm<T>,
+) -> String {
+    let ty = match mem::size_of::<T>() {
+        1 => "uint8_t",
+        2 => "uint16_t",
+        4 => "uint32_t",
+        8 => "uint64_t",
+        _ => panic!("T is wider than uint64_t"),
+    };
+    let guard = name.to_uppercase() + "_H";
+    let dimensions = if N == 1 {
+        String::from("[256]")
+    } else {
+        format!("[{N}][256]")
+    };
+
+    let mut out = String::new();
+    let _ = writeln!(out, "/* Generated by mycrc: {} */", describe(algorithm));
+    let _ = writeln!(
+        out,
+        "#ifndef {guard}\n#define {guard}\n\n#include <stdint.h>\n"
+    );
+    let _ = writeln!(out, "static const {ty} {name}{dimensions} = {{");
+    write_tables(&mut out, &T::create_tables::<N>(algorithm), ('{', '}'));
+    let _ = writeln!(out, "}};\n\n#endif /* {guard} */");
+    out
+}
+
+/// Rust source that defines `name` as a `pub static` array of the tables of `algorithm`.
+pub fn rust_static<T: CrcWidth + LowerHex, const N: usize>(
+    name: &str,
+    algorithm: &Algorithm<T>,
+) -> String {
+    let ty = match mem::size_of::<T>() {
+        1 => "u8",
+        2 => "u16",
+        4 => "u32",
+        8 => "u64",
+        _ => "u128",
+    };
+    let array = if N == 1 {
+        format!("[{ty}; 256]")
+    } else {
+        format!("[[{ty}; 256]; {N}]")
+    };
+
+    let mut out = String::new();
+    let _ = writeln!(out, "// Generated by mycrc: {}", describe(algorithm));
+    let _ = writeln!(out, "pub static {name}: {array} = [");
+    write_tables(&mut out, &T::create_tables::<N>(algorithm), ('[', ']'));
+    out.push_str("];\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Endian, CRC};
+
+    #[test]
+    fn codegen() {
+        // CRC-16/ARC
+        let crc = CRC::<u16>::new(Endian::Little, 0x8005, 0, true, true, 0);
+        let header = c_header::<u16, 1>("crc16_table", &crc.algorithm);
+        assert!(header.starts_with(
+            "/* Generated by mycrc: width=16 poly=0x8005 init=0x0000 refin=true refout=true xorout=0x0000 */\n"
+        ));
+        assert!(header.contains("#ifndef CRC16_TABLE_H\n"));
+        assert!(header.contains("static const uint16_t crc16_table[256] = {\n    0x0000, 0xc0c1,"));
+        assert!(header.ends_with("0x4040,\n};\n\n#endif /* CRC16_TABLE_H */\n"));
+        assert_eq!(header.lines().count(), 10 + 256 / 8);
+
+        let source = rust_static::<u16, 2>("CRC16_TABLES", &crc.algorithm);
+        assert!(
+            source.contains("pub static CRC16_TABLES: [[u16; 256]; 2] = [\n    [\n        0x0000,")
+        );
+        assert!(source.ends_with("    ],\n];\n"));
+        assert_eq!(source.lines().count(), 3 + 2 * (2 + 256 / 8));
+    }
+}
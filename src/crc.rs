@@ -1,18 +1,55 @@
-use crate::{Algorithm, Endian};
-use core::mem;
+use crate::{
+    Algorithm, Bitwise, ChecksumLayout, CrcError, CrcMatrix, CrcWidth, CreateTable, Digest, Endian,
+    HwRegister, LfsrTrace, MismatchError, Nibble, Notation, SelfTestError, SliceBy, Table,
+    CHECK_BYTES,
+};
+use core::ops::Deref;
 
 /// Cyclic redundancy check.
+///
+/// `B` is the [`Table`] used to calculate bytes.
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// use mycrc::{catalog::CRC_32_ISCSI, Slice8, CRC};
+///
+/// let mut crc32 = CRC::<u32, Slice8<u32>>::from_algorithm(CRC_32_ISCSI);
+/// assert_eq!(crc32.checksum(b"123456789"), 0xe3069283);
+/// # }
+/// ```
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-pub struct CRC<T> {
+pub struct CRC<T, B = [T; 256]> {
     pub algorithm: Algorithm<T>,
+    /// Register kept in the most significant bits, or reflected over all bits if `refin`.
+    /// See [`CRC::raw_value`] for the register of `width` bits.
     pub value: T,
-    pub table: [T; 256],
+    pub table: B,
+    /// Number of bytes since the value is initialized, see [`CRC::len`].
+    ///
+    /// It is compared and hashed with the other fields,
+    /// so CRCs of the same value after different numbers of bytes are not equal.
+    pub len: usize,
 }
 
+/// [`CRC`] that borrows the table, e.g. a `static` one, so that it is a few bytes to copy.
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// use mycrc::{catalog::{self, tables}, CrcRef};
+///
+/// let mut crc32 = CrcRef::<u32>::with_table(catalog::CRC_32_ISO_HDLC, &tables::CRC_32_ISO_HDLC);
+/// assert_eq!(crc32.checksum(b"123456789"), 0xcbf43926);
+/// assert!(core::mem::size_of_val(&crc32) < 64);
+/// # }
+/// ```
+pub type CrcRef<'a, T, B = [T; 256]> = CRC<T, &'a B>;
+
 macro_rules! crc_impl {
     ( $( $t:ty ),* ) => ($(
         impl CRC<$t> {
-            /// Create your own CRC.
+            /// Create your own CRC of `<$t>::BITS` bits.
             pub const fn new(
                 endian: Endian,
                 poly: $t,
@@ -21,8 +58,22 @@ macro_rules! crc_impl {
                 refout: bool,
                 xorout: $t,
             ) -> Self {
-                let (algorithm, value, table) = Algorithm::<$t>::new(
+                Self::new_with_width(endian, <$t>::BITS as u8, poly, init, refin, refout, xorout)
+            }
+
+            /// Create your own CRC of `width` bits.
+            pub const fn new_with_width(
+                endian: Endian,
+                width: u8,
+                poly: $t,
+                init: $t,
+                refin: bool,
+                refout: bool,
+                xorout: $t,
+            ) -> Self {
+                let (algorithm, value, table) = Algorithm::<$t>::new_with_width(
                     endian,
+                    width,
                     poly,
                     init,
                     refin,
@@ -33,186 +84,1400 @@ macro_rules! crc_impl {
                 Self {
                     algorithm,
                     value,
+                    len: 0,
                     table,
                 }
             }
 
+            /// Create your own CRC from a polynomial in any [`Notation`].
+            #[allow(clippy::too_many_arguments)]
+            pub const fn new_with_notation(
+                endian: Endian,
+                width: u8,
+                poly: $t,
+                notation: Notation,
+                init: $t,
+                refin: bool,
+                refout: bool,
+                xorout: $t,
+            ) -> Self {
+                let poly = Algorithm::<$t>::poly_to_normal(width, poly, notation);
+                Self::new_with_width(endian, width, poly, init, refin, refout, xorout)
+            }
+
+            /// Assert that the checksum of [`CHECK_BYTES`](crate::CHECK_BYTES) is `check`.
+            ///
+            /// In const context, mis-typed parameters fail at compile time.
+            /// See [`Algorithm::assert_check`] to assert that `algorithm.check` matches the parameters instead.
+            ///
+            /// # Panics
+            /// Panics if `algorithm.check` is not equal to `check`.
+            pub const fn assert_check_eq(self, check: $t) -> Self {
+                assert!(self.algorithm.check == check, "algorithm.check is not equal to check");
+                self
+            }
+
             /// The algorithm initializes the value and creates the table.
             ///
             /// # Safety
             /// [`Algorithm`] information must be correct.
             pub const fn from_algorithm(algorithm: Algorithm<$t>) -> Self {
-                let value = Algorithm::<$t>::initialize(algorithm.init, algorithm.refin);
-                let table = Algorithm::<$t>::create_table(algorithm.poly, algorithm.refin);
+                let value = Algorithm::<$t>::initialize_with_width(algorithm.width, algorithm.init, algorithm.refin);
+                let table = Algorithm::<$t>::create_table_with_width(algorithm.width, algorithm.poly, algorithm.refin);
                 Self {
                     algorithm,
                     value,
+                    len: 0,
                     table,
                 }
             }
+        }
 
-            /// Initialize value.
-            pub fn initialize(&mut self) -> &mut Self {
-                self.value = Algorithm::<$t>::initialize(self.algorithm.init, self.algorithm.refin);
-                self
+        impl CRC<$t> {
+            /// The algorithm initializes the value and uses `table` instead of creating it.
+            ///
+            /// With the tables of [`catalog::tables`](crate::catalog::tables), no table is created at all.
+            ///
+            /// `table` must be the table of the algorithm.
+            pub const fn with_table(algorithm: Algorithm<$t>, table: [$t; 256]) -> Self {
+                let value = Algorithm::<$t>::initialize_with_width(algorithm.width, algorithm.init, algorithm.refin);
+                Self {
+                    algorithm,
+                    value,
+                    len: 0,
+                    table,
+                }
             }
+        }
 
-            /// Caluculate bytes.
-            pub fn calc_bytes(&mut self, bytes: &[u8]) -> &mut Self {
-                self.value = Algorithm::<$t>::calc_bytes_with_values(self.algorithm.refin, self.value, bytes, &self.table);
-                self
+        impl<'a> CRC<$t, &'a [$t; 256]> {
+            /// The algorithm initializes the value and borrows `table`.
+            ///
+            /// `table` must be the table of the algorithm.
+            pub const fn with_table(algorithm: Algorithm<$t>, table: &'a [$t; 256]) -> Self {
+                let value = Algorithm::<$t>::initialize_with_width(algorithm.width, algorithm.init, algorithm.refin);
+                Self {
+                    algorithm,
+                    value,
+                    len: 0,
+                    table,
+                }
             }
+        }
 
-            /// Optional reflection.
-            pub const fn optional_reflection(&self) -> $t {
-                Algorithm::<$t>::optional_reflection(self.algorithm.refin, self.algorithm.refout, self.value)
+        impl<const N: usize> CRC<$t, SliceBy<$t, N>> {
+            /// Create your own CRC of `<$t>::BITS` bits with slicing-by-`N` tables.
+            pub const fn new(
+                endian: Endian,
+                poly: $t,
+                init: $t,
+                refin: bool,
+                refout: bool,
+                xorout: $t,
+            ) -> Self {
+                Self::new_with_width(endian, <$t>::BITS as u8, poly, init, refin, refout, xorout)
             }
 
-            /// Finalize value.
-            /// Change value to checksum.
-            pub const fn finalize(&self) -> $t {
-                Algorithm::<$t>::finalize(self.algorithm.refin, self.algorithm.refout, self.algorithm.xorout, self.value)
+            /// Create your own CRC of `width` bits with slicing-by-`N` tables.
+            pub const fn new_with_width(
+                endian: Endian,
+                width: u8,
+                poly: $t,
+                init: $t,
+                refin: bool,
+                refout: bool,
+                xorout: $t,
+            ) -> Self {
+                let (algorithm, _, _) = Algorithm::<$t>::new_with_width(
+                    endian,
+                    width,
+                    poly,
+                    init,
+                    refin,
+                    refout,
+                    xorout,
+                );
+                Self::from_algorithm(algorithm)
             }
 
-            /// Finalize to endian bytes.
-            pub const fn finalize_to_endian_bytes(&self) -> [u8; mem::size_of::<$t>()] {
-                Algorithm::<$t>::finalize_to_endian_bytes(self.algorithm.endian, self.algorithm.refin, self.algorithm.refout, self.algorithm.xorout, self.value)
+            /// The algorithm initializes the value and creates the slicing-by-`N` tables.
+            pub const fn from_algorithm(algorithm: Algorithm<$t>) -> Self {
+                #[allow(clippy::let_unit_value)]
+                let () = SliceBy::<$t, N>::VALID_N;
+
+                let value = Algorithm::<$t>::initialize_with_width(algorithm.width, algorithm.init, algorithm.refin);
+                let tables = Algorithm::<$t>::create_tables(algorithm.width, algorithm.poly, algorithm.refin);
+                Self {
+                    algorithm,
+                    value,
+                    len: 0,
+                    table: SliceBy { tables },
+                }
             }
+        }
 
-            /// Checksum function.
-            pub fn checksum(&mut self, bytes: &[u8]) -> $t {
-                self.initialize().calc_bytes(bytes).finalize()
+        impl CRC<$t, Nibble<$t>> {
+            /// Create your own CRC of `<$t>::BITS` bits with the table of 16 entries.
+            pub const fn new(
+                endian: Endian,
+                poly: $t,
+                init: $t,
+                refin: bool,
+                refout: bool,
+                xorout: $t,
+            ) -> Self {
+                Self::new_with_width(endian, <$t>::BITS as u8, poly, init, refin, refout, xorout)
             }
 
-            /// Checksum to endian bytes.
-            pub fn checksum_to_endian_bytes(&mut self, bytes: &[u8]) -> [u8; mem::size_of::<$t>()] {
-                self.initialize().calc_bytes(bytes).finalize_to_endian_bytes()
+            /// Create your own CRC of `width` bits with the table of 16 entries.
+            pub const fn new_with_width(
+                endian: Endian,
+                width: u8,
+                poly: $t,
+                init: $t,
+                refin: bool,
+                refout: bool,
+                xorout: $t,
+            ) -> Self {
+                let (algorithm, _, _) = Algorithm::<$t>::new_with_width(
+                    endian,
+                    width,
+                    poly,
+                    init,
+                    refin,
+                    refout,
+                    xorout,
+                );
+                Self::from_algorithm(algorithm)
             }
 
-            /// Check if `value` is error-free.
-            /// Returns `true` if error-free.
-            pub fn is_error_free(&mut self) -> bool {
-                if self.optional_reflection() == self.algorithm.residue {
-                    true
-                } else {
-                    false
+            /// The algorithm initializes the value and creates the table of 16 entries.
+            pub const fn from_algorithm(algorithm: Algorithm<$t>) -> Self {
+                let value = Algorithm::<$t>::initialize_with_width(algorithm.width, algorithm.init, algorithm.refin);
+                let table = Algorithm::<$t>::create_nibble_table(algorithm.width, algorithm.poly, algorithm.refin);
+                Self {
+                    algorithm,
+                    value,
+                    len: 0,
+                    table: Nibble { table },
                 }
             }
+        }
+
+        impl CRC<$t, Bitwise> {
+            /// Create your own CRC of `<$t>::BITS` bits without a table.
+            pub const fn new(
+                endian: Endian,
+                poly: $t,
+                init: $t,
+                refin: bool,
+                refout: bool,
+                xorout: $t,
+            ) -> Self {
+                Self::new_with_width(endian, <$t>::BITS as u8, poly, init, refin, refout, xorout)
+            }
+
+            /// Create your own CRC of `width` bits without a table.
+            pub const fn new_with_width(
+                endian: Endian,
+                width: u8,
+                poly: $t,
+                init: $t,
+                refin: bool,
+                refout: bool,
+                xorout: $t,
+            ) -> Self {
+                let (algorithm, _, _) = Algorithm::<$t>::new_with_width(
+                    endian,
+                    width,
+                    poly,
+                    init,
+                    refin,
+                    refout,
+                    xorout,
+                );
+                Self::from_algorithm(algorithm)
+            }
 
-            /// Check if bytes [message + checksum] are error-free.
-            /// Returns `true` if error-free.
-            pub fn is_error_free_bytes(&mut self, bytes: &[u8]) -> bool {
-                self.initialize().calc_bytes(bytes).is_error_free()
+            /// The algorithm initializes the value.
+            pub const fn from_algorithm(algorithm: Algorithm<$t>) -> Self {
+                let value = Algorithm::<$t>::initialize_with_width(algorithm.width, algorithm.init, algorithm.refin);
+                Self {
+                    algorithm,
+                    value,
+                    len: 0,
+                    table: Bitwise,
+                }
             }
         }
     )*)
 }
 
-crc_impl!(u16, u32, u64, u128);
+crc_impl!(u8, u16, u32, u64, u128);
+
+impl<T: CrcWidth, B: CreateTable<T>> CRC<T, B> {
+    /// The algorithm initializes the value and creates the table at runtime.
+    ///
+    /// Unlike `from_algorithm`, this is not `const` and works with any [`CreateTable`].
+    pub fn create(algorithm: Algorithm<T>) -> Self {
+        Self {
+            algorithm,
+            value: T::initialize(&algorithm),
+            table: B::create(&algorithm),
+            len: 0,
+        }
+    }
+}
+
+impl<T: CrcWidth, B: Table<T>> CRC<T, B> {
+    /// Borrow the table, so that the returned [`CrcRef`] is a few bytes to copy.
+    pub fn as_crc_ref(&self) -> CrcRef<'_, T, B> {
+        CRC {
+            algorithm: self.algorithm,
+            value: self.value,
+            table: &self.table,
+            len: self.len,
+        }
+    }
+
+    /// [`Digest`] with the initialized value that borrows the algorithm and the table.
+    ///
+    /// Many streams can be calculated with one `CRC` at the same time.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_32_ISCSI, CRC};
+    ///
+    /// let crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+    /// let (mut a, mut b) = (crc32.digest(), crc32.digest());
+    /// a.update(b"1234");
+    /// b.update(b"123");
+    /// a.update(b"56789");
+    /// b.update(b"456789");
+    /// assert_eq!(a.finalize(), 0xe3069283);
+    /// assert_eq!(b.finalize(), 0xe3069283);
+    /// # }
+    /// ```
+    pub fn digest(&self) -> Digest<'_, T, B> {
+        Digest::new(&self.algorithm, &self.table)
+    }
+
+    /// Initialize value.
+    pub fn initialize(&mut self) -> &mut Self {
+        self.value = T::initialize(&self.algorithm);
+        self.len = 0;
+        self
+    }
+
+    /// Replace `init` without creating the table again, and initialize value.
+    /// `check` of the algorithm is recomputed.
+    ///
+    /// Useful when `init` changes per connection or packet, e.g. Bluetooth LE whose CRC init is given by the link layer.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_24_BLE, CRC};
+    ///
+    /// let mut crc24 = CRC::<u32>::from_algorithm(CRC_24_BLE);
+    /// // CRCInit of the connection.
+    /// let pdu_crc = crc24.with_init(0x9a3f21).calc_bytes(b"PDU").finalize();
+    /// # }
+    /// ```
+    pub fn with_init(&mut self, init: T) -> &mut Self {
+        self.algorithm.init = init;
+        let value = T::initialize(&self.algorithm);
+        let check = self.table.calc_bytes(&self.algorithm, value, CHECK_BYTES);
+        self.algorithm.check = T::finalize(&self.algorithm, check);
+        self.value = value;
+        self.len = 0;
+        self
+    }
+
+    /// Resume the calculation from `checksum` returned by [`CRC::finalize`].
+    ///
+    /// Calculating bytes in parts gives the same checksum as calculating them at once.
+    /// [`CRC::len`] is reset to 0, since the bytes of `checksum` are unknown.
+    pub fn resume(&mut self, checksum: T) -> &mut Self {
+        self.value = T::unfinalize(&self.algorithm, checksum);
+        self.len = 0;
+        self
+    }
+
+    /// Calculate bytes.
+    pub fn calc_bytes(&mut self, bytes: &[u8]) -> &mut Self {
+        self.value = self.table.calc_bytes(&self.algorithm, self.value, bytes);
+        self.len += bytes.len();
+        self
+    }
+
+    /// Calculate byte.
+    ///
+    /// For bytes received one by one, e.g. in an interrupt handler of UART.
+    pub fn calc_byte(&mut self, byte: u8) -> &mut Self {
+        self.calc_bytes(&[byte])
+    }
+
+    /// Calculate bytes of slices in order, e.g. two segments of a ring buffer or [`IoSlice`](https://doc.rust-lang.org/std/io/struct.IoSlice.html)s.
+    pub fn calc_slices<S: Deref<Target = [u8]>>(&mut self, slices: &[S]) -> &mut Self {
+        for slice in slices {
+            self.calc_bytes(slice);
+        }
+        self
+    }
+
+    /// Calculate bytes of an iterator, without collecting them into a slice.
+    ///
+    /// Same as [`Extend::extend`], but can be chained.
+    pub fn calc_iter<I: IntoIterator<Item = u8>>(&mut self, iter: I) -> &mut Self {
+        self.extend(iter);
+        self
+    }
+
+    /// Calculate bytes of an iterator that may fail, e.g. a decoder, until the first error.
+    ///
+    /// On error, the bytes before it are calculated.
+    pub fn try_calc_iter<E, I>(&mut self, iter: I) -> Result<&mut Self, E>
+    where
+        I: IntoIterator<Item = Result<u8, E>>,
+    {
+        let mut buf = [0; 64];
+        let mut len = 0;
+        for byte in iter {
+            match byte {
+                Ok(byte) => buf[len] = byte,
+                Err(e) => {
+                    self.calc_bytes(&buf[..len]);
+                    return Err(e);
+                }
+            }
+            len += 1;
+            if len == buf.len() {
+                self.calc_bytes(&buf);
+                len = 0;
+            }
+        }
+        Ok(self.calc_bytes(&buf[..len]))
+    }
+
+    /// Calculate chunks of bytes of an iterator that may fail, until the first error.
+    ///
+    /// On error, the chunks before it are calculated.
+    pub fn try_calc_chunks<C, E, I>(&mut self, iter: I) -> Result<&mut Self, E>
+    where
+        C: AsRef<[u8]>,
+        I: IntoIterator<Item = Result<C, E>>,
+    {
+        for chunk in iter {
+            self.calc_bytes(chunk?.as_ref());
+        }
+        Ok(self)
+    }
+
+    /// Calculate `len` zero bytes in O(log `len`), without the table.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_32_ISCSI, CRC};
+    ///
+    /// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+    /// let zeros = crc32.initialize().calc_zeros(1 << 30).finalize();
+    /// assert_eq!(crc32.initialize().calc_zeros(1 << 29).calc_zeros(1 << 29).finalize(), zeros);
+    /// # }
+    /// ```
+    pub fn calc_zeros(&mut self, len: usize) -> &mut Self {
+        self.value = T::shift_zeros(&self.algorithm, self.value, len);
+        self.len += len;
+        self
+    }
+
+    /// Advance the value by the bytes of `matrix`, see [`CrcMatrix`].
+    pub fn calc_matrix(&mut self, matrix: &CrcMatrix<T>) -> &mut Self {
+        self.value = matrix.apply(self.value);
+        self.len += matrix.len();
+        self
+    }
+
+    /// Combine checksums of two parts into the checksum of both, see [`Algorithm::combine`].
+    /// The value is not changed.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_32_ISCSI, CRC};
+    ///
+    /// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+    /// let (first, second) = (crc32.checksum(b"1234"), crc32.checksum(b"56789"));
+    /// assert_eq!(crc32.combine(first, second, 5), 0xe3069283);
+    /// # }
+    /// ```
+    pub fn combine(&self, checksum1: T, checksum2: T, len2: usize) -> T {
+        T::combine(&self.algorithm, checksum1, checksum2, len2)
+    }
+
+    /// Checksum of `len` bytes after the byte at `offset` is changed, see [`Algorithm::patch`].
+    /// The value is not changed.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_32_ISCSI, CRC};
+    ///
+    /// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+    /// let checksum = crc32.checksum(b"123456789");
+    /// assert_eq!(crc32.patch(checksum, 4, b'5', b'x', 9), crc32.checksum(b"1234x6789"));
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `offset` is not less than `len`.
+    pub fn patch(&self, checksum: T, offset: usize, old_byte: u8, new_byte: u8, len: usize) -> T {
+        T::patch(&self.algorithm, checksum, offset, old_byte, new_byte, len)
+    }
+
+    /// Checksum of `len` bytes after `zeros` zero bytes are prepended, see [`Algorithm::prepend_zeros`].
+    /// The value is not changed.
+    ///
+    /// Together with [`CRC::combine`], the checksum of fragments received out of order can be built
+    /// without waiting for the first one.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_32_ISCSI, CRC};
+    ///
+    /// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+    /// // "56789" at offset 4 arrives first, as if it follows 4 zero bytes.
+    /// let second = crc32.checksum(b"56789");
+    /// let second = crc32.prepend_zeros(second, 5, 4);
+    /// assert_eq!(second, crc32.checksum(b"\0\0\0\056789"));
+    ///
+    /// // "1234" arrives last. `init` of the zero bytes cancels out.
+    /// let first = crc32.checksum(b"1234");
+    /// let zeros = crc32.checksum(&[0; 5]);
+    /// let first = crc32.combine(first, zeros, 5);
+    /// let all_zeros = crc32.checksum(&[0; 9]);
+    /// assert_eq!(first ^ second ^ all_zeros, crc32.checksum(b"123456789"));
+    /// # }
+    /// ```
+    pub fn prepend_zeros(&self, checksum: T, len: usize, zeros: usize) -> T {
+        T::prepend_zeros(&self.algorithm, checksum, len, zeros)
+    }
+
+    /// Overwrite `(width + 7) / 8` bytes at `offset` so that the checksum of `bytes` is `checksum`,
+    /// see [`Algorithm::forge`]. The value is not changed.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_32_ISCSI, CRC};
+    ///
+    /// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+    /// // Trailing bytes of a firmware image.
+    /// let mut image = *b"firmware\0\0\0\0";
+    /// assert!(crc32.forge(&mut image, 8, 0xdeadbeef));
+    /// assert_eq!(crc32.checksum(&image), 0xdeadbeef);
+    /// # }
+    /// ```
+    pub fn forge(&self, bytes: &mut [u8], offset: usize, checksum: T) -> bool {
+        T::forge(&self.algorithm, bytes, offset, checksum)
+    }
+
+    /// Number of bytes calculated since the value is initialized.
+    ///
+    /// Bits of [`CRC::calc_bit`] are not counted.
+    /// [`CRC::resume`], [`CRC::set_raw_value`] and [`CRC::from_hw_register`] reset it to 0.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_32_ISCSI, CRC};
+    ///
+    /// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+    /// crc32.calc_bytes(b"1234").calc_bytes(b"56789");
+    /// assert_eq!(crc32.len(), 9);
+    /// assert!(crc32.initialize().is_empty());
+    /// # }
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no bytes are calculated since the value is initialized.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Calculate bit.
+    ///
+    /// Bits are processed in the order they are given, regardless of `refin`.
+    pub fn calc_bit(&mut self, bit: bool) -> &mut Self {
+        self.value = T::calc_bit(&self.algorithm, self.value, bit);
+        self
+    }
+
+    /// Calculate bits.
+    ///
+    /// Bits are processed in the order they are given, regardless of `refin`.
+    pub fn calc_bits<I: IntoIterator<Item = bool>>(&mut self, bits: I) -> &mut Self {
+        for bit in bits {
+            self.calc_bit(bit);
+        }
+        self
+    }
+
+    /// Calculate the least significant `len` bits of `value`, most significant first,
+    /// e.g. a field of a bit-oriented frame.
+    ///
+    /// # Panics
+    /// Panics if `len` is greater than 64.
+    pub fn calc_bits_msb(&mut self, value: u64, len: u32) -> &mut Self {
+        assert!(len <= 64, "len is greater than 64");
+        self.calc_bits((0..len).rev().map(|i| value >> i & 1 == 1))
+    }
+
+    /// Register of `width` bits, aligned to the least significant bit and without `xorout`.
+    ///
+    /// It is reflected (`x^(width - 1)` in the least significant bit) if `refin`, and normal otherwise,
+    /// i.e. in the bit order bytes are shifted in.
+    /// Use it to hand off the state to other implementations in the middle of a message.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_16_IBM_3740, CRC};
+    ///
+    /// let mut crc16 = CRC::<u16>::from_algorithm(CRC_16_IBM_3740);
+    /// // `init` of CRC-16/IBM-3740 is 0xffff.
+    /// assert_eq!(crc16.raw_value(), 0xffff);
+    ///
+    /// let raw = crc16.calc_bytes(b"1234").raw_value();
+    /// crc16.initialize().set_raw_value(raw);
+    /// assert_eq!(crc16.calc_bytes(b"56789").finalize(), 0x29b1);
+    /// # }
+    /// ```
+    pub fn raw_value(&self) -> T {
+        T::raw_value(&self.algorithm, self.value)
+    }
+
+    /// Set the register of `width` bits, see [`CRC::raw_value`].
+    /// Bits above `width` are ignored, and [`CRC::len`] is reset to 0.
+    pub fn set_raw_value(&mut self, raw: T) -> &mut Self {
+        self.value = T::from_raw_value(&self.algorithm, raw);
+        self.len = 0;
+        self
+    }
+
+    /// Register of a peripheral with the conventions of `register`, to continue the message with the peripheral.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_32_ISO_HDLC, HwRegister, CRC};
+    ///
+    /// // e.g. CRC unit of STM32 with REV_IN and REV_OUT, whose DR register holds a normal register
+    /// // and is read reflected without `xorout`.
+    /// let register = HwRegister { reflected: true, xorout: false };
+    /// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISO_HDLC);
+    /// let dr = crc32.calc_bytes(b"1234").to_hw_register(register);
+    /// // ... the peripheral calculates the rest and the register is read back ...
+    /// crc32.initialize().from_hw_register(dr, register);
+    /// assert_eq!(crc32.calc_bytes(b"56789").finalize(), 0xcbf43926);
+    /// # }
+    /// ```
+    pub fn to_hw_register(&self, register: HwRegister) -> T {
+        T::to_hw_register(&self.algorithm, self.value, register)
+    }
+
+    /// Set value from the register of a peripheral with the conventions of `register`.
+    /// [`CRC::len`] is reset to 0.
+    pub fn from_hw_register(&mut self, hw_register: T, register: HwRegister) -> &mut Self {
+        self.value = T::from_hw_register(&self.algorithm, hw_register, register);
+        self.len = 0;
+        self
+    }
+
+    /// Trace the register of a peripheral with the conventions of `register` after each bit of `bytes`, see [`LfsrTrace`].
+    /// The value is not changed.
+    pub fn trace<'a>(&self, bytes: &'a [u8], register: HwRegister) -> LfsrTrace<'a, T> {
+        LfsrTrace::new(self.algorithm, self.value, bytes, register)
+    }
+
+    /// Optional reflection.
+    pub fn optional_reflection(&self) -> T {
+        T::optional_reflection(&self.algorithm, self.value)
+    }
+
+    /// Finalize value.
+    /// Change value to checksum.
+    pub fn finalize(&self) -> T {
+        T::finalize(&self.algorithm, self.value)
+    }
+
+    /// Finalize value, and check it by reversing the reflection and `xorout`.
+    /// Detects a fault in finalize, e.g. for functional safety with [`Guarded`](crate::Guarded).
+    pub fn finalize_checked(&self) -> Result<T, SelfTestError<T>> {
+        let checksum = self.finalize();
+        let actual = T::unfinalize(&self.algorithm, checksum);
+        if actual == self.value {
+            Ok(checksum)
+        } else {
+            Err(SelfTestError::Finalize {
+                expected: self.value,
+                actual,
+            })
+        }
+    }
+
+    /// Finalize to endian bytes.
+    pub fn finalize_to_endian_bytes(&self) -> T::Bytes {
+        T::finalize_to_endian_bytes(&self.algorithm, self.value)
+    }
+
+    /// Finalize value and initialize it for the next message.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_32_ISCSI, CRC};
+    ///
+    /// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+    /// for _ in 0..2 {
+    ///     assert_eq!(crc32.calc_bytes(b"123456789").finalize_reset(), 0xe3069283);
+    /// }
+    /// # }
+    /// ```
+    pub fn finalize_reset(&mut self) -> T {
+        let checksum = self.finalize();
+        self.initialize();
+        checksum
+    }
+
+    /// Checksum function.
+    pub fn checksum(&mut self, bytes: &[u8]) -> T {
+        self.initialize().calc_bytes(bytes).finalize()
+    }
+
+    /// Checksum of slices in order.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_32_ISCSI, CRC};
+    ///
+    /// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+    /// // Wraparound of a ring buffer.
+    /// let ring = b"6789_____12345";
+    /// assert_eq!(crc32.checksum_slices(&[&ring[9..], &ring[..4]]), 0xe3069283);
+    /// # }
+    /// ```
+    pub fn checksum_slices<S: Deref<Target = [u8]>>(&mut self, slices: &[S]) -> T {
+        self.initialize().calc_slices(slices).finalize()
+    }
+
+    /// Checksum of bytes of an iterator.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_32_ISCSI, CRC};
+    ///
+    /// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+    /// assert_eq!(crc32.checksum_iter(b'1'..=b'9'), 0xe3069283);
+    /// # }
+    /// ```
+    pub fn checksum_iter<I: IntoIterator<Item = u8>>(&mut self, iter: I) -> T {
+        self.initialize().calc_iter(iter).finalize()
+    }
+
+    /// Checksum of bytes of an iterator that may fail, or the first error.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_32_ISCSI, CRC};
+    ///
+    /// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+    /// let digits = "123456789".chars().map(|c| c.to_digit(10).map(|d| b'0' + d as u8).ok_or(c));
+    /// assert_eq!(crc32.try_checksum_iter(digits), Ok(0xe3069283));
+    /// let digits = "1234x6789".chars().map(|c| c.to_digit(10).map(|d| b'0' + d as u8).ok_or(c));
+    /// assert_eq!(crc32.try_checksum_iter(digits), Err('x'));
+    /// # }
+    /// ```
+    pub fn try_checksum_iter<E, I>(&mut self, iter: I) -> Result<T, E>
+    where
+        I: IntoIterator<Item = Result<u8, E>>,
+    {
+        Ok(self.initialize().try_calc_iter(iter)?.finalize())
+    }
+
+    /// Checksum of chunks of bytes of an iterator that may fail, or the first error.
+    pub fn try_checksum_chunks<C, E, I>(&mut self, iter: I) -> Result<T, E>
+    where
+        C: AsRef<[u8]>,
+        I: IntoIterator<Item = Result<C, E>>,
+    {
+        Ok(self.initialize().try_calc_chunks(iter)?.finalize())
+    }
+
+    /// Checksum to endian bytes.
+    pub fn checksum_to_endian_bytes(&mut self, bytes: &[u8]) -> T::Bytes {
+        self.initialize()
+            .calc_bytes(bytes)
+            .finalize_to_endian_bytes()
+    }
+
+    /// Check if `value` is error-free.
+    /// Returns `true` if error-free.
+    pub fn is_error_free(&mut self) -> bool {
+        self.optional_reflection() == self.algorithm.residue
+    }
+
+    /// Recompute `check` with the table and `residue`, and compare them with the algorithm.
+    ///
+    /// Unlike [`Algorithm::self_test`], this uses the table of `self`, so it also detects a corrupted table.
+    /// The value is not changed.
+    pub fn self_test(&self) -> Result<(), SelfTestError<T>> {
+        let value = T::initialize(&self.algorithm);
+        let value = self.table.calc_bytes(&self.algorithm, value, CHECK_BYTES);
+        let check = T::finalize(&self.algorithm, value);
+        if check != self.algorithm.check {
+            return Err(SelfTestError::Check {
+                expected: self.algorithm.check,
+                actual: check,
+            });
+        }
+
+        let residue = T::residue(&self.algorithm);
+        if residue != self.algorithm.residue {
+            return Err(SelfTestError::Residue {
+                expected: self.algorithm.residue,
+                actual: residue,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Checksum function that also calculates bytes bit by bit without the table, and compares both.
+    ///
+    /// Unlike [`CRC::self_test`], this checks the entries of the table used by `bytes`,
+    /// as a runtime defense against a table corrupted in memory.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_32_ISCSI, SelfTestError, CRC};
+    ///
+    /// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+    /// assert_eq!(crc32.checksum_verified(b"123456789"), Ok(0xe3069283));
+    ///
+    /// // The entry of `b'a'` after `init`.
+    /// crc32.table[0xff ^ b'a' as usize] ^= 1;
+    /// assert!(matches!(crc32.checksum_verified(b"a"), Err(SelfTestError::Table { .. })));
+    /// # }
+    /// ```
+    pub fn checksum_verified(&mut self, bytes: &[u8]) -> Result<T, SelfTestError<T>> {
+        let actual = self.checksum(bytes);
+        let value = T::calc_bytes_bitwise(&self.algorithm, T::initialize(&self.algorithm), bytes);
+        let expected = T::finalize(&self.algorithm, value);
+        if actual == expected {
+            Ok(actual)
+        } else {
+            Err(SelfTestError::Table { expected, actual })
+        }
+    }
+
+    /// Check if bytes [message + checksum in `endian` bytes] are error-free.
+    /// Returns `true` if error-free.
+    ///
+    /// `width` must be a multiple of 8.
+    /// If [`Algorithm::is_residue_endian`] is `false`, e.g. a reflected algorithm framed big-endian,
+    /// the checksum of the message is compared with the last bytes instead of `residue`.
+    pub fn is_error_free_bytes(&mut self, bytes: &[u8]) -> bool {
+        if self.algorithm.is_residue_endian() {
+            return self.initialize().calc_bytes(bytes).is_error_free();
+        }
+        let len = (self.algorithm.width as usize).div_ceil(8);
+        match bytes.len().checked_sub(len) {
+            Some(split) => {
+                let (message, checksum) = bytes.split_at(split);
+                *self.checksum_to_endian_bytes(message) == *checksum
+            }
+            None => false,
+        }
+    }
+
+    /// Compare the checksum of `message` with `expected`.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_32_ISCSI, MismatchError, CRC};
+    ///
+    /// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+    /// assert_eq!(crc32.verify(b"123456789", 0xe3069283), Ok(()));
+    /// assert_eq!(
+    ///     crc32.verify(b"123456789", 0),
+    ///     Err(MismatchError { expected: 0, actual: 0xe3069283 })
+    /// );
+    /// # }
+    /// ```
+    pub fn verify(&mut self, message: &[u8], expected: T) -> Result<(), MismatchError<T>> {
+        let actual = self.checksum(message);
+        if actual == expected {
+            Ok(())
+        } else {
+            Err(MismatchError { expected, actual })
+        }
+    }
+
+    /// Compare the checksum of `message` with `expected` bytes in `endian`.
+    ///
+    /// Returns [`CrcError::Length`] if `expected` is not `(width + 7) / 8` bytes.
+    pub fn verify_bytes(
+        &mut self,
+        message: &[u8],
+        expected: &[u8],
+        endian: Endian,
+    ) -> Result<(), CrcError<T>> {
+        let len = (self.algorithm.width as usize).div_ceil(8);
+        if expected.len() != len {
+            return Err(CrcError::Length {
+                expected: len,
+                actual: expected.len(),
+            });
+        }
+        Ok(self.verify(message, T::from_endian_bytes(expected, endian))?)
+    }
+
+    /// Verify the trailing checksum in `endian` bytes of `frame`, and return the message before it.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_16_ARC, CrcError, CRC};
+    ///
+    /// let mut crc16 = CRC::<u16>::from_algorithm(CRC_16_ARC);
+    /// assert_eq!(crc16.verify_and_strip(b"123456789\x3d\xbb"), Ok(&b"123456789"[..]));
+    /// assert!(matches!(crc16.verify_and_strip(b"123456789\0\0"), Err(CrcError::Mismatch(_))));
+    /// # }
+    /// ```
+    pub fn verify_and_strip<'a>(&mut self, frame: &'a [u8]) -> Result<&'a [u8], CrcError<T>> {
+        let len = (self.algorithm.width as usize).div_ceil(8);
+        let split = frame.len().checked_sub(len).ok_or(CrcError::TooShort {
+            expected: len,
+            actual: frame.len(),
+        })?;
+        let (message, checksum) = frame.split_at(split);
+        self.verify(
+            message,
+            T::from_endian_bytes(checksum, self.algorithm.endian),
+        )?;
+        Ok(message)
+    }
+
+    /// XOR `pattern` into `message` at `offset`, recompute the checksum, and return `true` if it changes,
+    /// i.e. if the corruption is detected. `message` itself is not changed.
+    ///
+    /// # Panics
+    /// Panics if `pattern` at `offset` is out of `message`.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_8_SMBUS, CRC};
+    ///
+    /// let mut crc8 = CRC::<u8>::from_algorithm(CRC_8_SMBUS);
+    /// let corruptions: [(usize, &[u8]); 3] = [(0, &[0x80]), (3, &[0xff, 0xff]), (2, &[0x01, 0x07])];
+    /// let detected = corruptions.map(|(offset, pattern)| crc8.detects_corruption(b"123456789", offset, pattern));
+    /// // The last one is the polynomial itself.
+    /// assert_eq!(detected, [true, true, false]);
+    /// # }
+    /// ```
+    pub fn detects_corruption(&mut self, message: &[u8], offset: usize, pattern: &[u8]) -> bool {
+        assert!(
+            offset
+                .checked_add(pattern.len())
+                .is_some_and(|end| end <= message.len()),
+            "pattern is out of message"
+        );
+        let expected = self.checksum(message);
+        let (before, rest) = message.split_at(offset);
+        let (corrupted, after) = rest.split_at(pattern.len());
+
+        self.initialize().calc_bytes(before);
+        let mut buf = [0; 64];
+        for (chunk, pattern) in corrupted.chunks(buf.len()).zip(pattern.chunks(buf.len())) {
+            let buf = &mut buf[..chunk.len()];
+            for ((byte, &original), &error) in buf.iter_mut().zip(chunk).zip(pattern) {
+                *byte = original ^ error;
+            }
+            self.calc_bytes(buf);
+        }
+        self.calc_bytes(after).finalize() != expected
+    }
+
+    /// Locate the bit whose error explains `frame` [message + checksum in `endian` bytes] failing verification.
+    ///
+    /// Returns `Ok(None)` if `frame` is error-free, `Ok(Some(position))` if flipping bit `position % 8`
+    /// (bit 0 is the least significant) of byte `position / 8` makes it error-free,
+    /// and `Err(CrcError::Mismatch(_))` if no single bit error explains it.
+    /// The position is unique only while the algorithm detects all 2-bit errors in frames of that length,
+    /// and a multi-bit error may be taken for a single bit error, so keep frames short.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_16_ARC, CRC};
+    ///
+    /// let mut crc16 = CRC::<u16>::from_algorithm(CRC_16_ARC);
+    /// let mut frame = *b"123456789\x3d\xbb";
+    /// frame[2] ^= 0x10;
+    /// assert_eq!(crc16.locate_bit_error(&frame), Ok(Some(2 * 8 + 4)));
+    /// # }
+    /// ```
+    pub fn locate_bit_error(&mut self, frame: &[u8]) -> Result<Option<usize>, CrcError<T>> {
+        let len = (self.algorithm.width as usize).div_ceil(8);
+        let split = frame.len().checked_sub(len).ok_or(CrcError::TooShort {
+            expected: len,
+            actual: frame.len(),
+        })?;
+        let (message, checksum) = frame.split_at(split);
+        let endian = self.algorithm.endian;
+        let expected = T::from_endian_bytes(checksum, endian);
+        let actual = self.checksum(message);
+        if actual == expected {
+            return Ok(None);
+        }
+
+        // Error in the checksum.
+        let mut bytes = [0; 16];
+        bytes[..len].copy_from_slice(checksum);
+        for bit in 0..8 * len {
+            bytes[bit / 8] ^= 1 << (bit % 8);
+            let flipped = T::from_endian_bytes(&bytes[..len], endian);
+            bytes[bit / 8] ^= 1 << (bit % 8);
+            if flipped == actual {
+                return Ok(Some(8 * split + bit));
+            }
+        }
+
+        // Error in the message changes the value by that of the error from zero, which is linear.
+        let algorithm = &self.algorithm;
+        let syndrome = self.value ^ T::unfinalize(algorithm, expected);
+        let zero = T::default();
+        let mut changes = [zero; 8];
+        for (bit, change) in changes.iter_mut().enumerate() {
+            *change = T::calc_bytes_bitwise(algorithm, zero, &[1 << bit]);
+        }
+        for i in (0..split).rev() {
+            for (bit, change) in changes.iter_mut().enumerate() {
+                if *change == syndrome {
+                    return Ok(Some(8 * i + bit));
+                }
+                *change = T::shift_zeros(algorithm, *change, 1);
+            }
+        }
+        Err(CrcError::Mismatch(MismatchError { expected, actual }))
+    }
+
+    /// Correct the bit located by [`CRC::locate_bit_error`] in `frame`, and return its position.
+    pub fn correct_bit_error(&mut self, frame: &mut [u8]) -> Result<Option<usize>, CrcError<T>> {
+        let position = self.locate_bit_error(frame)?;
+        if let Some(position) = position {
+            frame[position / 8] ^= 1 << (position % 8);
+        }
+        Ok(position)
+    }
+
+    /// Check if the checksum stored in `bytes` as of `layout` is the checksum of the other bytes.
+    /// Returns `false` if the checksum is out of `bytes`.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_16_ARC, ChecksumLayout, Endian, CRC};
+    ///
+    /// let mut crc16 = CRC::<u16>::from_algorithm(CRC_16_ARC);
+    /// // Header, big-endian checksum and payload.
+    /// let frame = b"1234\xbb\x3d56789";
+    /// let layout = ChecksumLayout { endian: Endian::Big, offset: 4 };
+    /// assert!(crc16.verify_frame(frame, layout));
+    /// # }
+    /// ```
+    pub fn verify_frame(&mut self, bytes: &[u8], layout: ChecksumLayout) -> bool {
+        let len = (self.algorithm.width as usize).div_ceil(8);
+        let end = match layout.offset.checked_add(len) {
+            Some(end) if end <= bytes.len() => end,
+            _ => return false,
+        };
+        self.initialize()
+            .calc_bytes(&bytes[..layout.offset])
+            .calc_bytes(&bytes[end..]);
+        let algorithm = Algorithm {
+            endian: layout.endian,
+            ..self.algorithm
+        };
+        *T::finalize_to_endian_bytes(&algorithm, self.value) == bytes[layout.offset..end]
+    }
+}
+
+/// Calculate bytes of an iterator.
+///
+/// Bytes are buffered on the stack, so that the table calculates them in chunks.
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// use mycrc::{catalog::CRC_32_ISCSI, CRC};
+///
+/// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+/// crc32.initialize().extend((b'1'..=b'9').filter(|b| b % 2 == 1));
+/// crc32.extend(b"2468");
+/// assert_eq!(crc32.finalize(), crc32.checksum(b"135792468"));
+/// # }
+/// ```
+impl<T: CrcWidth, B: Table<T>> Extend<u8> for CRC<T, B> {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        let mut buf = [0; 64];
+        let mut len = 0;
+        for byte in iter {
+            buf[len] = byte;
+            len += 1;
+            if len == buf.len() {
+                self.calc_bytes(&buf);
+                len = 0;
+            }
+        }
+        self.calc_bytes(&buf[..len]);
+    }
+}
+
+impl<'a, T: CrcWidth, B: Table<T>> Extend<&'a u8> for CRC<T, B> {
+    fn extend<I: IntoIterator<Item = &'a u8>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T: CrcWidth + Send + Sync, B: Table<T> + Sync> CRC<T, B> {
+    /// Checksum function that splits bytes across the threads of rayon.
+    ///
+    /// Parts are calculated from zero and combined in O(log `len`) each.
+    /// The value is not changed.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_32_ISCSI, CRC};
+    ///
+    /// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+    /// let bytes = vec![0x5a; 1 << 20];
+    /// assert_eq!(crc32.checksum_parallel(&bytes), crc32.checksum(&bytes));
+    /// # }
+    /// ```
+    pub fn checksum_parallel(&self, bytes: &[u8]) -> T {
+        use rayon::prelude::*;
+
+        /// Smaller parts are not worth a thread.
+        const MIN_PART_LEN: usize = 64 * 1024;
+
+        let algorithm = &self.algorithm;
+        let part_len = bytes
+            .len()
+            .div_ceil(rayon::current_num_threads())
+            .max(MIN_PART_LEN);
+        let zero = T::default();
+        let (value, len) = bytes
+            .par_chunks(part_len)
+            .map(|part| (self.table.calc_bytes(algorithm, zero, part), part.len()))
+            .reduce(
+                || (zero, 0),
+                |(value1, len1), (value2, len2)| {
+                    (
+                        T::combine_values(algorithm, value1, value2, len2),
+                        len1 + len2,
+                    )
+                },
+            );
+        let value = T::combine_values(algorithm, T::initialize(algorithm), value, len);
+        T::finalize(algorithm, value)
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_bytes;
 
     const CHECK_BYTES: &[u8] = b"123456789";
 
+    const CRC_3_GSM: Algorithm<u8> = Algorithm {
+        endian: Endian::Big,
+        width: 3,
+        poly: 0x3,
+        init: 0x0,
+        refin: false,
+        refout: false,
+        xorout: 0x7,
+        check: 0x4,
+        residue: 0x2,
+    };
+    const CRC_5_USB: Algorithm<u8> = Algorithm {
+        endian: Endian::Little,
+        width: 5,
+        poly: 0x05,
+        init: 0x1f,
+        refin: true,
+        refout: true,
+        xorout: 0x1f,
+        check: 0x19,
+        residue: 0x06,
+    };
+    const CRC_7_MMC: Algorithm<u8> = Algorithm {
+        endian: Endian::Big,
+        width: 7,
+        poly: 0x09,
+        init: 0x00,
+        refin: false,
+        refout: false,
+        xorout: 0x00,
+        check: 0x75,
+        residue: 0x00,
+    };
+    const CRC_12_UMTS: Algorithm<u16> = Algorithm {
+        endian: Endian::Big,
+        width: 12,
+        poly: 0x80f,
+        init: 0x000,
+        refin: false,
+        refout: true,
+        xorout: 0x000,
+        check: 0xdaf,
+        residue: 0x000,
+    };
+    const CRC_24_BLE: Algorithm<u32> = Algorithm {
+        endian: Endian::Little,
+        width: 24,
+        poly: 0x00065b,
+        init: 0x555555,
+        refin: true,
+        refout: true,
+        xorout: 0x000000,
+        check: 0xc25a56,
+        residue: 0x000000,
+    };
+    const CRC_24_OPENPGP: Algorithm<u32> = Algorithm {
+        endian: Endian::Big,
+        width: 24,
+        poly: 0x864cfb,
+        init: 0xb704ce,
+        refin: false,
+        refout: false,
+        xorout: 0x000000,
+        check: 0x21cf02,
+        residue: 0x000000,
+    };
+    const CRC_31_PHILIPS: Algorithm<u32> = Algorithm {
+        endian: Endian::Big,
+        width: 31,
+        poly: 0x04c11db7,
+        init: 0x7fffffff,
+        refin: false,
+        refout: false,
+        xorout: 0x7fffffff,
+        check: 0x0ce9e46c,
+        residue: 0x4eaf26f1,
+    };
+    const CRC_40_GSM: Algorithm<u64> = Algorithm {
+        endian: Endian::Big,
+        width: 40,
+        poly: 0x0004820009,
+        init: 0x0000000000,
+        refin: false,
+        refout: false,
+        xorout: 0xffffffffff,
+        check: 0xd4164fc646,
+        residue: 0xc4ff8071ff,
+    };
+    const CRC_82_DARC: Algorithm<u128> = Algorithm {
+        endian: Endian::Little,
+        width: 82,
+        poly: 0x0308c0111011401440411,
+        init: 0x000000000000000000000,
+        refin: true,
+        refout: true,
+        xorout: 0x000000000000000000000,
+        check: 0x09ea83f625023801fd612,
+        residue: 0x000000000000000000000,
+    };
+    const CRC_8_AUTOSAR: Algorithm<u8> = Algorithm {
+        endian: Endian::Big,
+        width: 8,
+        poly: 0x2f,
+        init: 0xff,
+        refin: false,
+        refout: false,
+        xorout: 0xff,
+        check: 0xdf,
+        residue: 0x42,
+    };
+    const CRC_8_I_432_1: Algorithm<u8> = Algorithm {
+        endian: Endian::Big,
+        width: 8,
+        poly: 0x07,
+        init: 0x00,
+        refin: false,
+        refout: false,
+        xorout: 0x55,
+        check: 0xa1,
+        residue: 0xac,
+    };
+    const CRC_8_MAXIM_DOW: Algorithm<u8> = Algorithm {
+        endian: Endian::Little,
+        width: 8,
+        poly: 0x31,
+        init: 0x00,
+        refin: true,
+        refout: true,
+        xorout: 0x00,
+        check: 0xa1,
+        residue: 0x00,
+    };
+    const CRC_8_SMBUS: Algorithm<u8> = Algorithm {
+        endian: Endian::Big,
+        width: 8,
+        poly: 0x07,
+        init: 0x00,
+        refin: false,
+        refout: false,
+        xorout: 0x00,
+        check: 0xf4,
+        residue: 0x00,
+    };
+
     const CRC_32_AIXM: Algorithm<u32> = Algorithm {
         endian: Endian::Native,
+        width: 32,
         poly: 0x814141ab,
         init: 0x00000000,
         refin: false,
         refout: false,
         xorout: 0x00000000,
+        check: 0x3010bf7f,
         residue: 0x00000000,
     };
     const CRC_32_AUTOSAR: Algorithm<u32> = Algorithm {
         endian: Endian::Little,
+        width: 32,
         poly: 0xf4acfb13,
         init: 0xffffffff,
         refin: true,
         refout: true,
         xorout: 0xffffffff,
+        check: 0x1697d06a,
         residue: 0x904cddbf,
     };
     const CRC_32_BASE91_D: Algorithm<u32> = Algorithm {
         endian: Endian::Little,
+        width: 32,
         poly: 0xa833982b,
         init: 0xffffffff,
         refin: true,
         refout: true,
         xorout: 0xffffffff,
+        check: 0x87315576,
         residue: 0x45270551,
     };
     const CRC_32_BZIP2: Algorithm<u32> = Algorithm {
         endian: Endian::Big,
+        width: 32,
         poly: 0x04c11db7,
         init: 0xffffffff,
         refin: false,
         refout: false,
         xorout: 0xffffffff,
+        check: 0xfc891918,
         residue: 0xc704dd7b,
     };
     const CRC_32_CD_ROM_EDC: Algorithm<u32> = Algorithm {
         endian: Endian::Native,
+        width: 32,
         poly: 0x8001801b,
         init: 0x00000000,
         refin: true,
         refout: true,
         xorout: 0x00000000,
+        check: 0x6ec2edc4,
         residue: 0x00000000,
     };
     const CRC_32_CKSUM: Algorithm<u32> = Algorithm {
         endian: Endian::Big,
+        width: 32,
         poly: 0x04c11db7,
         init: 0x00000000,
         refin: false,
         refout: false,
         xorout: 0xffffffff,
+        check: 0x765e7680,
         residue: 0xc704dd7b,
     };
     const CRC_32_ISCSI: Algorithm<u32> = Algorithm {
         endian: Endian::Little,
+        width: 32,
         poly: 0x1edc6f41,
         init: 0xffffffff,
         refin: true,
         refout: true,
         xorout: 0xffffffff,
+        check: 0xe3069283,
         residue: 0xb798b438,
     };
     const CRC_32_ISO_HDLC: Algorithm<u32> = Algorithm {
         endian: Endian::Little,
+        width: 32,
         poly: 0x04c11db7,
         init: 0xffffffff,
         refin: true,
         refout: true,
         xorout: 0xffffffff,
+        check: 0xcbf43926,
         residue: 0xdebb20e3,
     };
     const CRC_32_JAMCRC: Algorithm<u32> = Algorithm {
         endian: Endian::Native,
+        width: 32,
         poly: 0x04c11db7,
         init: 0xffffffff,
         refin: true,
         refout: true,
         xorout: 0x00000000,
+        check: 0x340bc6d9,
         residue: 0x00000000,
     };
     const CRC_32_MPEG_2: Algorithm<u32> = Algorithm {
         endian: Endian::Native,
+        width: 32,
         poly: 0x04c11db7,
         init: 0xffffffff,
         refin: false,
         refout: false,
         xorout: 0x00000000,
+        check: 0x0376e6e7,
         residue: 0x00000000,
     };
     const CRC_32_XFER: Algorithm<u32> = Algorithm {
         endian: Endian::Native,
+        width: 32,
         poly: 0x000000af,
         init: 0x00000000,
         refin: false,
         refout: false,
         xorout: 0x00000000,
+        check: 0xbd0be338,
         residue: 0x00000000,
     };
 
@@ -330,6 +1595,615 @@ mod tests {
         }
     }
 
+    #[test]
+    fn check_u8() {
+        // (Algorithm, check)
+        let algos = [
+            (CRC_8_AUTOSAR, 0xdf),
+            (CRC_8_I_432_1, 0xa1),
+            (CRC_8_MAXIM_DOW, 0xa1),
+            (CRC_8_SMBUS, 0xf4),
+        ];
+        for algo in algos {
+            let mut crc8 = CRC::<u8>::from_algorithm(algo.0);
+            assert_eq!(crc8.checksum(CHECK_BYTES), algo.1);
+
+            let checksum = crc8.checksum_to_endian_bytes(CHECK_BYTES);
+            let bytes = [CHECK_BYTES, &checksum].concat();
+            assert!(crc8.is_error_free_bytes(&bytes));
+
+            // Check if `CRC::from_algorithm` algo is equal to `CRC::new_with_width` algo.
+            let crc_new = CRC::<u8>::new_with_width(
+                algo.0.endian,
+                algo.0.width,
+                algo.0.poly,
+                algo.0.init,
+                algo.0.refin,
+                algo.0.refout,
+                algo.0.xorout,
+            );
+            assert_eq!(algo.0, crc_new.algorithm);
+        }
+    }
+
+    #[test]
+    fn check_width() {
+        // (Algorithm, check)
+        let algos = [(CRC_3_GSM, 0x4), (CRC_5_USB, 0x19), (CRC_7_MMC, 0x75)];
+        for algo in algos {
+            let mut crc = CRC::<u8>::from_algorithm(algo.0);
+            assert_eq!(crc.checksum(CHECK_BYTES), algo.1);
+            let a = algo.0;
+            let crc_new = CRC::<u8>::new_with_width(
+                a.endian, a.width, a.poly, a.init, a.refin, a.refout, a.xorout,
+            );
+            assert_eq!(a, crc_new.algorithm);
+        }
+
+        let mut crc12 = CRC::<u16>::from_algorithm(CRC_12_UMTS);
+        assert_eq!(crc12.checksum(CHECK_BYTES), 0xdaf);
+
+        let mut crc31 = CRC::<u32>::from_algorithm(CRC_31_PHILIPS);
+        assert_eq!(crc31.checksum(CHECK_BYTES), 0x0ce9e46c);
+        let a = CRC_31_PHILIPS;
+        let crc_new = CRC::<u32>::new_with_width(
+            a.endian, a.width, a.poly, a.init, a.refin, a.refout, a.xorout,
+        );
+        assert_eq!(a, crc_new.algorithm);
+    }
+
+    #[test]
+    fn check_u24() {
+        // (Algorithm, check, check bytes)
+        let algos = [
+            (CRC_24_BLE, 0xc25a56, [0x56, 0x5a, 0xc2]),
+            (CRC_24_OPENPGP, 0x21cf02, [0x21, 0xcf, 0x02]),
+        ];
+        for algo in algos {
+            let mut crc24 = CRC::<u32>::from_algorithm(algo.0);
+            assert_eq!(crc24.checksum(CHECK_BYTES), algo.1);
+
+            let checksum = crc24.checksum_to_endian_bytes(CHECK_BYTES);
+            assert_eq!(checksum.as_slice(), &algo.2);
+
+            let bytes = [CHECK_BYTES, &checksum].concat();
+            assert!(crc24.is_error_free_bytes(&bytes));
+        }
+    }
+
+    #[test]
+    fn check_u40() {
+        let mut crc40 = CRC::<u64>::from_algorithm(CRC_40_GSM);
+        assert_eq!(crc40.checksum(CHECK_BYTES), 0xd4164fc646);
+
+        let checksum = crc40.checksum_to_endian_bytes(CHECK_BYTES);
+        assert_eq!(checksum.as_slice(), &[0xd4, 0x16, 0x4f, 0xc6, 0x46]);
+        assert_eq!(
+            Algorithm::<u64>::from_endian_bytes(&checksum, Endian::Big),
+            0xd4164fc646
+        );
+        assert_eq!(
+            Algorithm::<u64>::from_endian_bytes(&checksum, Endian::Little),
+            0x46c64f16d4
+        );
+        let bytes = [CHECK_BYTES, &checksum].concat();
+        assert!(crc40.is_error_free_bytes(&bytes));
+
+        let a = CRC_40_GSM;
+        let crc_new = CRC::<u64>::new_with_width(
+            a.endian, a.width, a.poly, a.init, a.refin, a.refout, a.xorout,
+        );
+        assert_eq!(a, crc_new.algorithm);
+    }
+
+    #[test]
+    fn error_free_endian() {
+        // CRC-16/ARC framed big-endian, and CRC-32/BZIP2 framed big-endian as in the catalog.
+        let mut crc16 = CRC::<u16>::new(Endian::Big, 0x8005, 0, true, true, 0);
+        let mut crc32 = CRC::<u32>::new(Endian::Big, 0x04c11db7, !0, false, false, !0);
+        assert!(!crc16.algorithm.is_residue_endian());
+        assert!(crc32.algorithm.is_residue_endian());
+
+        let checksum = crc16.checksum_to_endian_bytes(CHECK_BYTES);
+        assert_eq!(checksum.as_slice(), &[0xbb, 0x3d]);
+        let mut bytes = [CHECK_BYTES, &checksum].concat();
+        assert!(crc16.is_error_free_bytes(&bytes));
+        bytes[3] ^= 1;
+        assert!(!crc16.is_error_free_bytes(&bytes));
+        assert!(!crc16.is_error_free_bytes(&[0xbb]));
+        assert_eq!(
+            crc16.verify_and_strip(&[0xbb]),
+            Err(CrcError::TooShort {
+                expected: 2,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            crc16.verify_and_strip(&bytes),
+            Err(CrcError::Mismatch(MismatchError {
+                expected: 0xbb3d,
+                actual: crc16.checksum(&bytes[..9])
+            }))
+        );
+        bytes[3] ^= 1;
+        assert_eq!(crc16.verify_and_strip(&bytes), Ok(CHECK_BYTES));
+        assert_eq!(crc16.verify_and_strip(&[0, 0]), Ok(&[][..]));
+
+        let checksum = crc32.checksum_to_endian_bytes(CHECK_BYTES);
+        let bytes = [CHECK_BYTES, &checksum].concat();
+        assert!(crc32.is_error_free_bytes(&bytes));
+
+        let layout = ChecksumLayout {
+            endian: Endian::Little,
+            offset: 9,
+        };
+        assert!(crc32.verify_frame(
+            &[CHECK_BYTES, &0xfc891918u32.to_le_bytes()].concat(),
+            layout
+        ));
+        assert!(!crc32.verify_frame(&bytes, layout));
+        let layout = ChecksumLayout {
+            endian: Endian::Big,
+            offset: 0,
+        };
+        assert!(crc32.verify_frame(&[&checksum, CHECK_BYTES].concat(), layout));
+        assert!(!crc32.verify_frame(
+            CHECK_BYTES,
+            ChecksumLayout {
+                offset: 6,
+                ..layout
+            }
+        ));
+        assert!(!crc32.verify_frame(
+            CHECK_BYTES,
+            ChecksumLayout {
+                offset: usize::MAX,
+                ..layout
+            }
+        ));
+
+        assert_eq!(
+            crc32.verify_bytes(CHECK_BYTES, &checksum, Endian::Big),
+            Ok(())
+        );
+        assert_eq!(
+            crc32.verify_bytes(CHECK_BYTES, &checksum, Endian::Little),
+            Err(CrcError::Mismatch(MismatchError {
+                expected: 0x181989fc,
+                actual: 0xfc891918
+            }))
+        );
+        assert_eq!(
+            crc32.verify_bytes(CHECK_BYTES, &checksum[1..], Endian::Big),
+            Err(CrcError::Length {
+                expected: 4,
+                actual: 3
+            })
+        );
+
+        let native = CRC::<u16>::new(Endian::Native, 0x8005, 0, true, true, 0);
+        assert_eq!(
+            native.algorithm.is_residue_endian(),
+            cfg!(target_endian = "little")
+        );
+    }
+
+    #[test]
+    fn check_u82() {
+        let mut crc82 = CRC::<u128>::from_algorithm(CRC_82_DARC);
+        assert_eq!(crc82.checksum(CHECK_BYTES), 0x09ea83f625023801fd612);
+
+        let checksum = crc82.checksum_to_endian_bytes(CHECK_BYTES);
+        assert_eq!(checksum.len(), 11);
+        let bytes = [CHECK_BYTES, &checksum].concat();
+        assert!(crc82.is_error_free_bytes(&bytes));
+
+        let a = CRC_82_DARC;
+        let crc_new = CRC::<u128>::new_with_width(
+            a.endian, a.width, a.poly, a.init, a.refin, a.refout, a.xorout,
+        );
+        assert_eq!(a, crc_new.algorithm);
+    }
+
+    #[test]
+    fn generic() {
+        fn checksum<T: CrcWidth>(crc: &mut CRC<T>) -> T {
+            crc.checksum(CHECK_BYTES)
+        }
+
+        assert_eq!(checksum(&mut CRC::<u8>::from_algorithm(CRC_8_SMBUS)), 0xf4);
+        assert_eq!(
+            checksum(&mut CRC::<u16>::from_algorithm(CRC_12_UMTS)),
+            0xdaf
+        );
+        assert_eq!(
+            checksum(&mut CRC::<u32>::from_algorithm(CRC_32_ISCSI)),
+            0xe3069283
+        );
+        assert_eq!(
+            checksum(&mut CRC::<u128>::from_algorithm(CRC_82_DARC)),
+            0x09ea83f625023801fd612
+        );
+    }
+
+    #[test]
+    fn with_init() {
+        let mut crc24 = CRC::<u32>::from_algorithm(CRC_24_BLE);
+        let table = crc24.table;
+
+        for init in [0x123456, 0xabcdef, 0x555555] {
+            crc24.with_init(init);
+            let a = CRC_24_BLE;
+            let crc_new = CRC::<u32>::new_with_width(
+                a.endian, a.width, a.poly, init, a.refin, a.refout, a.xorout,
+            );
+            assert_eq!(crc24.algorithm, crc_new.algorithm);
+            assert_eq!(crc24.value, crc_new.value);
+            assert_eq!(crc24.table, table);
+            assert_eq!(
+                crc24.calc_bytes(CHECK_BYTES).finalize(),
+                crc_new.algorithm.check
+            );
+        }
+    }
+
+    #[test]
+    fn resume() {
+        fn resume<T: CrcWidth>(mut crc: CRC<T>) {
+            let (a, b) = CHECK_BYTES.split_at(4);
+            let checksum = crc.initialize().calc_bytes(a).finalize();
+            assert_eq!(
+                crc.resume(checksum).calc_bytes(b).finalize(),
+                crc.algorithm.check
+            );
+        }
+        resume(CRC::<u8>::from_algorithm(CRC_3_GSM));
+        resume(CRC::<u8>::from_algorithm(CRC_7_MMC));
+        resume(CRC::<u16>::from_algorithm(CRC_12_UMTS));
+        resume(CRC::<u32>::from_algorithm(CRC_24_BLE));
+        resume(CRC::<u32>::from_algorithm(CRC_32_ISO_HDLC));
+        resume(CRC::<u128>::from_algorithm(CRC_82_DARC));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn checksum_parallel() {
+        use std::vec::Vec;
+
+        fn checksum_parallel<T: CrcWidth + Send + Sync>(mut crc: CRC<T>) {
+            let bytes: Vec<u8> = (0..1_000_003u32).map(|i| (i * 7 + i / 251) as u8).collect();
+            for len in [0, 1, 9, 64 * 1024, 64 * 1024 + 1, bytes.len()] {
+                assert_eq!(
+                    crc.checksum_parallel(&bytes[..len]),
+                    crc.checksum(&bytes[..len])
+                );
+            }
+        }
+        checksum_parallel(CRC::<u8>::from_algorithm(CRC_3_GSM));
+        checksum_parallel(CRC::<u16>::from_algorithm(CRC_12_UMTS));
+        checksum_parallel(CRC::<u32>::from_algorithm(CRC_24_BLE));
+        checksum_parallel(CRC::<u32>::from_algorithm(CRC_32_ISO_HDLC));
+        checksum_parallel(CRC::<u128>::from_algorithm(CRC_82_DARC));
+    }
+
+    #[test]
+    fn finalize_reset() {
+        let mut crc = CRC::<u16>::from_algorithm(CRC_12_UMTS);
+        crc.calc_bytes(CHECK_BYTES);
+        assert_eq!(crc.finalize_reset(), crc.algorithm.check);
+        assert_eq!(crc.value, u16::initialize(&crc.algorithm));
+        assert_eq!(
+            crc.calc_bytes(CHECK_BYTES).finalize_reset(),
+            crc.algorithm.check
+        );
+    }
+
+    #[test]
+    fn calc_byte() {
+        let mut crc = CRC::<u32>::from_algorithm(CRC_24_OPENPGP);
+        crc.initialize();
+        for byte in CHECK_BYTES {
+            crc.calc_byte(*byte);
+        }
+        assert_eq!(crc.finalize(), crc.algorithm.check);
+    }
+
+    #[test]
+    fn extend() {
+        let mut crc = CRC::<u128, Nibble<u128>>::from_algorithm(CRC_82_DARC);
+        let bytes: [u8; 200] = core::array::from_fn(|i| i as u8 ^ 0xa5);
+        let checksum = crc.checksum(&bytes);
+        crc.initialize().extend(bytes);
+        assert_eq!(crc.finalize(), checksum);
+        crc.initialize().extend(&bytes[..64]);
+        crc.extend(bytes[64..].iter());
+        assert_eq!(crc.finalize(), checksum);
+    }
+
+    #[test]
+    fn calc_iter() {
+        let mut crc = CRC::<u16>::from_algorithm(CRC_12_UMTS);
+        let checksum = crc.checksum_iter(CHECK_BYTES.iter().copied());
+        assert_eq!(checksum, crc.algorithm.check);
+        crc.initialize()
+            .calc_iter(CHECK_BYTES[..3].iter().copied())
+            .calc_bytes(&CHECK_BYTES[3..]);
+        assert_eq!(crc.finalize(), checksum);
+        assert_eq!(crc.checksum_iter(core::iter::empty()), crc.checksum(&[]));
+    }
+
+    #[test]
+    fn try_calc_iter() {
+        let mut crc = CRC::<u32>::from_algorithm(CRC_32_ISO_HDLC);
+        let bytes: [u8; 200] = test_bytes();
+        let checksum = crc.try_checksum_iter(bytes.iter().map(|&b| Ok::<_, ()>(b)));
+        assert_eq!(checksum, Ok(crc.checksum(&bytes)));
+
+        // Bytes before the error are calculated.
+        let iter = bytes.iter().enumerate();
+        let iter = iter.map(|(i, &b)| if i == 150 { Err(i) } else { Ok(b) });
+        assert_eq!(crc.initialize().try_calc_iter(iter).err(), Some(150));
+        assert_eq!(crc.finalize(), crc.checksum(&bytes[..150]));
+
+        let chunks = bytes.chunks(7).map(Ok::<_, ()>);
+        assert_eq!(crc.try_checksum_chunks(chunks), Ok(crc.checksum(&bytes)));
+        let chunks = [Ok(&bytes[..9]), Err(()), Ok(&bytes[9..])];
+        assert_eq!(crc.initialize().try_calc_chunks(chunks).err(), Some(()));
+        assert_eq!(crc.len(), 9);
+    }
+
+    #[test]
+    fn calc_slices() {
+        let mut crc = CRC::<u32>::from_algorithm(CRC_31_PHILIPS);
+        let (a, b) = CHECK_BYTES.split_at(5);
+        assert_eq!(crc.checksum_slices(&[a, &[], b]), crc.algorithm.check);
+        assert_eq!(crc.checksum_slices::<&[u8]>(&[]), crc.checksum(&[]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn calc_slices_io() {
+        use std::io::IoSlice;
+
+        let mut crc = CRC::<u32>::from_algorithm(CRC_24_BLE);
+        let (a, b) = CHECK_BYTES.split_at(2);
+        let slices = [IoSlice::new(a), IoSlice::new(b)];
+        assert_eq!(crc.checksum_slices(&slices), crc.algorithm.check);
+    }
+
+    #[test]
+    fn raw_value() {
+        let mut crc = CRC::<u8>::from_algorithm(CRC_5_USB);
+        // init
+        assert_eq!(crc.raw_value(), 0x1f);
+        crc.set_raw_value(0xff);
+        assert_eq!(crc.raw_value(), 0x1f);
+
+        let mut crc = CRC::<u16>::from_algorithm(CRC_12_UMTS);
+        crc.set_raw_value(0xabc);
+        assert_eq!(crc.value, 0xabc0);
+        assert_eq!(crc.raw_value(), 0xabc);
+        // Register of `refin == false` after a single `1` bit from zero is the polynomial.
+        crc.set_raw_value(0).calc_bit(true);
+        assert_eq!(crc.raw_value(), crc.algorithm.poly);
+    }
+
+    #[test]
+    fn hw_register() {
+        fn hw_register<T: CrcWidth>(mut crc: CRC<T>) {
+            let registers = [false, true]
+                .map(|reflected| [false, true].map(|xorout| HwRegister { reflected, xorout }));
+            for &register in registers.iter().flatten() {
+                let value = crc.initialize().calc_bytes(b"1234").value;
+                let hw_register = crc.to_hw_register(register);
+                assert_eq!(crc.from_hw_register(hw_register, register).value, value);
+                assert_eq!(crc.calc_bytes(b"56789").finalize(), crc.algorithm.check);
+            }
+            // Reflected register with `xorout` of an algorithm of `refout` is the checksum.
+            let register = HwRegister {
+                reflected: crc.algorithm.refout,
+                xorout: true,
+            };
+            crc.initialize().calc_bytes(CHECK_BYTES);
+            assert_eq!(crc.to_hw_register(register), crc.algorithm.check);
+        }
+        hw_register(CRC::<u8>::from_algorithm(CRC_5_USB));
+        hw_register(CRC::<u16>::from_algorithm(CRC_12_UMTS));
+        hw_register(CRC::<u32>::from_algorithm(CRC_24_OPENPGP));
+        hw_register(CRC::<u32>::from_algorithm(CRC_32_ISO_HDLC));
+        hw_register(CRC::<u128>::from_algorithm(CRC_82_DARC));
+    }
+
+    #[test]
+    #[should_panic(expected = "algorithm.check is not equal to check")]
+    fn assert_check_eq() {
+        const ARC: CRC<u16> =
+            CRC::<u16>::new(Endian::Little, 0x8005, 0, true, true, 0).assert_check_eq(0xbb3d);
+        ARC.assert_check_eq(0xbb3e);
+    }
+
+    #[test]
+    fn len() {
+        let mut crc = CRC::<u8>::from_algorithm(CRC_7_MMC);
+        assert!(crc.is_empty());
+        crc.calc_bytes(b"12").calc_byte(b'3').calc_zeros(100);
+        crc.extend(b"456");
+        crc.calc_bit(true);
+        assert_eq!(crc.len(), 106);
+        crc.with_init(0);
+        assert_eq!(crc.len(), 0);
+        crc.checksum(CHECK_BYTES);
+        assert_eq!(crc.len(), CHECK_BYTES.len());
+        let checksum = crc.finalize();
+        assert_eq!(crc.resume(checksum).len(), 0);
+        crc.calc_bytes(b"1");
+        assert_eq!(crc.set_raw_value(0).len(), 0);
+        crc.calc_bytes(b"1");
+        assert_eq!(crc.from_hw_register(0, HwRegister::default()).len(), 0);
+    }
+
+    #[test]
+    fn digest() {
+        let crc = CRC::<u128>::from_algorithm(CRC_82_DARC);
+        let mut digest = crc.digest();
+        for byte in CHECK_BYTES {
+            digest.update(&[*byte]);
+        }
+        assert_eq!(digest.finalize(), crc.algorithm.check);
+        // The value of `crc` is not used.
+        let mut crc = crc;
+        crc.calc_bytes(b"123");
+        assert_eq!(
+            crc.digest().update(CHECK_BYTES).finalize(),
+            crc.algorithm.check
+        );
+    }
+
+    #[test]
+    fn calc_zeros() {
+        fn calc_zeros<T: CrcWidth>(mut crc: CRC<T>) {
+            for len in [0, 1, 2, 7, 100, 1000] {
+                crc.initialize().calc_bytes(CHECK_BYTES);
+                let mut bytes = crc;
+                let zeros = bytes.calc_bytes(&[0; 1000][..len]).finalize();
+                assert_eq!(crc.calc_zeros(len).finalize(), zeros);
+            }
+        }
+        calc_zeros(CRC::<u8>::from_algorithm(CRC_3_GSM));
+        calc_zeros(CRC::<u8>::from_algorithm(CRC_5_USB));
+        calc_zeros(CRC::<u16>::from_algorithm(CRC_12_UMTS));
+        calc_zeros(CRC::<u32>::from_algorithm(CRC_24_OPENPGP));
+        calc_zeros(CRC::<u32>::from_algorithm(CRC_32_ISO_HDLC));
+        calc_zeros(CRC::<u128>::from_algorithm(CRC_82_DARC));
+    }
+
+    #[test]
+    fn calc_bits() {
+        let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISO_HDLC);
+        // LSB first.
+        let bits = CHECK_BYTES
+            .iter()
+            .flat_map(|byte| (0..8).map(move |i| byte >> i & 1 == 1));
+        crc32.initialize().calc_bits(bits);
+        assert_eq!(crc32.finalize(), 0xcbf43926);
+
+        let mut crc15 =
+            CRC::<u16>::new_with_width(Endian::Big, 15, 0x4599, 0x0000, false, false, 0x0000);
+        // MSB first.
+        let bits = CHECK_BYTES
+            .iter()
+            .flat_map(|byte| (0..8).rev().map(move |i| byte >> i & 1 == 1));
+        crc15.initialize().calc_bits(bits);
+        assert_eq!(crc15.finalize(), 0x059e);
+
+        crc15.initialize().calc_bits_msb(0x3132_3334, 32);
+        crc15.calc_bits_msb(0x35_3637_3839, 40).calc_bits_msb(0, 0);
+        assert_eq!(crc15.finalize(), 0x059e);
+    }
+
+    #[test]
+    fn detects_corruption() {
+        let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+        let message: [u8; 200] = core::array::from_fn(|i| i as u8);
+        for bit in 0..8 * message.len() {
+            assert!(crc32.detects_corruption(&message, bit / 8, &[1 << (bit % 8)]));
+        }
+        assert!(crc32.detects_corruption(&message, 10, &[0xff; 100]));
+        assert!(!crc32.detects_corruption(&message, 10, &[0; 100]));
+        assert!(!crc32.detects_corruption(&message, 200, &[]));
+
+        // x^8 + x^2 + x + 1 across bytes.
+        let mut crc8 = CRC::<u8>::from_algorithm(CRC_8_SMBUS);
+        assert!(!crc8.detects_corruption(&message, 150, &[0x01, 0x07]));
+        assert!(!crc8.detects_corruption(&message, 0, &[0x02, 0x0e]));
+        assert!(crc8.detects_corruption(&message, 0, &[0x01, 0x06]));
+    }
+
+    #[test]
+    fn bit_error() {
+        fn assert_bit_errors<T: CrcWidth>(mut crc: CRC<T>) {
+            let mut frame = [0; 20];
+            frame[..9].copy_from_slice(CHECK_BYTES);
+            let checksum = crc.checksum_to_endian_bytes(CHECK_BYTES);
+            let end = 9 + checksum.len();
+            frame[9..end].copy_from_slice(&checksum);
+            let original = frame;
+            let frame = &mut frame[..end];
+            assert_eq!(crc.locate_bit_error(frame), Ok(None));
+
+            // Message and the bytes of the checksum without padding.
+            for position in 0..8 * (9 + crc.algorithm.width as usize / 8) {
+                frame[position / 8] ^= 1 << (position % 8);
+                assert_eq!(crc.correct_bit_error(frame), Ok(Some(position)));
+                assert_eq!(frame, &original[..end]);
+            }
+        }
+
+        assert_bit_errors(CRC::<u8>::from_algorithm(CRC_8_SMBUS));
+        assert_bit_errors(CRC::<u16>::from_algorithm(CRC_12_UMTS));
+        assert_bit_errors(CRC::<u32>::from_algorithm(CRC_32_BZIP2));
+
+        let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISO_HDLC);
+        let mut frame = *b"123456789\x26\x39\xf4\xcb";
+        frame[0] ^= 1;
+        frame[8] ^= 1;
+        assert!(matches!(
+            crc32.correct_bit_error(&mut frame),
+            Err(CrcError::Mismatch(_))
+        ));
+        assert_eq!(
+            crc32.locate_bit_error(b"123"),
+            Err(CrcError::TooShort {
+                expected: 4,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn self_test() {
+        let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISO_HDLC);
+        assert_eq!(crc32.self_test(), Ok(()));
+        assert_eq!(
+            crc32
+                .initialize()
+                .calc_bytes(CHECK_BYTES)
+                .finalize_checked(),
+            Ok(0xcbf43926)
+        );
+
+        // Corrupted table.
+        crc32.table[0xff ^ 0x31] ^= 1;
+        assert!(matches!(
+            crc32.self_test(),
+            Err(SelfTestError::Check { .. })
+        ));
+
+        // The entry is only used by the message of `check`.
+        assert_eq!(crc32.checksum_verified(b"abc"), Ok(0x352441c2));
+        assert!(matches!(
+            crc32.checksum_verified(CHECK_BYTES),
+            Err(SelfTestError::Table { actual, .. }) if actual != 0xcbf43926
+        ));
+
+        // Corrupted value with a bit outside the register, of non-reflected and reflected algorithms.
+        // CRC-24/OPENPGP and CRC-24/BLE
+        let mut crc24 =
+            CRC::<u32>::new_with_width(Endian::Big, 24, 0x864cfb, 0xb704ce, false, false, 0);
+        crc24.calc_bytes(CHECK_BYTES).value ^= 1;
+        assert!(matches!(
+            crc24.finalize_checked(),
+            Err(SelfTestError::Finalize { expected, actual }) if expected ^ actual == 1
+        ));
+        let mut crc24 =
+            CRC::<u32>::new_with_width(Endian::Little, 24, 0x00065b, 0x555555, true, true, 0);
+        crc24.calc_bytes(CHECK_BYTES).value ^= 1 << 31;
+        assert!(matches!(
+            crc24.finalize_checked(),
+            Err(SelfTestError::Finalize { expected, actual }) if expected ^ actual == 1 << 31
+        ));
+    }
+
     #[test]
     fn residue() {
         // True if little-endian.
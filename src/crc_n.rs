@@ -0,0 +1,208 @@
+use crate::{Algorithm, CrcWidth, Endian, CRC};
+
+/// Cyclic redundancy check whose width is a const parameter.
+///
+/// The value is held in `u64`, so `WIDTH` must be in `1..=64`.
+/// `poly`, `init` and `xorout` are masked to `WIDTH` bits.
+///
+/// # Migration from [`CRC<T>`]
+/// [`CrcN::from_crc`] and [`CrcN::into_crc`] convert from/to [`CRC<u64>`].
+///
+/// ```
+/// use mycrc::{CrcN, Endian};
+///
+/// // CRC-10/ATM
+/// let mut crc10 = CrcN::<10>::new(Endian::Big, 0x233, 0x000, false, false, 0x000);
+/// assert_eq!(crc10.checksum(b"123456789"), 0x199);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CrcN<const WIDTH: usize> {
+    crc: CRC<u64>,
+}
+
+impl<const WIDTH: usize> CrcN<WIDTH> {
+    const VALID_WIDTH: () = assert!(WIDTH >= 1 && WIDTH <= 64, "WIDTH must be in 1..=64");
+
+    /// Mask of `WIDTH` bits, or 0 if `WIDTH` is not in `1..=64`.
+    pub const MASK: u64 = if WIDTH == 0 || WIDTH > 64 {
+        0
+    } else {
+        u64::MAX >> (64 - WIDTH)
+    };
+
+    /// Create your own CRC.
+    pub const fn new(
+        endian: Endian,
+        poly: u64,
+        init: u64,
+        refin: bool,
+        refout: bool,
+        xorout: u64,
+    ) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_WIDTH;
+
+        Self {
+            crc: CRC::<u64>::new_with_width(
+                endian,
+                WIDTH as u8,
+                poly & Self::MASK,
+                init & Self::MASK,
+                refin,
+                refout,
+                xorout & Self::MASK,
+            ),
+        }
+    }
+
+    /// The algorithm initializes the value and creates the table.
+    ///
+    /// # Panics
+    /// Panics if `algorithm.width` is not equal to `WIDTH`.
+    pub const fn from_algorithm(algorithm: Algorithm<u64>) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_WIDTH;
+        assert!(
+            algorithm.width as usize == WIDTH,
+            "algorithm.width must be equal to WIDTH"
+        );
+
+        Self {
+            crc: CRC::<u64>::from_algorithm(algorithm),
+        }
+    }
+
+    /// Convert from [`CRC<u64>`].
+    /// Returns `None` if `crc.algorithm.width` is not equal to `WIDTH`.
+    pub const fn from_crc(crc: CRC<u64>) -> Option<Self> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_WIDTH;
+
+        if crc.algorithm.width as usize == WIDTH {
+            Some(Self { crc })
+        } else {
+            None
+        }
+    }
+
+    /// Convert into [`CRC<u64>`].
+    pub const fn into_crc(self) -> CRC<u64> {
+        self.crc
+    }
+
+    /// Reference to the inner [`CRC<u64>`].
+    pub const fn as_crc(&self) -> &CRC<u64> {
+        &self.crc
+    }
+
+    /// Algorithm.
+    pub const fn algorithm(&self) -> &Algorithm<u64> {
+        &self.crc.algorithm
+    }
+
+    /// Initialize value.
+    pub fn initialize(&mut self) -> &mut Self {
+        self.crc.initialize();
+        self
+    }
+
+    /// Calculate bytes.
+    pub fn calc_bytes(&mut self, bytes: &[u8]) -> &mut Self {
+        self.crc.calc_bytes(bytes);
+        self
+    }
+
+    /// Finalize value.
+    /// Change value to checksum.
+    pub fn finalize(&self) -> u64 {
+        self.crc.finalize()
+    }
+
+    /// Finalize to endian bytes.
+    pub fn finalize_to_endian_bytes(&self) -> <u64 as CrcWidth>::Bytes {
+        self.crc.finalize_to_endian_bytes()
+    }
+
+    /// Checksum function.
+    pub fn checksum(&mut self, bytes: &[u8]) -> u64 {
+        self.crc.checksum(bytes)
+    }
+
+    /// Checksum to endian bytes.
+    pub fn checksum_to_endian_bytes(&mut self, bytes: &[u8]) -> <u64 as CrcWidth>::Bytes {
+        self.crc.checksum_to_endian_bytes(bytes)
+    }
+
+    /// Check if `value` is error-free.
+    /// Returns `true` if error-free.
+    pub fn is_error_free(&mut self) -> bool {
+        self.crc.is_error_free()
+    }
+
+    /// Check if bytes [message + checksum] are error-free.
+    /// Returns `true` if error-free.
+    ///
+    /// `WIDTH` must be a multiple of 8.
+    pub fn is_error_free_bytes(&mut self, bytes: &[u8]) -> bool {
+        self.crc.is_error_free_bytes(bytes)
+    }
+}
+
+impl<const WIDTH: usize> From<CrcN<WIDTH>> for CRC<u64> {
+    fn from(crc: CrcN<WIDTH>) -> Self {
+        crc.into_crc()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHECK_BYTES: &[u8] = b"123456789";
+
+    #[test]
+    fn check() {
+        // CRC-11/FLEXRAY
+        let mut crc11 = CrcN::<11>::new(Endian::Big, 0x385, 0x01a, false, false, 0x000);
+        assert_eq!(crc11.checksum(CHECK_BYTES), 0x5a3);
+        // CRC-13/BBC
+        let mut crc13 = CrcN::<13>::new(Endian::Big, 0x1cf5, 0x0000, false, false, 0x0000);
+        assert_eq!(crc13.checksum(CHECK_BYTES), 0x04fa);
+        // CRC-21/CAN-FD
+        let mut crc21 = CrcN::<21>::new(Endian::Big, 0x102899, 0x000000, false, false, 0x000000);
+        assert_eq!(crc21.checksum(CHECK_BYTES), 0x0ed841);
+        // CRC-30/CDMA
+        let mut crc30 = CrcN::<30>::new(
+            Endian::Big,
+            0x2030b9c7,
+            0x3fffffff,
+            false,
+            false,
+            0x3fffffff,
+        );
+        assert_eq!(crc30.checksum(CHECK_BYTES), 0x04c34abf);
+        assert_eq!(crc30.algorithm().residue, 0x34efa55a);
+    }
+
+    #[test]
+    fn mask() {
+        // Bits above `WIDTH` are ignored.
+        let crc15 = CrcN::<15>::new(Endian::Big, 0xc599, 0x0000, false, false, 0x0000);
+        assert_eq!(crc15.algorithm().poly, 0x4599);
+        assert_eq!(CrcN::<64>::MASK, u64::MAX);
+        // Evaluated without overflow, though `new` rejects the width.
+        assert_eq!(CrcN::<0>::MASK, 0);
+        assert_eq!(CrcN::<65>::MASK, 0);
+    }
+
+    #[test]
+    fn migration() {
+        let crc = CRC::<u64>::new_with_width(Endian::Big, 10, 0x233, 0x000, false, false, 0x000);
+        let mut crc10 = CrcN::<10>::from_crc(crc).unwrap();
+        assert_eq!(crc10.checksum(CHECK_BYTES), 0x199);
+        assert!(CrcN::<11>::from_crc(crc).is_none());
+
+        let mut crc: CRC<u64> = crc10.into();
+        assert_eq!(crc.checksum(CHECK_BYTES), 0x199);
+    }
+}
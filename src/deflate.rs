@@ -0,0 +1,105 @@
+use crate::{table::CRC_32_ISO_HDLC, Algorithm, MismatchError, TrailerError};
+
+/// Modulus of Adler-32, the largest prime below `2^16`.
+const ADLER_MOD: u32 = 65521;
+/// Most bytes before the sums of Adler-32 may overflow `u32`.
+const ADLER_NMAX: usize = 5552;
+
+/// CRC-32 of gzip (CRC-32/ISO-HDLC).
+pub fn crc32(data: &[u8]) -> u32 {
+    !Algorithm::<u32>::calc_bytes_with_values(true, !0, data, &CRC_32_ISO_HDLC)
+}
+
+/// Adler-32 of zlib.
+pub fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1, 0);
+    for chunk in data.chunks(ADLER_NMAX) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= ADLER_MOD;
+        b %= ADLER_MOD;
+    }
+    b << 16 | a
+}
+
+/// Verify the 8-byte trailer of a gzip member [CRC32 + ISIZE, both little-endian] with the decompressed `data`.
+///
+/// ```
+/// use mycrc::deflate;
+///
+/// let trailer = [0x26, 0x39, 0xf4, 0xcb, 9, 0, 0, 0];
+/// assert_eq!(deflate::verify_gzip_trailer(b"123456789", &trailer), Ok(()));
+/// ```
+pub fn verify_gzip_trailer(data: &[u8], trailer: &[u8; 8]) -> Result<(), TrailerError> {
+    let [c0, c1, c2, c3, s0, s1, s2, s3] = *trailer;
+    let expected = u32::from_le_bytes([c0, c1, c2, c3]);
+    let actual = crc32(data);
+    if actual != expected {
+        return Err(TrailerError::Crc(MismatchError { expected, actual }));
+    }
+    let expected = u32::from_le_bytes([s0, s1, s2, s3]);
+    // ISIZE is the length modulo 2^32.
+    let actual = data.len() as u32;
+    if actual != expected {
+        return Err(TrailerError::Size(MismatchError { expected, actual }));
+    }
+    Ok(())
+}
+
+/// Verify the 4-byte trailer of a zlib stream [Adler-32, big-endian] with the decompressed `data`.
+///
+/// ```
+/// use mycrc::deflate;
+///
+/// assert_eq!(deflate::verify_zlib_trailer(b"Wikipedia", &[0x11, 0xe6, 0x03, 0x98]), Ok(()));
+/// ```
+pub fn verify_zlib_trailer(data: &[u8], trailer: &[u8; 4]) -> Result<(), TrailerError> {
+    let expected = u32::from_be_bytes(*trailer);
+    let actual = adler32(data);
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(TrailerError::Adler(MismatchError { expected, actual }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trailers() {
+        assert_eq!(adler32(&[]), 1);
+        // The sums are reduced at least every `ADLER_NMAX` bytes.
+        let ones = [0xff; 3 * ADLER_NMAX + 1];
+        let (mut a, mut b) = (1u64, 0u64);
+        for &byte in &ones[..] {
+            a = (a + byte as u64) % ADLER_MOD as u64;
+            b = (b + a) % ADLER_MOD as u64;
+        }
+        assert_eq!(adler32(&ones), (b << 16 | a) as u32);
+
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+        let trailer = [0x26, 0x39, 0xf4, 0xcb, 10, 0, 0, 0];
+        assert_eq!(
+            verify_gzip_trailer(b"123456789", &trailer),
+            Err(TrailerError::Size(MismatchError {
+                expected: 10,
+                actual: 9
+            }))
+        );
+        assert_eq!(
+            verify_gzip_trailer(b"12345678", &trailer),
+            Err(TrailerError::Crc(MismatchError {
+                expected: 0xcbf43926,
+                actual: crc32(b"12345678")
+            }))
+        );
+        assert!(matches!(
+            verify_zlib_trailer(b"wikipedia", &[0x11, 0xe6, 0x03, 0x98]),
+            Err(TrailerError::Adler(_))
+        ));
+    }
+}
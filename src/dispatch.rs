@@ -0,0 +1,134 @@
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// CPU features used by [`Hardware`](crate::Hardware).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct CpuFeatures {
+    /// SSE4.2 of `x86_64`
+    pub sse42: bool,
+    /// PCLMULQDQ and SSSE3 of `x86_64`
+    pub pclmulqdq: bool,
+    /// CRC extension of `aarch64`
+    pub crc: bool,
+    /// PMULL of `aarch64`
+    pub pmull: bool,
+}
+
+const SSE42: u8 = 1 << 0;
+const PCLMULQDQ: u8 = 1 << 1;
+const CRC: u8 = 1 << 2;
+const PMULL: u8 = 1 << 3;
+/// Features are probed or set.
+const KNOWN: u8 = 1 << 7;
+
+static FEATURES: AtomicU8 = AtomicU8::new(0);
+
+impl CpuFeatures {
+    /// Probe features of the CPU.
+    ///
+    /// Uses `is_x86_feature_detected!` and `is_aarch64_feature_detected!` with the `std` feature.
+    /// Otherwise, `cpuid` on `x86_64` and enabled target features on the other targets.
+    pub fn detect() -> Self {
+        #[allow(unused_mut)]
+        let mut features = Self::default();
+
+        #[cfg(all(target_arch = "x86_64", feature = "std"))]
+        {
+            features.sse42 = std::is_x86_feature_detected!("sse4.2");
+            features.pclmulqdq = std::is_x86_feature_detected!("pclmulqdq")
+                && std::is_x86_feature_detected!("ssse3");
+        }
+        #[cfg(all(target_arch = "x86_64", not(feature = "std")))]
+        {
+            #[allow(unused_unsafe)]
+            let ecx = unsafe { core::arch::x86_64::__cpuid(1) }.ecx;
+            // CPUID.01H:ECX.SSE4_2[bit 20]
+            features.sse42 = ecx >> 20 & 1 == 1;
+            // CPUID.01H:ECX.PCLMULQDQ[bit 1] and CPUID.01H:ECX.SSSE3[bit 9]
+            features.pclmulqdq = ecx >> 1 & 1 == 1 && ecx >> 9 & 1 == 1;
+        }
+        #[cfg(all(target_arch = "aarch64", feature = "std"))]
+        {
+            features.crc = std::arch::is_aarch64_feature_detected!("crc");
+            features.pmull = std::arch::is_aarch64_feature_detected!("pmull");
+        }
+        #[cfg(all(target_arch = "aarch64", not(feature = "std")))]
+        {
+            features.crc = cfg!(target_feature = "crc");
+            features.pmull = cfg!(target_feature = "aes");
+        }
+
+        features
+    }
+
+    const fn to_bits(self) -> u8 {
+        let mut bits = KNOWN;
+        if self.sse42 {
+            bits |= SSE42;
+        }
+        if self.pclmulqdq {
+            bits |= PCLMULQDQ;
+        }
+        if self.crc {
+            bits |= CRC;
+        }
+        if self.pmull {
+            bits |= PMULL;
+        }
+        bits
+    }
+
+    const fn from_bits(bits: u8) -> Self {
+        Self {
+            sse42: bits & SSE42 != 0,
+            pclmulqdq: bits & PCLMULQDQ != 0,
+            crc: bits & CRC != 0,
+            pmull: bits & PMULL != 0,
+        }
+    }
+}
+
+/// Features of the CPU.
+/// Probed with [`CpuFeatures::detect`] on the first call unless set with [`set_features`].
+pub fn features() -> CpuFeatures {
+    let bits = FEATURES.load(Ordering::Relaxed);
+    if bits & KNOWN != 0 {
+        return CpuFeatures::from_bits(bits);
+    }
+
+    let features = CpuFeatures::detect();
+    FEATURES.store(features.to_bits(), Ordering::Relaxed);
+    features
+}
+
+/// Set features of the CPU instead of probing them,
+/// e.g. on `no_std` targets where a probe of the platform knows more than [`CpuFeatures::detect`].
+///
+/// Only affects [`Hardware`](crate::Hardware) tables created after this call.
+///
+/// ```
+/// use mycrc::dispatch::{self, CpuFeatures};
+///
+/// // Disable CRC instructions.
+/// // SAFETY: No features are enabled.
+/// unsafe { dispatch::set_features(CpuFeatures::default()) };
+/// assert_eq!(dispatch::features(), CpuFeatures::default());
+/// ```
+///
+/// # Safety
+/// The CPU must support the enabled features.
+pub unsafe fn set_features(features: CpuFeatures) {
+    FEATURES.store(features.to_bits(), Ordering::Relaxed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bits() {
+        for bits in 0..16 {
+            let features = CpuFeatures::from_bits(bits);
+            assert_eq!(features.to_bits(), bits | KNOWN);
+        }
+    }
+}
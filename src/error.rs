@@ -0,0 +1,248 @@
+use core::fmt;
+
+/// Self-test error.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SelfTestError<T> {
+    /// The checksum of [`CHECK_BYTES`](crate::CHECK_BYTES) is not equal to `check`.
+    Check {
+        /// `check` of the algorithm
+        expected: T,
+        /// recomputed check
+        actual: T,
+    },
+    /// The recomputed residue is not equal to `residue`.
+    Residue {
+        /// `residue` of the algorithm
+        expected: T,
+        /// recomputed residue
+        actual: T,
+    },
+    /// The checksum with the table is not equal to the checksum calculated bit by bit.
+    Table {
+        /// checksum calculated bit by bit
+        expected: T,
+        /// checksum with the table
+        actual: T,
+    },
+    /// The value recomputed from the checksum is not equal to the value.
+    Finalize {
+        /// value
+        expected: T,
+        /// value recomputed from the checksum
+        actual: T,
+    },
+}
+
+impl<T: fmt::LowerHex> fmt::Display for SelfTestError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Check { expected, actual } => {
+                write!(
+                    f,
+                    "check mismatch: expected {expected:#x}, actual {actual:#x}"
+                )
+            }
+            Self::Residue { expected, actual } => {
+                write!(
+                    f,
+                    "residue mismatch: expected {expected:#x}, actual {actual:#x}"
+                )
+            }
+            Self::Table { expected, actual } => {
+                write!(
+                    f,
+                    "table mismatch: expected {expected:#x}, actual {actual:#x}"
+                )
+            }
+            Self::Finalize { expected, actual } => {
+                write!(
+                    f,
+                    "finalize mismatch: expected {expected:#x}, actual {actual:#x}"
+                )
+            }
+        }
+    }
+}
+
+/// Error of [`CRC::verify`](crate::CRC::verify).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MismatchError<T> {
+    /// expected checksum
+    pub expected: T,
+    /// checksum of the message
+    pub actual: T,
+}
+
+impl<T: fmt::LowerHex> fmt::Display for MismatchError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { expected, actual } = self;
+        write!(
+            f,
+            "checksum mismatch: expected {expected:#x}, actual {actual:#x}"
+        )
+    }
+}
+
+/// Error of [`CRC::verify_and_strip`](crate::CRC::verify_and_strip) and [`CRC::verify_bytes`](crate::CRC::verify_bytes).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CrcError<T> {
+    /// The frame is shorter than the checksum.
+    TooShort {
+        /// `(width + 7) / 8` of the algorithm
+        expected: usize,
+        /// length of the frame
+        actual: usize,
+    },
+    /// The checksum bytes are not `(width + 7) / 8` bytes.
+    Length {
+        /// `(width + 7) / 8` of the algorithm
+        expected: usize,
+        /// length of the checksum bytes
+        actual: usize,
+    },
+    /// The checksum of the message is not equal to the trailing checksum.
+    Mismatch(MismatchError<T>),
+}
+
+impl<T> From<MismatchError<T>> for CrcError<T> {
+    fn from(error: MismatchError<T>) -> Self {
+        Self::Mismatch(error)
+    }
+}
+
+impl<T: fmt::LowerHex> fmt::Display for CrcError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooShort { expected, actual } => {
+                write!(
+                    f,
+                    "frame too short: expected at least {expected} bytes, actual {actual}"
+                )
+            }
+            Self::Length { expected, actual } => {
+                write!(
+                    f,
+                    "wrong checksum length: expected {expected} bytes, actual {actual}"
+                )
+            }
+            Self::Mismatch(error) => error.fmt(f),
+        }
+    }
+}
+
+/// Error of the trailers of [`deflate`](crate::deflate) streams.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TrailerError {
+    /// The CRC-32 of the data is not equal to the CRC32 of the gzip trailer.
+    Crc(MismatchError<u32>),
+    /// The length of the data modulo `2^32` is not equal to the ISIZE of the gzip trailer.
+    Size(MismatchError<u32>),
+    /// The Adler-32 of the data is not equal to the zlib trailer.
+    Adler(MismatchError<u32>),
+}
+
+impl fmt::Display for TrailerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Crc(error) => write!(f, "CRC32 {error}"),
+            Self::Size(MismatchError { expected, actual }) => {
+                write!(f, "ISIZE mismatch: expected {expected}, actual {actual}")
+            }
+            Self::Adler(error) => write!(f, "Adler-32 {error}"),
+        }
+    }
+}
+
+/// Error of [`Algorithm::try_new`](crate::Algorithm::try_new).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ParamError {
+    /// `width` is 0 or wider than the type.
+    Width(u8),
+    /// `poly` is 0.
+    ZeroPoly,
+    /// `poly` has no `x^0` term, so the CRC doesn't depend on the last bits of the message.
+    EvenPoly,
+    /// `poly` is wider than `width`.
+    WidePoly,
+    /// `init` is wider than `width`.
+    WideInit,
+    /// `xorout` is wider than `width`.
+    WideXorout,
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Width(width) => write!(f, "width {width} is out of range"),
+            Self::ZeroPoly => f.write_str("poly is zero"),
+            Self::EvenPoly => f.write_str("poly has no x^0 term"),
+            Self::WidePoly => f.write_str("poly is wider than width"),
+            Self::WideInit => f.write_str("init is wider than width"),
+            Self::WideXorout => f.write_str("xorout is wider than width"),
+        }
+    }
+}
+
+/// Error of [`CRC::restore`](crate::CRC::restore).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum StateError {
+    /// The state is of another version of [`CrcState`](crate::CrcState).
+    Version {
+        /// [`CrcState::VERSION`](crate::CrcState::VERSION)
+        expected: u8,
+        /// version of the state
+        actual: u8,
+    },
+    /// The state is of an algorithm of another width.
+    Width {
+        /// `width` of the algorithm
+        expected: u8,
+        /// width of the state
+        actual: u8,
+    },
+}
+
+impl fmt::Display for StateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Version { expected, actual } => {
+                write!(f, "version mismatch: expected {expected}, actual {actual}")
+            }
+            Self::Width { expected, actual } => {
+                write!(f, "width mismatch: expected {expected}, actual {actual}")
+            }
+        }
+    }
+}
+
+/// Error of [`BlockCrcs::merge`](crate::BlockCrcs::merge).
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MergeError {
+    /// No block starts at `offset`, the end of the previous block.
+    Gap {
+        /// end of the previous block
+        offset: usize,
+    },
+    /// The block at `offset` overlaps the previous block.
+    Overlap {
+        /// offset of the block
+        offset: usize,
+    },
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Gap { offset } => write!(f, "no block at offset {offset}"),
+            Self::Overlap { offset } => write!(f, "block at offset {offset} overlaps"),
+        }
+    }
+}
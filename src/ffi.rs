@@ -0,0 +1,125 @@
+use crate::{bindings, CRC};
+#[cfg(feature = "catalog")]
+use core::ffi::{c_char, CStr};
+use std::boxed::Box;
+
+/// Opaque CRC handle of C.
+#[derive(Debug)]
+pub struct MycrcCrc(CRC<u64>);
+
+/// Create a handle of your own algorithm of `width` bits, with the initialized value.
+///
+/// Returns null if the parameters are not of a CRC of `width` bits, see [`Algorithm::try_new`](crate::Algorithm::try_new).
+/// The handle must be freed by [`mycrc_free`].
+#[no_mangle]
+pub extern "C" fn mycrc_new(
+    width: u8,
+    poly: u64,
+    init: u64,
+    refin: bool,
+    refout: bool,
+    xorout: u64,
+) -> *mut MycrcCrc {
+    match bindings::with_params(width, poly, init, refin, refout, xorout) {
+        Ok(crc) => Box::into_raw(Box::new(MycrcCrc(crc))),
+        Err(_) => core::ptr::null_mut(),
+    }
+}
+
+/// Create a handle of the algorithm of [`catalog::by_name`](crate::catalog::by_name), e.g. `"CRC-32/ISCSI"`.
+///
+/// Returns null if the name is unknown or the algorithm is wider than 64 bits.
+/// The handle must be freed by [`mycrc_free`].
+///
+/// # Safety
+/// `name` must be a nul-terminated string.
+#[cfg(feature = "catalog")]
+#[no_mangle]
+pub unsafe extern "C" fn mycrc_new_by_name(name: *const c_char) -> *mut MycrcCrc {
+    let name = match CStr::from_ptr(name).to_str() {
+        Ok(name) => name,
+        Err(_) => return core::ptr::null_mut(),
+    };
+    match bindings::by_name(name) {
+        Ok(crc) => Box::into_raw(Box::new(MycrcCrc(crc))),
+        Err(_) => core::ptr::null_mut(),
+    }
+}
+
+/// Free a handle. Null is ignored.
+///
+/// # Safety
+/// `crc` must be null or a handle which is not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn mycrc_free(crc: *mut MycrcCrc) {
+    if !crc.is_null() {
+        drop(Box::from_raw(crc));
+    }
+}
+
+/// Initialize the value.
+///
+/// # Safety
+/// `crc` must be a handle which is not freed.
+#[no_mangle]
+pub unsafe extern "C" fn mycrc_reset(crc: *mut MycrcCrc) {
+    (*crc).0.initialize();
+}
+
+/// Calculate `len` bytes.
+///
+/// # Safety
+/// `crc` must be a handle which is not freed, and `bytes` must be valid for `len` bytes.
+/// `bytes` may be null if `len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn mycrc_update(crc: *mut MycrcCrc, bytes: *const u8, len: usize) {
+    if len != 0 {
+        (*crc).0.calc_bytes(core::slice::from_raw_parts(bytes, len));
+    }
+}
+
+/// Checksum of the bytes since the value is initialized. The value is not changed.
+///
+/// # Safety
+/// `crc` must be a handle which is not freed.
+#[no_mangle]
+pub unsafe extern "C" fn mycrc_finalize(crc: *const MycrcCrc) -> u64 {
+    (*crc).0.finalize()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CHECK_BYTES;
+
+    #[test]
+    fn ffi() {
+        unsafe {
+            // CRC-16/ARC
+            let crc = mycrc_new(16, 0x8005, 0, true, true, 0);
+            mycrc_update(crc, CHECK_BYTES.as_ptr(), 4);
+            mycrc_update(crc, core::ptr::null(), 0);
+            mycrc_update(crc, CHECK_BYTES[4..].as_ptr(), 5);
+            assert_eq!(mycrc_finalize(crc), 0xbb3d);
+            mycrc_reset(crc);
+            assert_eq!(mycrc_finalize(crc), 0);
+            mycrc_free(crc);
+
+            assert!(mycrc_new(0, 0x8005, 0, true, true, 0).is_null());
+            assert!(mycrc_new(65, 0x8005, 0, true, true, 0).is_null());
+            assert!(mycrc_new(16, 0x8005, 0, true, true, 0x10000).is_null());
+            mycrc_free(core::ptr::null_mut());
+
+            #[cfg(feature = "catalog")]
+            {
+                let crc = mycrc_new_by_name(b"CRC-24/OPENPGP\0".as_ptr().cast());
+                mycrc_update(crc, CHECK_BYTES.as_ptr(), CHECK_BYTES.len());
+                assert_eq!(mycrc_finalize(crc), 0x21cf02);
+                mycrc_free(crc);
+
+                assert!(mycrc_new_by_name(b"CRC-82/DARC\0".as_ptr().cast()).is_null());
+                assert!(mycrc_new_by_name(b"CRC-0/UNKNOWN\0".as_ptr().cast()).is_null());
+            }
+        }
+    }
+}
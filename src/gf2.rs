@@ -0,0 +1,507 @@
+use crate::Algorithm;
+
+/// Polynomial over GF(2) whose bit `i` is the coefficient of `x^i`.
+///
+/// A CRC is the remainder of the message polynomial modulo `x^width + poly`,
+/// so [`Poly::mul_mod`] and [`Poly::xpow_mod`] are the steps that
+/// [`Algorithm::combine`] and [`CRC::calc_zeros`](crate::CRC::calc_zeros) are built on.
+///
+/// ```
+/// use mycrc::{gf2::Poly, Algorithm};
+///
+/// // The value of the message `x^0` after 32 bits is `x^32` modulo the polynomial of CRC-32.
+/// let value = Algorithm::<u32>::calc_bytes_bitwise(32, 0x04c11db7, false, 0, &[1]);
+/// assert_eq!(Poly::<u32>::xpow_mod(32, 32, 0x04c11db7), Poly(value));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Poly<T>(pub T);
+
+/// Prime factors of `2^n - 1` for `n` up to 128, the orders of the multiplicative groups of `GF(2^n)`.
+const MERSENNE_FACTORS: [u128; 233] = [
+    3,
+    5,
+    7,
+    11,
+    13,
+    17,
+    19,
+    23,
+    29,
+    31,
+    37,
+    41,
+    43,
+    47,
+    53,
+    59,
+    61,
+    67,
+    71,
+    73,
+    79,
+    83,
+    89,
+    97,
+    101,
+    103,
+    107,
+    109,
+    113,
+    127,
+    137,
+    151,
+    157,
+    167,
+    191,
+    193,
+    199,
+    223,
+    229,
+    233,
+    239,
+    241,
+    251,
+    257,
+    277,
+    281,
+    283,
+    307,
+    331,
+    337,
+    353,
+    397,
+    431,
+    433,
+    439,
+    457,
+    571,
+    601,
+    631,
+    641,
+    673,
+    683,
+    727,
+    881,
+    911,
+    937,
+    953,
+    1013,
+    1103,
+    1321,
+    1429,
+    1613,
+    1657,
+    1777,
+    1801,
+    2089,
+    2113,
+    2143,
+    2351,
+    2593,
+    2687,
+    2731,
+    2833,
+    2857,
+    2971,
+    3191,
+    3391,
+    4051,
+    4177,
+    4513,
+    5153,
+    5419,
+    5581,
+    6361,
+    6529,
+    6553,
+    8101,
+    8191,
+    8681,
+    9719,
+    11119,
+    11447,
+    13367,
+    14449,
+    14951,
+    20231,
+    20857,
+    23279,
+    23311,
+    26317,
+    29191,
+    30269,
+    32377,
+    37171,
+    38737,
+    43691,
+    49477,
+    61681,
+    65537,
+    65993,
+    69431,
+    71119,
+    86113,
+    86171,
+    87211,
+    92737,
+    100801,
+    106681,
+    121369,
+    122921,
+    131071,
+    152041,
+    153649,
+    174763,
+    178481,
+    179951,
+    201961,
+    228479,
+    246241,
+    262657,
+    268501,
+    274177,
+    279073,
+    321679,
+    384773,
+    524287,
+    525313,
+    599479,
+    649657,
+    858001,
+    1212847,
+    1868569,
+    2099863,
+    2298041,
+    2796203,
+    3033169,
+    3887047,
+    4036961,
+    6700417,
+    10567201,
+    13264529,
+    15790321,
+    18837001,
+    20394401,
+    22253377,
+    22366891,
+    25781083,
+    26295457,
+    48544121,
+    48912491,
+    97685839,
+    107367629,
+    112901153,
+    160465489,
+    164511353,
+    193707721,
+    202029703,
+    212885833,
+    308761441,
+    319020217,
+    420778751,
+    536903681,
+    616318177,
+    715827883,
+    745988807,
+    1824726041,
+    2147483647,
+    2550183799,
+    2931542417,
+    4278255361,
+    4562284561,
+    7830118297,
+    8831418697,
+    23140471537,
+    30327152671,
+    33057806959,
+    54410972897,
+    62983048367,
+    77158673929,
+    131105292137,
+    165768537521,
+    269089806001,
+    761838257287,
+    1113491139767,
+    2932031007403,
+    3203431780337,
+    4363953127297,
+    4432676798593,
+    7432339208719,
+    9361973132609,
+    9857737155463,
+    10052678938039,
+    28059810762433,
+    67280421310721,
+    145295143558111,
+    1066818132868207,
+    2646507710984041,
+    177722253954175633,
+    341117531003194129,
+    581283643249112959,
+    658812288653553079,
+    768614336404564651,
+    2305843009213693951,
+    4710883168879506001,
+    9520972806333758431,
+    3976656429941438590393,
+    57912614113275649087721,
+    870035986098720987332873,
+    13842607235828485645766393,
+    618970019642690137449562111,
+    1786393878363164227858270210279,
+    162259276829213363391578010288127,
+    170141183460469231731687303715884105727,
+];
+
+macro_rules! poly_impl {
+    ( $( $t:ty ),* ) => ($(
+        impl Poly<$t> {
+            /// `0`
+            pub const ZERO: Self = Self(0);
+            /// `1`
+            pub const ONE: Self = Self(1);
+            /// `x`
+            pub const X: Self = Self(2);
+
+            /// Degree, or `None` for [`Self::ZERO`].
+            pub const fn degree(self) -> Option<u32> {
+                if self.0 == 0 {
+                    None
+                } else {
+                    Some(<$t>::BITS - 1 - self.0.leading_zeros())
+                }
+            }
+
+            /// Sum, which is also the difference.
+            pub const fn add(self, other: Self) -> Self {
+                Self(self.0 ^ other.0)
+            }
+
+            /// Carry-less product, as the low and the high halves.
+            pub const fn clmul(self, other: Self) -> (Self, Self) {
+                let mut low = 0;
+                let mut high = 0;
+                let mut i = 0;
+                while i < <$t>::BITS {
+                    if other.0 >> i & 1 == 1 {
+                        low ^= self.0 << i;
+                        if i != 0 {
+                            high ^= self.0 >> (<$t>::BITS - i);
+                        }
+                    }
+                    i += 1;
+                }
+                (Self(low), Self(high))
+            }
+
+            /// Quotient and remainder of `self` divided by `divisor`.
+            ///
+            /// # Panics
+            /// Panics if `divisor` is [`Self::ZERO`].
+            pub const fn div_rem(self, divisor: Self) -> (Self, Self) {
+                let divisor_degree = match divisor.degree() {
+                    Some(degree) => degree,
+                    None => panic!("divisor is zero"),
+                };
+                let mut quotient = 0;
+                let mut remainder = self;
+                while let Some(degree) = remainder.degree() {
+                    if degree < divisor_degree {
+                        break;
+                    }
+                    quotient |= 1 << (degree - divisor_degree);
+                    remainder.0 ^= divisor.0 << (degree - divisor_degree);
+                }
+                (Self(quotient), remainder)
+            }
+
+            /// Remainder of `self` divided by `divisor`.
+            ///
+            /// # Panics
+            /// Panics if `divisor` is [`Self::ZERO`].
+            pub const fn rem(self, divisor: Self) -> Self {
+                self.div_rem(divisor).1
+            }
+
+            /// Greatest common divisor.
+            pub const fn gcd(self, other: Self) -> Self {
+                let mut a = self;
+                let mut b = other;
+                while b.0 != 0 {
+                    let remainder = a.rem(b);
+                    a = b;
+                    b = remainder;
+                }
+                a
+            }
+
+            /// Product modulo `x^width + poly`, where `poly` is in normal notation like [`Algorithm::poly`].
+            ///
+            /// The degree of `self` must be less than `width`, while `other` may be of any degree.
+            /// `width` may be the bits of the type, as the `x^width` term is implicit.
+            pub const fn mul_mod(self, other: Self, width: u8, poly: $t) -> Self {
+                let mask = Algorithm::<$t>::mask(width);
+                let mut product = 0;
+
+                let mut i = <$t>::BITS;
+                while i > 0 {
+                    i -= 1;
+                    let carry = product >> (width - 1) & 1 == 1;
+                    product = (product << 1) & mask;
+                    if carry {
+                        product ^= poly;
+                    }
+                    if other.0 >> i & 1 == 1 {
+                        product ^= self.0;
+                    }
+                }
+
+                Self(product)
+            }
+
+            /// Remainder modulo `x^width + poly`.
+            pub const fn reduce(self, width: u8, poly: $t) -> Self {
+                Self::ONE.mul_mod(self, width, poly)
+            }
+
+            /// `self^exp` modulo `x^width + poly`, in O(log `exp`).
+            pub const fn pow_mod(self, mut exp: u128, width: u8, poly: $t) -> Self {
+                let mut result = Self::ONE.reduce(width, poly);
+                let mut square = self.reduce(width, poly);
+                while exp != 0 {
+                    if exp & 1 == 1 {
+                        result = result.mul_mod(square, width, poly);
+                    }
+                    square = square.mul_mod(square, width, poly);
+                    exp >>= 1;
+                }
+                result
+            }
+
+            /// `x^n` modulo `x^width + poly`, in O(log `n`).
+            pub const fn xpow_mod(n: u128, width: u8, poly: $t) -> Self {
+                Self::X.pow_mod(n, width, poly)
+            }
+
+            /// Returns `true` if `x^width + self` has no factors but 1 and itself.
+            ///
+            /// `self` is of lower degree than `width`, like [`Algorithm::poly`].
+            pub const fn is_irreducible(self, width: u8) -> bool {
+                let poly = self.0;
+                // Ben-Or: `x^(2^i) + x` is the product of the irreducible polynomials of degrees dividing `i`,
+                // so none of it may be a factor for `i` up to `width / 2`.
+                let x = Self::X.reduce(width, poly);
+                let mut power = x;
+                let mut i = 0;
+                while i < width / 2 {
+                    power = power.mul_mod(power, width, poly);
+                    let sum = power.add(x);
+                    let degree = match sum.degree() {
+                        Some(degree) => degree,
+                        None => return false,
+                    };
+                    if degree != 0 {
+                        // gcd(x^width + self, sum), where `x^width + self` may not fit in the type.
+                        let low = sum.0 ^ (1 << degree);
+                        let rem = Self::xpow_mod(width as u128, degree as u8, low).add(self.rem(sum));
+                        if sum.gcd(rem).0 != 1 {
+                            return false;
+                        }
+                    }
+                    i += 1;
+                }
+                true
+            }
+
+            /// Returns `true` if `x^width + self` is irreducible and `x` is of order `2^width - 1` modulo it,
+            /// so that the value cycles through all nonzero values and the period is the longest.
+            ///
+            /// `self` is of lower degree than `width`, like [`Algorithm::poly`].
+            pub const fn is_primitive(self, width: u8) -> bool {
+                let poly = self.0;
+                if !self.is_irreducible(width) {
+                    return false;
+                }
+                let one = Self::ONE.reduce(width, poly);
+                let order = u128::MAX >> (128 - width as u32);
+                if Self::xpow_mod(order, width, poly).0 != one.0 {
+                    return false;
+                }
+                let mut i = 0;
+                while i < MERSENNE_FACTORS.len() {
+                    let factor = MERSENNE_FACTORS[i];
+                    if order % factor == 0
+                        && Self::xpow_mod(order / factor, width, poly).0 == one.0
+                    {
+                        return false;
+                    }
+                    i += 1;
+                }
+                true
+            }
+        }
+    )*)
+}
+
+poly_impl!(u8, u16, u32, u64, u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        // (x + 1)^2 == x^2 + 1
+        assert_eq!(Poly(0b11u8).clmul(Poly(0b11)), (Poly(0b101), Poly(0)));
+        assert_eq!(Poly(0x80u8).clmul(Poly(0x81)), (Poly(0x80), Poly(0x40)));
+        assert_eq!(Poly(0u16).degree(), None);
+        assert_eq!(Poly(0x8005u16).degree(), Some(15));
+
+        // x^3 + 1 == (x + 1)(x^2 + x + 1)
+        assert_eq!(Poly(0b1001u8).div_rem(Poly(0b11)), (Poly(0b111), Poly(0)));
+        assert_eq!(Poly(0b1011u8).div_rem(Poly(0b11)), (Poly(0b110), Poly(1)));
+        assert_eq!(Poly(0b1001u8).gcd(Poly(0b101)), Poly(0b11));
+        assert_eq!(Poly(0b1011u8).gcd(Poly(0b11)), Poly::<u8>::ONE);
+
+        // x^8 + x^2 + x + 1 of CRC-8/SMBUS
+        let x8 = Poly::<u8>::xpow_mod(8, 8, 0x07);
+        assert_eq!(x8, Poly(0x07));
+        assert_eq!(Poly::<u8>::X.pow_mod(9, 8, 0x07), Poly(0x0e));
+        assert_eq!(Poly(0x107u16).reduce(8, 0x07), Poly::<u16>::ZERO);
+        assert_eq!(x8.pow_mod(5, 8, 0x07), Poly::<u8>::xpow_mod(40, 8, 0x07));
+        assert_eq!(Poly::<u8>::xpow_mod(0, 1, 1), Poly::<u8>::ONE);
+    }
+
+    #[test]
+    fn irreducible() {
+        // (irreducible, primitive)
+        let polys8 = [
+            (0x07, false, false),
+            (0x1d, true, true),
+            (0x1b, true, false),
+        ];
+        for (poly, irreducible, primitive) in polys8 {
+            assert_eq!(Poly::<u8>(poly).is_irreducible(8), irreducible);
+            assert_eq!(Poly::<u8>(poly).is_primitive(8), primitive);
+        }
+        assert!(!Poly::<u16>(0x8005).is_irreducible(16));
+        assert!(!Poly::<u16>(0x4599).is_irreducible(15));
+        assert!(Poly::<u32>(0x04c11db7).is_primitive(32));
+        assert!(!Poly::<u32>(0x1edc6f41).is_irreducible(32));
+        assert!(Poly::<u128>(0x87).is_primitive(128));
+        assert!(Poly::<u8>(1).is_primitive(1));
+        assert!(!Poly::<u8>(0).is_primitive(1));
+        assert!(Poly::<u8>(3).is_primitive(3));
+    }
+
+    #[test]
+    fn crc() {
+        // The value from zero is `message * x^width` modulo the polynomial.
+        let (width, poly) = (24, 0x864cfb);
+        let bytes = [0x12, 0x34, 0x56];
+        let message = Poly(0x123456u32);
+        let value = Algorithm::<u32>::calc_bytes_bitwise(width, poly, false, 0, &bytes);
+        let expected = message.mul_mod(Poly::<u32>::xpow_mod(24, width, poly), width, poly);
+        assert_eq!(Poly(value >> 8), expected);
+    }
+}
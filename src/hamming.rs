@@ -0,0 +1,131 @@
+use crate::Algorithm;
+use std::{collections::HashMap, vec::Vec};
+
+impl<T: Copy + Into<u128>> Algorithm<T> {
+    /// Minimum Hamming distance of the polynomial for messages of `len` bytes, like the tables of Koopman.
+    ///
+    /// This is the fewest bits of an error [message + checksum] that the algorithm misses.
+    /// Returns `None` if all errors of up to `max_weight` bits are detected.
+    /// `init`, `xorout` and the reflections don't change the distance.
+    ///
+    /// Errors of `w` bits are searched in O(`n^(w - 1)`) for `n` bits of message and checksum,
+    /// so `max_weight` above 4 is practical only for short messages.
+    ///
+    /// Requires the `std` feature.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::catalog::CRC_8_SMBUS;
+    ///
+    /// // x^8 + x^2 + x + 1 has a period of 127 bits, and x + 1 detects errors of odd bits.
+    /// assert_eq!(CRC_8_SMBUS.hamming_distance(14, 8), Some(4));
+    /// assert_eq!(CRC_8_SMBUS.hamming_distance(15, 8), Some(2));
+    /// # }
+    /// ```
+    pub fn hamming_distance(&self, len: usize, max_weight: u8) -> Option<u8> {
+        let syndromes = self.syndromes(len);
+        // Last bit of each syndrome.
+        let last: HashMap<_, _> = syndromes.iter().enumerate().map(|(i, &s)| (s, i)).collect();
+
+        (1..=max_weight).find(|&weight| undetected(&syndromes, &last, weight, 0, 0))
+    }
+
+    /// First error of 2 bits that the algorithm misses in messages of up to `len` bytes, or `None` if all are detected.
+    ///
+    /// The error is `x^i + x^j` with `i < j` of the smallest `j`, where `x^0` is the last bit of the checksum,
+    /// so it also fits in every shorter message that has `j` bits of message and checksum.
+    /// Every pair of bits is checked, by the remainder of each bit modulo the polynomial.
+    ///
+    /// Requires the `std` feature.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::catalog::CRC_8_SMBUS;
+    ///
+    /// // x^8 + x^2 + x + 1 divides x^127 + 1.
+    /// assert_eq!(CRC_8_SMBUS.undetected_two_bit_error(14), None);
+    /// assert_eq!(CRC_8_SMBUS.undetected_two_bit_error(15), Some((0, 127)));
+    /// # }
+    /// ```
+    pub fn undetected_two_bit_error(&self, len: usize) -> Option<(usize, usize)> {
+        let mut first = HashMap::new();
+        for (j, syndrome) in self.syndromes(len).into_iter().enumerate() {
+            if let Some(&i) = first.get(&syndrome) {
+                return Some((i, j));
+            }
+            first.insert(syndrome, j);
+        }
+        None
+    }
+
+    /// `x^i` modulo the polynomial for each bit `i` of message of `len` bytes and checksum.
+    fn syndromes(&self, len: usize) -> Vec<u128> {
+        let (width, poly) = (self.width, self.poly.into());
+        let mask = Algorithm::<u128>::mask(width);
+
+        let bits = 8 * len + width as usize;
+        let mut syndromes = Vec::with_capacity(bits);
+        let mut syndrome = 1 & mask;
+        for _ in 0..bits {
+            syndromes.push(syndrome);
+            let carry = syndrome >> (width - 1) & 1 == 1;
+            syndrome = (syndrome << 1) & mask;
+            if carry {
+                syndrome ^= poly;
+            }
+        }
+        syndromes
+    }
+}
+
+/// Whether `weight` bits from `start` have syndromes whose sum is `sum`.
+fn undetected(
+    syndromes: &[u128],
+    last: &HashMap<u128, usize>,
+    weight: u8,
+    start: usize,
+    sum: u128,
+) -> bool {
+    if weight == 1 {
+        return last.get(&sum).is_some_and(|&i| i >= start);
+    }
+    (start..syndromes.len())
+        .any(|i| undetected(syndromes, last, weight - 1, i + 1, sum ^ syndromes[i]))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Endian, CRC};
+
+    #[test]
+    fn hamming_distance() {
+        // CRC-16/ARC, which is of a period of 32767 bits.
+        let arc = CRC::<u16>::new(Endian::Little, 0x8005, 0, true, true, 0).algorithm;
+        assert_eq!(arc.hamming_distance(8, 3), None);
+        assert_eq!(arc.hamming_distance(8, 4), Some(4));
+        assert_eq!(arc.hamming_distance(4096, 2), Some(2));
+        assert_eq!(arc.hamming_distance(4093, 2), None);
+
+        // CRC-32/ISO-HDLC detects more than 4 bits of errors in short messages.
+        let hdlc = CRC::<u32>::new(Endian::Little, 0x04c11db7, !0, true, true, !0).algorithm;
+        assert_eq!(hdlc.hamming_distance(16, 4), None);
+
+        // The error of the polynomial itself is missed.
+        let even = CRC::<u8>::new(Endian::Big, 0x06, 0, false, false, 0).algorithm;
+        assert_eq!(even.hamming_distance(1, 8), Some(3));
+    }
+
+    #[test]
+    fn undetected_two_bit_error() {
+        // CRC-16/ARC
+        let arc = CRC::<u16>::new(Endian::Little, 0x8005, 0, true, true, 0).algorithm;
+        assert_eq!(arc.undetected_two_bit_error(4093), None);
+        assert_eq!(arc.undetected_two_bit_error(4094), Some((0, 32767)));
+
+        // The error of the polynomial x^3 + x itself is missed.
+        let even = CRC::<u8>::new_with_width(Endian::Big, 3, 0x2, 0, false, false, 0).algorithm;
+        assert_eq!(even.undetected_two_bit_error(1), Some((1, 3)));
+    }
+}
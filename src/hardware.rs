@@ -0,0 +1,533 @@
+use crate::{dispatch, Algorithm, CrcWidth, CreateTable, Table};
+use core::mem;
+
+/// Table that uses CRC instructions of the CPU when the algorithm and the CPU support them.
+///
+/// Supported instructions are chosen with [`dispatch::features`] when the table is created, and the table is used otherwise.
+///
+/// | Algorithm | Target | Instructions |
+/// | --- | --- | --- |
+/// | CRC-32/ISCSI (CRC-32C) | `x86_64` with SSE4.2 | `crc32` |
+/// | CRC-32/ISO-HDLC and CRC-32/ISCSI | `aarch64` with the CRC extension | `crc32x` and `crc32cx` |
+/// | Any algorithm of `u32` or `u64` | `x86_64` with PCLMULQDQ and SSSE3 | `pclmulqdq` folding |
+/// | Any algorithm of `u32` or `u64` | `aarch64` with PMULL | `pmull` folding |
+///
+/// Algorithms of `u8`, `u16` and `u128` always use the table.
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// use mycrc::{catalog::CRC_32_ISCSI, Hardware, CRC};
+///
+/// let mut crc32 = CRC::<u32, Hardware<u32>>::create(CRC_32_ISCSI);
+/// assert_eq!(crc32.checksum(b"123456789"), 0xe3069283);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Hardware<T> {
+    table: [T; 256],
+    crc32: Option<Crc32>,
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    fold: Option<Fold>,
+}
+
+impl<T> Hardware<T> {
+    /// Returns `true` if CRC instructions are used instead of the table.
+    pub fn is_accelerated(&self) -> bool {
+        #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+        if self.fold.is_some() {
+            return true;
+        }
+        self.crc32.is_some()
+    }
+}
+
+/// CRC-32 instructions.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Crc32 {
+    /// CRC-32/ISO-HDLC
+    #[cfg(target_arch = "aarch64")]
+    IsoHdlc,
+    /// CRC-32/ISCSI
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    Castagnoli,
+}
+
+impl Crc32 {
+    fn new<T: CrcWidth + Into<u64>>(algorithm: &Algorithm<T>) -> Option<Self> {
+        if mem::size_of::<T>() != 4 || algorithm.width != 32 || !algorithm.refin {
+            return None;
+        }
+
+        match Into::<u64>::into(algorithm.poly) {
+            #[cfg(target_arch = "x86_64")]
+            CRC32C_POLY if dispatch::features().sse42 => Some(Self::Castagnoli),
+            #[cfg(target_arch = "aarch64")]
+            CRC32C_POLY if dispatch::features().crc => Some(Self::Castagnoli),
+            #[cfg(target_arch = "aarch64")]
+            CRC32_POLY if dispatch::features().crc => Some(Self::IsoHdlc),
+            _ => None,
+        }
+    }
+
+    /// Reflected CRC-32 value of `bytes`.
+    ///
+    /// # Safety
+    /// The instructions must be detected by [`Crc32::new`].
+    unsafe fn calc_bytes(self, value: u32, bytes: &[u8]) -> u32 {
+        match self {
+            #[cfg(target_arch = "x86_64")]
+            Self::Castagnoli => x86_64::crc32c(value, bytes),
+            #[cfg(target_arch = "aarch64")]
+            Self::Castagnoli => aarch64::crc32c(value, bytes),
+            #[cfg(target_arch = "aarch64")]
+            Self::IsoHdlc => aarch64::crc32(value, bytes),
+        }
+    }
+}
+
+macro_rules! hardware_impl {
+    ( $( $t:ty ),* ) => ($(
+        impl CreateTable<$t> for Hardware<$t> {
+            fn create(algorithm: &Algorithm<$t>) -> Self {
+                Self {
+                    table: <$t>::create_table(algorithm),
+                    crc32: Crc32::new(algorithm),
+                    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+                    fold: fold(algorithm),
+                }
+            }
+        }
+
+        impl Table<$t> for Hardware<$t> {
+            fn calc_bytes(&self, algorithm: &Algorithm<$t>, value: $t, bytes: &[u8]) -> $t {
+                #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+                if let Some(fold) = self.fold.filter(|_| bytes.len() >= Fold::MIN_LEN) {
+                    // The value is moved into the first bytes of the message.
+                    let mut init = [0; 16];
+                    if algorithm.refin {
+                        init[..mem::size_of::<$t>()].copy_from_slice(&value.to_le_bytes());
+                    } else {
+                        init[..mem::size_of::<$t>()].copy_from_slice(&value.to_be_bytes());
+                    }
+                    // SAFETY: PCLMULQDQ and SSSE3, or PMULL are detected when the table is created.
+                    #[cfg(target_arch = "x86_64")]
+                    let (folded, tail) = unsafe { x86_64::fold(&fold, init, bytes) };
+                    #[cfg(target_arch = "aarch64")]
+                    let (folded, tail) = unsafe { aarch64::fold(&fold, init, bytes) };
+                    let value = self.calc_bytes(algorithm, 0, &folded);
+                    return self.calc_bytes(algorithm, value, tail);
+                }
+
+                if let Some(crc32) = self.crc32 {
+                    // SAFETY: The instructions are detected when the table is created, and only `u32` uses them.
+                    return unsafe { crc32.calc_bytes(value as u32, bytes) as $t };
+                }
+
+                <$t>::calc_bytes(algorithm, value, bytes, &self.table)
+            }
+        }
+    )*)
+}
+
+hardware_impl!(u32, u64);
+
+// No instructions for other widths, so that `Hardware<T>` is a table of every width.
+macro_rules! table_only_impl {
+    ( $( $t:ty ),* ) => ($(
+        impl CreateTable<$t> for Hardware<$t> {
+            fn create(algorithm: &Algorithm<$t>) -> Self {
+                Self {
+                    table: <$t>::create_table(algorithm),
+                    crc32: None,
+                    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+                    fold: None,
+                }
+            }
+        }
+
+        impl Table<$t> for Hardware<$t> {
+            fn calc_bytes(&self, algorithm: &Algorithm<$t>, value: $t, bytes: &[u8]) -> $t {
+                <$t>::calc_bytes(algorithm, value, bytes, &self.table)
+            }
+        }
+    )*)
+}
+
+table_only_impl!(u8, u16, u128);
+
+/// Polynomial of CRC-32/ISO-HDLC.
+#[cfg(target_arch = "aarch64")]
+const CRC32_POLY: u64 = 0x04c11db7;
+/// Polynomial of CRC-32C.
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+const CRC32C_POLY: u64 = 0x1edc6f41;
+
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+fn fold<T: CrcWidth + Into<u64>>(algorithm: &Algorithm<T>) -> Option<Fold> {
+    #[cfg(target_arch = "x86_64")]
+    let supported = dispatch::features().pclmulqdq;
+    #[cfg(target_arch = "aarch64")]
+    let supported = dispatch::features().pmull;
+    if !supported {
+        return None;
+    }
+    // The value is kept in the most significant bits, so the polynomial is shifted as well.
+    let bits = mem::size_of::<T>() as u32 * 8;
+    let poly = Into::<u64>::into(algorithm.poly) << (bits - algorithm.width as u32);
+    Some(Fold::new(bits, poly, algorithm.refin))
+}
+
+/// Constants to fold 16 bytes into the following bytes with carry-less multiplication.
+///
+/// Each pair is `(high, low)`, multiplied with the high and low 64 bits of the 16 bytes.
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct Fold {
+    /// Fold over 16 bytes.
+    k16: (u64, u64),
+    /// Fold over 64 bytes.
+    k64: (u64, u64),
+    reflected: bool,
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+impl Fold {
+    /// Shorter messages use the table.
+    const MIN_LEN: usize = 128;
+
+    /// `poly` is the polynomial of `bits` width without `x^bits`.
+    fn new(bits: u32, poly: u64, reflected: bool) -> Self {
+        let k = |distance: u32| {
+            if reflected {
+                // The product of bit-reversed operands is shifted by one bit, so the exponents are one less.
+                (
+                    xn_mod(distance - 1, bits, poly).reverse_bits(),
+                    xn_mod(distance + 63, bits, poly).reverse_bits(),
+                )
+            } else {
+                (
+                    xn_mod(distance + 64, bits, poly),
+                    xn_mod(distance, bits, poly),
+                )
+            }
+        };
+
+        Self {
+            k16: k(128),
+            k64: k(512),
+            reflected,
+        }
+    }
+}
+
+/// `x^n` modulo `x^bits + poly`.
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+fn xn_mod(n: u32, bits: u32, poly: u64) -> u64 {
+    let top = 1 << (bits - 1);
+    let mask = u64::MAX >> (64 - bits);
+    let mut value: u64 = 1;
+    for _ in 0..n {
+        let carry = value & top != 0;
+        value = (value << 1) & mask;
+        if carry {
+            value ^= poly;
+        }
+    }
+    value
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86_64 {
+    use super::Fold;
+    use core::arch::x86_64::*;
+
+    /// Reflected CRC-32C value of `bytes`.
+    ///
+    /// # Safety
+    /// The CPU must support SSE4.2.
+    #[target_feature(enable = "sse4.2")]
+    pub(super) unsafe fn crc32c(value: u32, bytes: &[u8]) -> u32 {
+        let mut chunks = bytes.chunks_exact(8);
+        let mut value = value as u64;
+        for chunk in &mut chunks {
+            let mut word = [0; 8];
+            word.copy_from_slice(chunk);
+            value = _mm_crc32_u64(value, u64::from_le_bytes(word));
+        }
+
+        let mut value = value as u32;
+        for &byte in chunks.remainder() {
+            value = _mm_crc32_u8(value, byte);
+        }
+        value
+    }
+
+    /// Fold `bytes` XORed with `init` into 16 bytes which have the same CRC from a value of zero.
+    /// Returns the 16 bytes and the remaining bytes shorter than 16 bytes.
+    ///
+    /// # Safety
+    /// The CPU must support PCLMULQDQ and SSSE3, and `bytes` must be at least 64 bytes long.
+    #[target_feature(enable = "pclmulqdq,ssse3")]
+    pub(super) unsafe fn fold<'a>(
+        fold: &Fold,
+        init: [u8; 16],
+        bytes: &'a [u8],
+    ) -> ([u8; 16], &'a [u8]) {
+        // Reverse bytes so that the first byte is the most significant.
+        let reverse = _mm_set_epi8(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        let load = |bytes: &[u8]| {
+            let block = _mm_loadu_si128(bytes.as_ptr() as *const __m128i);
+            if fold.reflected {
+                block
+            } else {
+                _mm_shuffle_epi8(block, reverse)
+            }
+        };
+        let k16 = _mm_set_epi64x(fold.k16.0 as i64, fold.k16.1 as i64);
+        let k64 = _mm_set_epi64x(fold.k64.0 as i64, fold.k64.1 as i64);
+        let fold_into = |acc: __m128i, k: __m128i, next: __m128i| {
+            let high = _mm_clmulepi64_si128(acc, k, 0x11);
+            let low = _mm_clmulepi64_si128(acc, k, 0x00);
+            _mm_xor_si128(_mm_xor_si128(high, low), next)
+        };
+
+        let mut acc = [
+            _mm_xor_si128(load(&bytes[..16]), load(&init)),
+            load(&bytes[16..32]),
+            load(&bytes[32..48]),
+            load(&bytes[48..64]),
+        ];
+        let mut bytes = &bytes[64..];
+
+        while bytes.len() >= 64 {
+            for (i, acc) in acc.iter_mut().enumerate() {
+                *acc = fold_into(*acc, k64, load(&bytes[16 * i..]));
+            }
+            bytes = &bytes[64..];
+        }
+
+        let mut folded = fold_into(acc[0], k16, acc[1]);
+        folded = fold_into(folded, k16, acc[2]);
+        folded = fold_into(folded, k16, acc[3]);
+        while bytes.len() >= 16 {
+            folded = fold_into(folded, k16, load(bytes));
+            bytes = &bytes[16..];
+        }
+
+        let folded = if fold.reflected {
+            folded
+        } else {
+            _mm_shuffle_epi8(folded, reverse)
+        };
+        let mut out = [0; 16];
+        _mm_storeu_si128(out.as_mut_ptr() as *mut __m128i, folded);
+        (out, bytes)
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod aarch64 {
+    use super::Fold;
+    use core::arch::aarch64::{__crc32b, __crc32cb, __crc32cd, __crc32d, vmull_p64};
+
+    /// Fold `bytes` XORed with `init` into 16 bytes which have the same CRC from a value of zero.
+    /// Returns the 16 bytes and the remaining bytes shorter than 16 bytes.
+    ///
+    /// # Safety
+    /// The CPU must support PMULL, and `bytes` must be at least 64 bytes long.
+    #[target_feature(enable = "neon,aes")]
+    pub(super) unsafe fn fold<'a>(
+        fold: &Fold,
+        init: [u8; 16],
+        bytes: &'a [u8],
+    ) -> ([u8; 16], &'a [u8]) {
+        // The first byte is the most significant unless reflected.
+        let load = |bytes: &[u8]| {
+            let mut block = [0; 16];
+            block.copy_from_slice(&bytes[..16]);
+            if fold.reflected {
+                u128::from_le_bytes(block)
+            } else {
+                u128::from_be_bytes(block)
+            }
+        };
+        let fold_into = |acc: u128, k: (u64, u64), next: u128| {
+            let high = vmull_p64((acc >> 64) as u64, k.0);
+            let low = vmull_p64(acc as u64, k.1);
+            high ^ low ^ next
+        };
+
+        let mut acc = [
+            load(&bytes[..16]) ^ load(&init),
+            load(&bytes[16..32]),
+            load(&bytes[32..48]),
+            load(&bytes[48..64]),
+        ];
+        let mut bytes = &bytes[64..];
+
+        while bytes.len() >= 64 {
+            for (i, acc) in acc.iter_mut().enumerate() {
+                *acc = fold_into(*acc, fold.k64, load(&bytes[16 * i..]));
+            }
+            bytes = &bytes[64..];
+        }
+
+        let mut folded = fold_into(acc[0], fold.k16, acc[1]);
+        folded = fold_into(folded, fold.k16, acc[2]);
+        folded = fold_into(folded, fold.k16, acc[3]);
+        while bytes.len() >= 16 {
+            folded = fold_into(folded, fold.k16, load(bytes));
+            bytes = &bytes[16..];
+        }
+
+        let out = if fold.reflected {
+            folded.to_le_bytes()
+        } else {
+            folded.to_be_bytes()
+        };
+        (out, bytes)
+    }
+
+    /// Reflected CRC-32/ISO-HDLC value of `bytes`.
+    ///
+    /// # Safety
+    /// The CPU must support the CRC extension.
+    #[target_feature(enable = "crc")]
+    pub(super) unsafe fn crc32(value: u32, bytes: &[u8]) -> u32 {
+        let mut chunks = bytes.chunks_exact(8);
+        let mut value = value;
+        for chunk in &mut chunks {
+            let mut word = [0; 8];
+            word.copy_from_slice(chunk);
+            value = __crc32d(value, u64::from_le_bytes(word));
+        }
+        for &byte in chunks.remainder() {
+            value = __crc32b(value, byte);
+        }
+        value
+    }
+
+    /// Reflected CRC-32C value of `bytes`.
+    ///
+    /// # Safety
+    /// The CPU must support the CRC extension.
+    #[target_feature(enable = "crc")]
+    pub(super) unsafe fn crc32c(value: u32, bytes: &[u8]) -> u32 {
+        let mut chunks = bytes.chunks_exact(8);
+        let mut value = value;
+        for chunk in &mut chunks {
+            let mut word = [0; 8];
+            word.copy_from_slice(chunk);
+            value = __crc32cd(value, u64::from_le_bytes(word));
+        }
+        for &byte in chunks.remainder() {
+            value = __crc32cb(value, byte);
+        }
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_same_checksums, test_bytes, Endian, CRC};
+
+    fn assert_hardware<T: CrcWidth>(mut crc: CRC<T>)
+    where
+        Hardware<T>: CreateTable<T>,
+    {
+        let mut hw = CRC::<T, Hardware<T>>::create(crc.algorithm);
+        assert_same_checksums(&mut crc, &mut hw, &test_bytes::<300>());
+    }
+
+    #[test]
+    fn hardware() {
+        // CRC-32/ISCSI
+        let crc = CRC::<u32>::new(Endian::Little, 0x1edc6f41, !0, true, true, !0);
+        #[cfg(target_arch = "x86_64")]
+        assert_eq!(
+            CRC::<u32, Hardware<u32>>::create(crc.algorithm)
+                .table
+                .is_accelerated(),
+            dispatch::features().sse42 || dispatch::features().pclmulqdq
+        );
+        assert_hardware(crc);
+        // CRC-32/ISO-HDLC
+        assert_hardware(CRC::<u32>::new(
+            Endian::Little,
+            0x04c11db7,
+            !0,
+            true,
+            true,
+            !0,
+        ));
+        // CRC-32/BZIP2
+        assert_hardware(CRC::<u32>::new(
+            Endian::Big,
+            0x04c11db7,
+            !0,
+            false,
+            false,
+            !0,
+        ));
+        // CRC-24/OPENPGP
+        assert_hardware(CRC::<u32>::new_with_width(
+            Endian::Big,
+            24,
+            0x864cfb,
+            0xb704ce,
+            false,
+            false,
+            0,
+        ));
+        // CRC-24/BLE
+        assert_hardware(CRC::<u32>::new_with_width(
+            Endian::Little,
+            24,
+            0x00065b,
+            0x555555,
+            true,
+            true,
+            0,
+        ));
+        // CRC-64/XZ
+        assert_hardware(CRC::<u64>::new(
+            Endian::Little,
+            0x42f0e1eba9ea3693,
+            !0,
+            true,
+            true,
+            !0,
+        ));
+        // CRC-64/ECMA-182
+        assert_hardware(CRC::<u64>::new(
+            Endian::Big,
+            0x42f0e1eba9ea3693,
+            0,
+            false,
+            false,
+            0,
+        ));
+        // CRC-40/GSM
+        assert_hardware(CRC::<u64>::new_with_width(
+            Endian::Big,
+            40,
+            0x0004820009,
+            0,
+            false,
+            false,
+            !0 >> 24,
+        ));
+        // CRC-12/UMTS
+        assert_hardware(CRC::<u64>::new_with_width(
+            Endian::Big,
+            12,
+            0x80f,
+            0,
+            false,
+            true,
+            0,
+        ));
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{CrcWidth, Table, CRC};
+use core::hash::{BuildHasher, Hasher};
+
+/// [`Hasher`] of up to 64 bits.
+/// [`Hasher::finish`] is the checksum of the bytes written so far.
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// use core::hash::Hasher;
+/// use mycrc::{catalog::CRC_32_ISCSI, CRC};
+///
+/// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+/// crc32.write(b"123456789");
+/// assert_eq!(crc32.finish(), 0xe3069283);
+/// # }
+/// ```
+impl<T: CrcWidth + Into<u64>, B: Table<T>> Hasher for CRC<T, B> {
+    fn write(&mut self, bytes: &[u8]) {
+        self.calc_bytes(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        self.finalize().into()
+    }
+}
+
+/// [`BuildHasher`] of [`CRC`]s with the initialized value, e.g. for `HashMap`.
+///
+/// Every hasher is a copy of the CRC, so prefer [`CrcRef`](crate::CrcRef) with a `static` table to copying the table.
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// use core::hash::BuildHasher;
+/// use mycrc::{catalog::{self, tables}, BuildCrcHasher, CrcRef};
+///
+/// let crc32 = CrcRef::<u32>::with_table(catalog::CRC_32_ISCSI, &tables::CRC_32_ISCSI);
+/// let build_hasher = BuildCrcHasher::new(crc32);
+/// assert_eq!(build_hasher.hash_one("a"), build_hasher.hash_one("a"));
+/// assert_ne!(build_hasher.hash_one("a"), build_hasher.hash_one("b"));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct BuildCrcHasher<T, B = [T; 256]> {
+    crc: CRC<T, B>,
+}
+
+impl<T, B> BuildCrcHasher<T, B> {
+    /// Build hashers of `crc`.
+    pub const fn new(crc: CRC<T, B>) -> Self {
+        Self { crc }
+    }
+
+    /// CRC of the hashers.
+    pub const fn crc(&self) -> &CRC<T, B> {
+        &self.crc
+    }
+}
+
+impl<T, B> From<CRC<T, B>> for BuildCrcHasher<T, B> {
+    fn from(crc: CRC<T, B>) -> Self {
+        Self::new(crc)
+    }
+}
+
+impl<T: CrcWidth + Into<u64>, B: Table<T>> BuildHasher for BuildCrcHasher<T, B> {
+    type Hasher = CRC<T, B>;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut crc = self.crc;
+        crc.initialize();
+        crc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Endian;
+
+    #[test]
+    fn hasher() {
+        // CRC-64/XZ
+        let mut crc = CRC::<u64>::new(Endian::Little, 0x42f0e1eba9ea3693, !0, true, true, !0);
+        crc.calc_bytes(b"dirty");
+        let build_hasher = BuildCrcHasher::from(crc);
+
+        let mut hasher = build_hasher.build_hasher();
+        hasher.write(b"1234");
+        hasher.write_u8(b'5');
+        hasher.write(b"6789");
+        assert_eq!(hasher.finish(), 0x995dc9bbdf1939fa);
+
+        let mut hasher = build_hasher.build_hasher();
+        hasher.write_u32(u32::from_ne_bytes(*b"1234"));
+        assert_eq!(hasher.finish(), crc.checksum(b"1234"));
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{Algorithm, HwRegister};
+use core::fmt;
+
+/// Next-state logic of a parallel CRC that takes `data_bits` bits per clock, as XOR equations.
+///
+/// Bit `i` of the register is the coefficient of `x^i`, as [`HwRegister::default`].
+/// The data is bytes in message order, bit `k` being bit `k % 8` of byte `k / 8`,
+/// so bits are shifted in as of `refin` without reflecting the data.
+/// The register starts from [`CRC::to_hw_register`](crate::CRC::to_hw_register) of the initialized value.
+///
+/// `Display` writes an equation per bit of the next register, e.g. `next[0] = c[0] ^ d[0];`,
+/// where `c` is the register and `d` is the data.
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// use mycrc::catalog::CRC_8_SMBUS;
+///
+/// let equations = CRC_8_SMBUS.xor_equations(8);
+/// assert_eq!(equations.next(0x00, 0x31), 0x97);
+/// assert!(equations.to_string().starts_with("next[0] = c[0] ^ c[6] ^ c[7] ^ d[0] ^ d[6] ^ d[7];\n"));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct XorEquations {
+    /// Bits of the register.
+    pub width: u8,
+    /// Bits of data per clock.
+    pub data_bits: u8,
+    /// Bits of the register each bit of the next register depends on.
+    pub register: [u128; 128],
+    /// Bits of data each bit of the next register depends on.
+    pub data: [u64; 128],
+}
+
+impl XorEquations {
+    /// Next register after `data`, by evaluating the equations.
+    pub fn next(&self, register: u128, data: u64) -> u128 {
+        (0..self.width as usize).fold(0, |next, i| {
+            let ones =
+                (register & self.register[i]).count_ones() + (data & self.data[i]).count_ones();
+            next | ((ones % 2) as u128) << i
+        })
+    }
+}
+
+impl fmt::Display for XorEquations {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for i in 0..self.width as usize {
+            write!(f, "next[{i}] =")?;
+            let register = (0..self.width).filter(|&j| self.register[i] >> j & 1 == 1);
+            let data = (0..self.data_bits).filter(|&k| self.data[i] >> k & 1 == 1);
+            let mut separator = " ";
+            for j in register {
+                write!(f, "{separator}c[{j}]")?;
+                separator = " ^ ";
+            }
+            for k in data {
+                write!(f, "{separator}d[{k}]")?;
+                separator = " ^ ";
+            }
+            if separator == " " {
+                write!(f, " 0")?;
+            }
+            writeln!(f, ";")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Copy + Into<u128>> Algorithm<T> {
+    /// XOR equations of a parallel CRC that takes `data_bits` bits per clock, e.g. 8, 16 or 32, see [`XorEquations`].
+    ///
+    /// # Panics
+    /// Panics if `data_bits` is not a multiple of 8 from 8 to 64.
+    pub fn xor_equations(&self, data_bits: u8) -> XorEquations {
+        assert!(
+            data_bits.is_multiple_of(8) && (8..=64).contains(&data_bits),
+            "data_bits is not a multiple of 8 from 8 to 64"
+        );
+        let (width, poly, refin) = (self.width, self.poly.into(), self.refin);
+        let algorithm = Algorithm::<u128> {
+            endian: self.endian,
+            width,
+            poly,
+            init: self.init.into(),
+            refin,
+            refout: self.refout,
+            xorout: self.xorout.into(),
+            check: self.check.into(),
+            residue: self.residue.into(),
+        };
+        let register = HwRegister::default();
+        let len = data_bits as usize / 8;
+        // Next register, which is linear in the register and the data.
+        let next = |value: u128, data: u64| {
+            let value = algorithm.from_hw_register(value, register);
+            let value = Algorithm::<u128>::calc_bytes_bitwise(
+                width,
+                poly,
+                refin,
+                value,
+                &data.to_le_bytes()[..len],
+            );
+            algorithm.to_hw_register(value, register)
+        };
+
+        let mut equations = XorEquations {
+            width,
+            data_bits,
+            register: [0; 128],
+            data: [0; 128],
+        };
+        for j in 0..width as usize {
+            let column = next(1 << j, 0);
+            for (i, row) in equations
+                .register
+                .iter_mut()
+                .enumerate()
+                .take(width as usize)
+            {
+                *row |= (column >> i & 1) << j;
+            }
+        }
+        for k in 0..data_bits as usize {
+            let column = next(0, 1 << k);
+            for (i, row) in equations.data.iter_mut().enumerate().take(width as usize) {
+                *row |= ((column >> i & 1) as u64) << k;
+            }
+        }
+        equations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_bytes, CrcWidth, Endian, HwRegister, CRC};
+
+    #[test]
+    fn xor_equations() {
+        fn assert_equations<T: CrcWidth>(mut crc: CRC<T>, data_bits: u8) {
+            let equations = crc.algorithm.xor_equations(data_bits);
+            let register = HwRegister::default();
+            let bytes: [u8; 64] = test_bytes();
+            let mut value = crc.initialize().to_hw_register(register).into();
+            for chunk in bytes.chunks(data_bits as usize / 8) {
+                let mut data = [0; 8];
+                data[..chunk.len()].copy_from_slice(chunk);
+                value = equations.next(value, u64::from_le_bytes(data));
+            }
+            crc.calc_bytes(&bytes);
+            assert_eq!(value, crc.to_hw_register(register).into());
+        }
+
+        // CRC-12/UMTS
+        let umts = CRC::<u16>::new_with_width(Endian::Big, 12, 0x80f, 0, false, true, 0);
+        // CRC-32/ISO-HDLC
+        let hdlc = CRC::<u32>::new(Endian::Little, 0x04c11db7, !0, true, true, !0);
+        // CRC-82/DARC
+        let darc = CRC::<u128>::new_with_width(
+            Endian::Little,
+            82,
+            0x0308c0111011401440411,
+            0,
+            true,
+            true,
+            0,
+        );
+        for data_bits in [8, 16, 32, 64] {
+            assert_equations(umts, data_bits);
+            assert_equations(hdlc, data_bits);
+            assert_equations(darc, data_bits);
+        }
+    }
+}
@@ -0,0 +1,600 @@
+use crate::{CrcWidth, Table, CRC};
+#[cfg(feature = "std")]
+use std::{
+    fs::File,
+    io::{self, ErrorKind, Read, Write},
+    path::Path,
+    vec,
+};
+#[cfg(any(feature = "tokio", feature = "futures-io"))]
+use std::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+/// Large enough for the wide tables and few system calls.
+#[cfg(feature = "std")]
+const BUF_LEN: usize = 128 * 1024;
+
+/// Reader that calculates the bytes read through it.
+///
+/// With the `std` feature, it is a [`std::io::Read`].
+/// With the `tokio` feature, it is also a [`tokio::io::AsyncRead`].
+/// With the `futures-io` feature, it is also a [`futures_io::AsyncRead`].
+/// With the `embedded-io` feature, it is an [`embedded_io::Read`], also without `std`.
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// # #[cfg(feature = "std")] {
+/// use mycrc::{catalog::CRC_32_ISCSI, CrcReader, CRC};
+/// use std::io::Read;
+///
+/// let crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+/// let mut reader = CrcReader::new(&b"123456789"[..], crc32);
+/// let mut buf = Vec::new();
+/// reader.read_to_end(&mut buf).unwrap();
+/// assert_eq!(reader.crc().finalize(), 0xe3069283);
+/// # }
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CrcReader<R, T, B = [T; 256]> {
+    inner: R,
+    crc: CRC<T, B>,
+}
+
+impl<R, T, B> CrcReader<R, T, B> {
+    /// Wrap `inner`. Bytes are calculated from the current value of `crc`.
+    pub fn new(inner: R, crc: CRC<T, B>) -> Self {
+        Self { inner, crc }
+    }
+
+    /// The CRC of the bytes read so far.
+    pub fn crc(&self) -> &CRC<T, B> {
+        &self.crc
+    }
+
+    /// The CRC of the bytes read so far, e.g. to initialize it.
+    pub fn crc_mut(&mut self) -> &mut CRC<T, B> {
+        &mut self.crc
+    }
+
+    /// The inner reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// The inner reader.
+    /// Bytes read from it directly are not calculated.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// The inner reader and the CRC.
+    pub fn into_inner(self) -> (R, CRC<T, B>) {
+        (self.inner, self.crc)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read, T: CrcWidth, B: Table<T>> Read for CrcReader<R, T, B> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = self.inner.read(buf)?;
+        self.crc.calc_bytes(&buf[..len]);
+        Ok(len)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<R, T, B> tokio::io::AsyncRead for CrcReader<R, T, B>
+where
+    R: tokio::io::AsyncRead + Unpin,
+    T: CrcWidth + Unpin,
+    B: Table<T> + Unpin,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        let filled = buf.filled().len();
+        ready!(Pin::new(&mut this.inner).poll_read(cx, buf))?;
+        this.crc.calc_bytes(&buf.filled()[filled..]);
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(feature = "futures-io")]
+impl<R, T, B> futures_io::AsyncRead for CrcReader<R, T, B>
+where
+    R: futures_io::AsyncRead + Unpin,
+    T: CrcWidth + Unpin,
+    B: Table<T> + Unpin,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let len = ready!(Pin::new(&mut this.inner).poll_read(cx, buf))?;
+        this.crc.calc_bytes(&buf[..len]);
+        Poll::Ready(Ok(len))
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl<R: embedded_io::ErrorType, T, B> embedded_io::ErrorType for CrcReader<R, T, B> {
+    type Error = R::Error;
+}
+
+#[cfg(feature = "embedded-io")]
+impl<R, T, B> embedded_io::Read for CrcReader<R, T, B>
+where
+    R: embedded_io::Read,
+    T: CrcWidth,
+    B: Table<T>,
+{
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let len = self.inner.read(buf)?;
+        self.crc.calc_bytes(&buf[..len]);
+        Ok(len)
+    }
+}
+
+/// Writer that calculates the bytes written through it.
+///
+/// Only the bytes accepted by the inner writer are calculated.
+///
+/// With the `std` feature, it is a [`std::io::Write`].
+/// With the `tokio` feature, it is also a [`tokio::io::AsyncWrite`].
+/// With the `futures-io` feature, it is also a [`futures_io::AsyncWrite`].
+/// With the `embedded-io` feature, it is an [`embedded_io::Write`], also without `std`.
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// # #[cfg(feature = "std")] {
+/// use mycrc::{catalog::CRC_32_ISCSI, CrcWriter, CRC};
+/// use std::io::Write;
+///
+/// let crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+/// let mut writer = CrcWriter::new(Vec::new(), crc32);
+/// writer.write_all(b"123456789").unwrap();
+/// assert_eq!(writer.crc().finalize(), 0xe3069283);
+/// # }
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CrcWriter<W, T, B = [T; 256]> {
+    inner: W,
+    crc: CRC<T, B>,
+}
+
+impl<W, T, B> CrcWriter<W, T, B> {
+    /// Wrap `inner`. Bytes are calculated from the current value of `crc`.
+    pub fn new(inner: W, crc: CRC<T, B>) -> Self {
+        Self { inner, crc }
+    }
+
+    /// The CRC of the bytes written so far.
+    pub fn crc(&self) -> &CRC<T, B> {
+        &self.crc
+    }
+
+    /// The CRC of the bytes written so far, e.g. to initialize it.
+    pub fn crc_mut(&mut self) -> &mut CRC<T, B> {
+        &mut self.crc
+    }
+
+    /// The inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// The inner writer.
+    /// Bytes written to it directly are not calculated.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// The inner writer and the CRC.
+    pub fn into_inner(self) -> (W, CRC<T, B>) {
+        (self.inner, self.crc)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: Write, T: CrcWidth, B: Table<T>> Write for CrcWriter<W, T, B> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = self.inner.write(buf)?;
+        self.crc.calc_bytes(&buf[..len]);
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<W, T, B> tokio::io::AsyncWrite for CrcWriter<W, T, B>
+where
+    W: tokio::io::AsyncWrite + Unpin,
+    T: CrcWidth + Unpin,
+    B: Table<T> + Unpin,
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let len = ready!(Pin::new(&mut this.inner).poll_write(cx, buf))?;
+        this.crc.calc_bytes(&buf[..len]);
+        Poll::Ready(Ok(len))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_shutdown(cx)
+    }
+}
+
+#[cfg(feature = "futures-io")]
+impl<W, T, B> futures_io::AsyncWrite for CrcWriter<W, T, B>
+where
+    W: futures_io::AsyncWrite + Unpin,
+    T: CrcWidth + Unpin,
+    B: Table<T> + Unpin,
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let len = ready!(Pin::new(&mut this.inner).poll_write(cx, buf))?;
+        this.crc.calc_bytes(&buf[..len]);
+        Poll::Ready(Ok(len))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_close(cx)
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl<W: embedded_io::ErrorType, T, B> embedded_io::ErrorType for CrcWriter<W, T, B> {
+    type Error = W::Error;
+}
+
+#[cfg(feature = "embedded-io")]
+impl<W, T, B> embedded_io::Write for CrcWriter<W, T, B>
+where
+    W: embedded_io::Write,
+    T: CrcWidth,
+    B: Table<T>,
+{
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        let len = self.inner.write(buf)?;
+        self.crc.calc_bytes(&buf[..len]);
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: CrcWidth, B: Table<T>> CRC<T, B> {
+    /// Calculate bytes of `reader` until the end, in large chunks.
+    ///
+    /// Requires the `std` feature.
+    pub fn calc_reader<R: Read>(&mut self, mut reader: R) -> io::Result<&mut Self> {
+        let mut buf = vec![0; BUF_LEN];
+        loop {
+            match reader.read(&mut buf) {
+                Ok(0) => return Ok(self),
+                Ok(len) => {
+                    self.calc_bytes(&buf[..len]);
+                }
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Checksum of the rest of `file`.
+    ///
+    /// Requires the `std` feature.
+    pub fn checksum_file(&mut self, file: &File) -> io::Result<T> {
+        Ok(self.initialize().calc_reader(file)?.finalize())
+    }
+
+    /// Checksum of the file at `path`.
+    ///
+    /// Requires the `std` feature.
+    ///
+    /// ```no_run
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_32_ISCSI, CRC};
+    ///
+    /// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+    /// let checksum = crc32.checksum_path("Cargo.toml")?;
+    /// # }
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn checksum_path<P: AsRef<Path>>(&mut self, path: P) -> io::Result<T> {
+        self.checksum_file(&File::open(path)?)
+    }
+
+    /// Checksum of the file at `path`, which is memory-mapped instead of read.
+    /// Faster than [`CRC::checksum_path`] for large files, especially with a wide table.
+    ///
+    /// The file must not be modified or truncated by other processes while it is calculated.
+    /// Otherwise, the checksum is unspecified or the process may be killed, e.g. by `SIGBUS`.
+    ///
+    /// Requires the `mmap` feature.
+    ///
+    /// ```no_run
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::{catalog::CRC_32_ISCSI, Slice16, CRC};
+    ///
+    /// let mut crc32 = CRC::<u32, Slice16<u32>>::create(CRC_32_ISCSI);
+    /// let checksum = crc32.checksum_mmap("large.img")?;
+    /// # }
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[cfg(feature = "mmap")]
+    pub fn checksum_mmap<P: AsRef<Path>>(&mut self, path: P) -> io::Result<T> {
+        let file = File::open(path)?;
+        // SAFETY: the file is only read, and the caller must not modify it meanwhile.
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+        #[cfg(unix)]
+        let _ = mmap.advise(memmap2::Advice::Sequential);
+        Ok(self.checksum(&mmap))
+    }
+}
+
+/// Copy the rest of `reader` to `writer` and calculate the bytes with `crc`.
+/// Returns the number of bytes copied, like [`io::copy`].
+///
+/// Bytes are calculated from the current value of `crc`.
+/// On error, the bytes of the failed read or write are not calculated.
+///
+/// Requires the `std` feature.
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// use mycrc::{catalog::CRC_32_ISCSI, copy_with_crc, CRC};
+///
+/// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+/// let mut writer = Vec::new();
+/// let len = copy_with_crc(&b"123456789"[..], &mut writer, &mut crc32)?;
+/// assert_eq!(len, 9);
+/// assert_eq!(crc32.finalize(), 0xe3069283);
+/// # }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[cfg(feature = "std")]
+pub fn copy_with_crc<R, W, T, B>(
+    mut reader: R,
+    mut writer: W,
+    crc: &mut CRC<T, B>,
+) -> io::Result<u64>
+where
+    R: Read,
+    W: Write,
+    T: CrcWidth,
+    B: Table<T>,
+{
+    let mut buf = vec![0; BUF_LEN];
+    let mut copied = 0;
+    loop {
+        let len = match reader.read(&mut buf) {
+            Ok(0) => return Ok(copied),
+            Ok(len) => len,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        writer.write_all(&buf[..len])?;
+        crc.calc_bytes(&buf[..len]);
+        copied += len as u64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Endian, CHECK_BYTES};
+    #[cfg(feature = "std")]
+    use std::vec::Vec;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reader() {
+        // CRC-16/ARC
+        let crc = CRC::<u16>::new(Endian::Little, 0x8005, 0, true, true, 0);
+        // Read in small parts.
+        let mut reader = CrcReader::new(CHECK_BYTES, crc);
+        let mut buf = [0; 4];
+        while reader.read(&mut buf).unwrap() != 0 {}
+        assert_eq!(reader.crc().finalize(), 0xbb3d);
+        assert_eq!(reader.crc().len(), CHECK_BYTES.len());
+
+        let (rest, crc) = reader.into_inner();
+        assert!(rest.is_empty());
+        assert_eq!(crc.finalize(), 0xbb3d);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn writer() {
+        // CRC-16/ARC
+        let crc = CRC::<u16>::new(Endian::Little, 0x8005, 0, true, true, 0);
+        let mut writer = CrcWriter::new(Vec::new(), crc);
+        for chunk in CHECK_BYTES.chunks(4) {
+            writer.write_all(chunk).unwrap();
+        }
+        writer.flush().unwrap();
+        assert_eq!(writer.crc().finalize(), 0xbb3d);
+
+        let (inner, crc) = writer.into_inner();
+        assert_eq!(inner, CHECK_BYTES);
+        assert_eq!(crc.len(), CHECK_BYTES.len());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn checksum_path() {
+        use std::{fs, process};
+
+        // CRC-16/ARC
+        let mut crc = CRC::<u16>::new(Endian::Little, 0x8005, 0, true, true, 0);
+        // Larger than the buffer.
+        let bytes: Vec<u8> = (0..300_007u32).map(|i| (i * 7 + i / 251) as u8).collect();
+        let path = std::env::temp_dir().join(std::format!("mycrc-{}", process::id()));
+        fs::write(&path, &bytes).unwrap();
+        let checksum = crc.checksum_path(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(checksum.unwrap(), crc.checksum(&bytes));
+        assert_eq!(crc.len(), bytes.len());
+
+        #[cfg(feature = "mmap")]
+        {
+            fs::write(&path, &bytes).unwrap();
+            let checksum = crc.checksum_mmap(&path);
+            fs::write(&path, []).unwrap();
+            let empty = crc.checksum_mmap(&path);
+            fs::remove_file(&path).unwrap();
+            assert_eq!(checksum.unwrap(), crc.checksum(&bytes));
+            assert_eq!(empty.unwrap(), crc.checksum(&[]));
+            assert!(crc.checksum_mmap(&path).is_err());
+        }
+
+        assert!(crc.checksum_path(&path).is_err());
+        assert_eq!(
+            crc.initialize()
+                .calc_reader(CHECK_BYTES)
+                .unwrap()
+                .finalize(),
+            0xbb3d
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn copy_with_crc() {
+        // CRC-16/ARC
+        let mut crc = CRC::<u16>::new(Endian::Little, 0x8005, 0, true, true, 0);
+        let bytes: Vec<u8> = (0..300_007u32).map(|i| (i * 7 + i / 251) as u8).collect();
+        let mut writer = Vec::new();
+        let len = super::copy_with_crc(&bytes[..], &mut writer, &mut crc).unwrap();
+        assert_eq!(len, bytes.len() as u64);
+        assert_eq!(writer, bytes);
+        assert_eq!(crc.finalize(), crc.checksum(&bytes));
+
+        // Full writer.
+        let mut buf = [0; 4];
+        crc.initialize();
+        assert!(super::copy_with_crc(CHECK_BYTES, &mut buf[..], &mut crc).is_err());
+        assert!(crc.is_empty());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn tokio() {
+        use std::task::Waker;
+        use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+        let mut cx = Context::from_waker(Waker::noop());
+        // CRC-16/ARC
+        let crc = CRC::<u16>::new(Endian::Little, 0x8005, 0, true, true, 0);
+
+        let mut reader = CrcReader::new(CHECK_BYTES, crc);
+        let mut buf = [0; 4];
+        loop {
+            let mut buf = ReadBuf::new(&mut buf);
+            let poll = Pin::new(&mut reader).poll_read(&mut cx, &mut buf);
+            assert!(matches!(poll, Poll::Ready(Ok(()))));
+            if buf.filled().is_empty() {
+                break;
+            }
+        }
+        assert_eq!(reader.crc().finalize(), 0xbb3d);
+
+        let mut writer = CrcWriter::new(Vec::new(), crc);
+        for chunk in CHECK_BYTES.chunks(4) {
+            let poll = Pin::new(&mut writer).poll_write(&mut cx, chunk);
+            assert!(matches!(poll, Poll::Ready(Ok(len)) if len == chunk.len()));
+        }
+        assert!(Pin::new(&mut writer).poll_shutdown(&mut cx).is_ready());
+        assert_eq!(writer.crc().finalize(), 0xbb3d);
+        assert_eq!(writer.get_ref(), CHECK_BYTES);
+    }
+
+    #[cfg(feature = "futures-io")]
+    #[test]
+    fn futures_io() {
+        use futures_io::{AsyncRead, AsyncWrite};
+        use std::task::Waker;
+
+        let mut cx = Context::from_waker(Waker::noop());
+        // CRC-16/ARC
+        let crc = CRC::<u16>::new(Endian::Little, 0x8005, 0, true, true, 0);
+
+        let mut reader = CrcReader::new(CHECK_BYTES, crc);
+        let mut buf = [0; 4];
+        while let Poll::Ready(Ok(len)) = Pin::new(&mut reader).poll_read(&mut cx, &mut buf) {
+            if len == 0 {
+                break;
+            }
+        }
+        assert_eq!(reader.crc().finalize(), 0xbb3d);
+
+        let mut writer = CrcWriter::new(Vec::new(), crc);
+        for chunk in CHECK_BYTES.chunks(4) {
+            let poll = Pin::new(&mut writer).poll_write(&mut cx, chunk);
+            assert!(matches!(poll, Poll::Ready(Ok(len)) if len == chunk.len()));
+        }
+        assert!(Pin::new(&mut writer).poll_close(&mut cx).is_ready());
+        assert_eq!(writer.crc().finalize(), 0xbb3d);
+        assert_eq!(writer.get_ref(), CHECK_BYTES);
+    }
+
+    #[cfg(feature = "embedded-io")]
+    #[test]
+    fn embedded_io() {
+        // Qualified, as `std::io` traits are in scope with the `std` feature.
+        // CRC-16/ARC
+        let crc = CRC::<u16>::new(Endian::Little, 0x8005, 0, true, true, 0);
+
+        let mut reader = CrcReader::new(CHECK_BYTES, crc);
+        let mut buf = [0; 4];
+        while embedded_io::Read::read(&mut reader, &mut buf).unwrap() != 0 {}
+        assert_eq!(reader.crc().finalize(), 0xbb3d);
+
+        let mut buf = [0; 9];
+        let mut writer = CrcWriter::new(&mut buf[..], crc);
+        for chunk in CHECK_BYTES.chunks(4) {
+            embedded_io::Write::write_all(&mut writer, chunk).unwrap();
+        }
+        embedded_io::Write::flush(&mut writer).unwrap();
+        assert_eq!(writer.crc().finalize(), 0xbb3d);
+        assert_eq!(buf, CHECK_BYTES);
+    }
+}
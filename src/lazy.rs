@@ -0,0 +1,94 @@
+use crate::{Algorithm, CrcRef, CrcWidth, CreateTable, CRC};
+use std::sync::OnceLock;
+
+/// CRC whose table is created on first use at runtime instead of in const context.
+///
+/// Declaring many `u128` algorithms as `const` [`CRC`] evaluates every table at compile time.
+/// `LazyCrc::new` only stores the algorithm, so it is cheap to put in a `static`.
+///
+/// Requires the `std` feature.
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// use mycrc::{catalog::CRC_82_DARC, LazyCrc};
+///
+/// static CRC82: LazyCrc<u128> = LazyCrc::new(CRC_82_DARC);
+///
+/// assert_eq!(CRC82.checksum(b"123456789"), 0x09ea83f625023801fd612);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct LazyCrc<T, B = [T; 256]> {
+    algorithm: Algorithm<T>,
+    table: OnceLock<B>,
+}
+
+impl<T, B> LazyCrc<T, B> {
+    /// Create `LazyCrc` without creating the table.
+    pub const fn new(algorithm: Algorithm<T>) -> Self {
+        Self {
+            algorithm,
+            table: OnceLock::new(),
+        }
+    }
+
+    /// Algorithm of the CRC.
+    pub const fn algorithm(&self) -> &Algorithm<T> {
+        &self.algorithm
+    }
+}
+
+impl<T: CrcWidth, B: CreateTable<T>> LazyCrc<T, B> {
+    /// The table, created on the first call.
+    pub fn table(&self) -> &B {
+        self.table.get_or_init(|| B::create(&self.algorithm))
+    }
+
+    /// [`CRC`] with a copy of the table and the initialized value.
+    pub fn crc(&self) -> CRC<T, B> {
+        CRC {
+            algorithm: self.algorithm,
+            value: T::initialize(&self.algorithm),
+            table: *self.table(),
+            len: 0,
+        }
+    }
+
+    /// [`CrcRef`] that borrows the table, with the initialized value.
+    pub fn crc_ref(&self) -> CrcRef<'_, T, B> {
+        CRC {
+            algorithm: self.algorithm,
+            value: T::initialize(&self.algorithm),
+            table: self.table(),
+            len: 0,
+        }
+    }
+
+    /// Checksum function.
+    pub fn checksum(&self, bytes: &[u8]) -> T {
+        let value = T::initialize(&self.algorithm);
+        let value = self.table().calc_bytes(&self.algorithm, value, bytes);
+        T::finalize(&self.algorithm, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Endian, Slice8};
+
+    #[test]
+    fn lazy() {
+        // CRC-32/ISO-HDLC
+        let crc = CRC::<u32>::new(Endian::Little, 0x04c11db7, !0, true, true, !0);
+        let lazy = LazyCrc::<u32>::new(crc.algorithm);
+        assert!(lazy.table.get().is_none());
+        assert_eq!(lazy.checksum(b"123456789"), 0xcbf43926);
+        assert_eq!(lazy.crc(), crc);
+        assert_eq!(lazy.crc_ref(), crc.as_crc_ref());
+
+        let lazy = LazyCrc::<u32, Slice8<u32>>::new(crc.algorithm);
+        assert_eq!(lazy.crc().checksum(b"123456789"), 0xcbf43926);
+    }
+}
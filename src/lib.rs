@@ -1,10 +1,164 @@
 #![doc = include_str!("../README.md")]
 #![no_std]
+#![cfg_attr(feature = "portable-simd", feature(portable_simd))]
 
-pub use self::algorithm::{Algorithm, Endian};
-pub use self::crc::CRC;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub use self::algorithm::{
+    Algorithm, AnyAlgorithm, BurstDetection, ChecksumLayout, Endian, HwRegister, Notation,
+    CHECK_BYTES,
+};
+#[cfg(feature = "std")]
+pub use self::blocks::{Block, BlockCrcs};
+pub use self::bytes::ChecksumBytes;
+pub use self::crc::{CrcRef, CRC};
+pub use self::crc_n::CrcN;
+#[cfg(feature = "std")]
+pub use self::error::MergeError;
+pub use self::error::{
+    CrcError, MismatchError, ParamError, SelfTestError, StateError, TrailerError,
+};
+pub use self::hardware::Hardware;
+pub use self::hasher::BuildCrcHasher;
+pub use self::hdl::XorEquations;
+#[cfg(feature = "std")]
+pub use self::io::copy_with_crc;
+#[cfg(any(feature = "std", feature = "embedded-io"))]
+pub use self::io::{CrcReader, CrcWriter};
+#[cfg(feature = "std")]
+pub use self::lazy::LazyCrc;
+pub use self::matrix::CrcMatrix;
+pub use self::params::{Digest, Params};
+pub use self::rolling::RollingCrc;
+#[cfg(feature = "portable-simd")]
+pub use self::simd::SimdSlice16;
+pub use self::state::CrcState;
+pub use self::strategy::{AnyTable, Strategy};
+pub use self::table::{
+    Bitwise, CreateTable, Guarded, Interleaved, Nibble, SkipZeros, Slice16, Slice8, SliceBy, Table,
+};
+pub use self::trace::{LfsrStep, LfsrTrace};
+#[cfg(feature = "std")]
+pub use self::vectors::TestVector;
+pub use self::width::CrcWidth;
 
 /// CRC algorithm
 mod algorithm;
+/// AUTOSAR CRC routines
+#[cfg(feature = "catalog")]
+pub mod autosar;
+/// Construction shared by the C, JavaScript and Python bindings
+#[cfg(any(feature = "ffi", feature = "wasm", feature = "python"))]
+mod bindings;
+/// Checksums of blocks
+#[cfg(feature = "std")]
+mod blocks;
+/// Checksum bytes
+mod bytes;
+/// Controller Area Network (CAN)
+pub mod can;
+/// Algorithms of the [Catalogue of parametrised CRC algorithms](https://reveng.sourceforge.io/crc-catalogue/all.htm)
+#[cfg(feature = "catalog")]
+pub mod catalog;
+/// Tables rendered as C and Rust source code
+#[cfg(feature = "std")]
+pub mod codegen;
 /// Cyclic redundancy check
 mod crc;
+/// Const-generic width CRC
+mod crc_n;
+/// Trailers of gzip and zlib streams, to verify the output of raw inflate
+pub mod deflate;
+/// CPU feature detection for [`Hardware`]
+pub mod dispatch;
+/// Errors
+mod error;
+/// C functions around an opaque [`MycrcCrc`](ffi::MycrcCrc) handle, for cbindgen.
+///
+/// Algorithms of up to 64 bits are supported, and values are `uint64_t`.
+/// Build a `staticlib` or `cdylib` with the `ffi` feature to link it, e.g.
+/// `cargo rustc --release --features ffi --crate-type staticlib`.
+#[cfg(feature = "ffi")]
+pub mod ffi;
+/// Polynomial arithmetic over GF(2)
+pub mod gf2;
+/// Hamming distance of polynomials
+#[cfg(feature = "std")]
+mod hamming;
+/// CRC instructions of the CPU
+mod hardware;
+/// Hasher
+mod hasher;
+/// XOR equations for hardware description languages
+mod hdl;
+/// I/O adapters
+#[cfg(any(feature = "std", feature = "embedded-io"))]
+mod io;
+/// Table created on first use
+#[cfg(feature = "std")]
+mod lazy;
+/// Transition matrix
+mod matrix;
+/// Parameters shared by digests
+mod params;
+/// CRC of PNG chunks
+pub mod png;
+/// Python bindings of pyo3
+#[cfg(feature = "python")]
+pub mod python;
+/// Recovery of the parameters of unknown CRC algorithms from samples
+pub mod reveng;
+/// Rolling-window CRC
+mod rolling;
+/// RustCrypto `digest` traits
+#[cfg(feature = "digest")]
+mod rust_crypto;
+/// Serde support
+#[cfg(feature = "serde")]
+mod serialize;
+/// Portable SIMD
+#[cfg(feature = "portable-simd")]
+mod simd;
+/// Snapshot of the value
+mod state;
+/// Strategy chosen at runtime
+mod strategy;
+/// Lookup tables
+mod table;
+/// Bit-level trace of the shift register
+mod trace;
+/// Test vectors
+#[cfg(feature = "std")]
+mod vectors;
+/// JavaScript bindings of wasm-bindgen
+#[cfg(feature = "wasm")]
+pub mod wasm;
+/// CRC width
+mod width;
+
+/// `N` bytes for tests, which are neither zeros nor periodic.
+#[cfg(test)]
+pub(crate) fn test_bytes<const N: usize>() -> [u8; N] {
+    core::array::from_fn(|i| (i as u8).wrapping_mul(0x9d) ^ 0x5a)
+}
+
+/// Assert that `other` gives the same checksums as `crc` for every prefix of `bytes`,
+/// and when resumed from a value which is not the initial value.
+#[cfg(test)]
+pub(crate) fn assert_same_checksums<T: CrcWidth, B: Table<T>>(
+    crc: &mut CRC<T>,
+    other: &mut CRC<T, B>,
+    bytes: &[u8],
+) {
+    for len in 0..bytes.len() {
+        assert_eq!(other.checksum(&bytes[..len]), crc.checksum(&bytes[..len]));
+    }
+    let (head, tail) = bytes.split_at(bytes.len().min(7));
+    crc.checksum(head);
+    other.checksum(head);
+    assert_eq!(
+        other.calc_bytes(tail).finalize(),
+        crc.calc_bytes(tail).finalize()
+    );
+}
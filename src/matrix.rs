@@ -0,0 +1,179 @@
+use crate::{Algorithm, CrcWidth};
+use core::{mem, ops::Mul};
+
+/// Linear operator on the value of [`CRC`](crate::CRC) that advances it by `len` zero bytes.
+///
+/// Column `i` is the operator applied to the value with only bit `i` set,
+/// so [`CrcMatrix::apply`] is a XOR of columns and needs no polynomial arithmetic.
+/// Create it once, e.g. to jump over a fixed-size header, and reuse it with [`CRC::calc_matrix`](crate::CRC::calc_matrix).
+/// Matrices of the same algorithm are multiplied with `*`.
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// use mycrc::{catalog::CRC_32_ISCSI, CrcMatrix, CRC};
+///
+/// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+/// let header = CrcMatrix::zeros(&crc32.algorithm, 64);
+/// let page = CrcMatrix::zeros(&crc32.algorithm, 4096);
+///
+/// let jump = crc32.initialize().calc_matrix(&(page * header)).finalize();
+/// assert_eq!(crc32.initialize().calc_zeros(64 + 4096).finalize(), jump);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CrcMatrix<T> {
+    columns: [T; 128],
+    len: usize,
+}
+
+impl<T> CrcMatrix<T>
+where
+    T: CrcWidth,
+{
+    /// Bits of `T`.
+    const BITS: usize = 8 * mem::size_of::<T>();
+
+    /// Value with only bit `i` set.
+    fn bit(i: usize) -> T {
+        T::try_from(1 << i).ok().expect("bit is out of T")
+    }
+
+    /// Operator that advances by no bytes.
+    pub fn identity() -> Self {
+        let mut columns = [T::default(); 128];
+        for (i, column) in columns.iter_mut().enumerate().take(Self::BITS) {
+            *column = Self::bit(i);
+        }
+        Self { columns, len: 0 }
+    }
+
+    /// Operator that advances by `len` zero bytes of `algorithm`.
+    pub fn zeros(algorithm: &Algorithm<T>, len: usize) -> Self {
+        let mut columns = [T::default(); 128];
+        for (i, column) in columns.iter_mut().enumerate().take(Self::BITS) {
+            *column = T::shift_zeros(algorithm, Self::bit(i), len);
+        }
+        Self { columns, len }
+    }
+
+    /// Number of bytes to advance by.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the operator is of no bytes.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Apply to `value`.
+    pub fn apply(&self, value: T) -> T {
+        let bits = value.into();
+        self.columns[..Self::BITS]
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| bits >> i & 1 == 1)
+            .fold(T::default(), |acc, (_, &column)| acc ^ column)
+    }
+
+    /// Operator that advances by `n` times the bytes, in O(log `n`) products.
+    pub fn pow(&self, mut n: usize) -> Self {
+        let mut result = Self::identity();
+        let mut square = *self;
+        while n != 0 {
+            if n & 1 == 1 {
+                result = result * square;
+            }
+            square = square * square;
+            n >>= 1;
+        }
+        result
+    }
+}
+
+/// Operator that advances by the bytes of `rhs` and then by the bytes of `self`.
+impl<T> Mul for CrcMatrix<T>
+where
+    T: CrcWidth,
+{
+    type Output = Self;
+
+    // Bytes to advance by are added by the product.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self {
+        let mut columns = [T::default(); 128];
+        for (column, &rhs_column) in columns.iter_mut().zip(&rhs.columns[..Self::BITS]) {
+            *column = self.apply(rhs_column);
+        }
+        Self {
+            columns,
+            len: self.len + rhs.len,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Endian, CRC};
+
+    #[test]
+    fn matrix() {
+        fn assert_matrix<T>(mut crc: CRC<T>)
+        where
+            T: CrcWidth,
+        {
+            let algorithm = crc.algorithm;
+            let identity = CrcMatrix::identity();
+            let byte = CrcMatrix::zeros(&algorithm, 1);
+            assert_eq!(identity * byte, byte);
+            assert_eq!(byte * identity, byte);
+            assert_eq!(byte.pow(0), identity);
+            assert_eq!(byte.pow(100), CrcMatrix::zeros(&algorithm, 100));
+            assert_eq!(byte.pow(100).len(), 100);
+            assert!(identity.is_empty());
+
+            let three = CrcMatrix::zeros(&algorithm, 3);
+            let value = crc.initialize().calc_bytes(b"123").value;
+            let expected = crc.calc_zeros(7).value;
+            assert_eq!((three * byte.pow(4)).apply(value), expected);
+            crc.initialize()
+                .calc_bytes(b"123")
+                .calc_matrix(&three.pow(2));
+            assert_eq!(crc.len(), 9);
+            assert_eq!(crc.finalize(), crc.checksum(b"123\0\0\0\0\0\0"));
+        }
+
+        // CRC-5/USB
+        assert_matrix(CRC::<u8>::new_with_width(
+            Endian::Little,
+            5,
+            0x05,
+            0x1f,
+            true,
+            true,
+            0x1f,
+        ));
+        // CRC-12/UMTS
+        assert_matrix(CRC::<u16>::new_with_width(
+            Endian::Big,
+            12,
+            0x80f,
+            0,
+            false,
+            true,
+            0,
+        ));
+        // CRC-82/DARC
+        assert_matrix(CRC::<u128>::new_with_width(
+            Endian::Little,
+            82,
+            0x0308c0111011401440411,
+            0,
+            true,
+            true,
+            0,
+        ));
+    }
+}
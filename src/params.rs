@@ -0,0 +1,186 @@
+use crate::{Algorithm, CrcWidth, CreateTable, Table, CRC};
+
+/// Algorithm and table of a CRC, without the value.
+///
+/// Immutable, so one `Params` can be shared, e.g. in a `static`, by many [`Digest`]s.
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// use mycrc::{catalog::CRC_32_ISCSI, Params};
+///
+/// static CRC32: Params<u32> = Params::<u32>::from_algorithm(CRC_32_ISCSI);
+///
+/// let mut digest = CRC32.digest();
+/// digest.update(b"1234");
+/// // Checkpoint is a copy of a few bytes.
+/// let checkpoint = digest;
+/// assert_eq!(digest.update(b"56789").finalize(), 0xe3069283);
+/// assert_eq!(checkpoint.finalize(), CRC32.checksum(b"1234"));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Params<T, B = [T; 256]> {
+    pub algorithm: Algorithm<T>,
+    pub table: B,
+}
+
+/// Running value of a CRC that borrows the algorithm and the table.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Digest<'a, T, B = [T; 256]> {
+    algorithm: &'a Algorithm<T>,
+    table: &'a B,
+    value: T,
+    len: usize,
+}
+
+macro_rules! params_impl {
+    ( $( $t:ty ),* ) => ($(
+        impl Params<$t> {
+            /// The algorithm creates the table.
+            pub const fn from_algorithm(algorithm: Algorithm<$t>) -> Self {
+                let table = Algorithm::<$t>::create_table_with_width(algorithm.width, algorithm.poly, algorithm.refin);
+                Self { algorithm, table }
+            }
+        }
+    )*)
+}
+
+params_impl!(u8, u16, u32, u64, u128);
+
+impl<T: CrcWidth, B: CreateTable<T>> Params<T, B> {
+    /// The algorithm creates the table at runtime.
+    pub fn create(algorithm: Algorithm<T>) -> Self {
+        Self {
+            algorithm,
+            table: B::create(&algorithm),
+        }
+    }
+}
+
+impl<T: CrcWidth, B: Table<T>> Params<T, B> {
+    /// [`Digest`] with the initialized value.
+    pub fn digest(&self) -> Digest<'_, T, B> {
+        Digest::new(&self.algorithm, &self.table)
+    }
+
+    /// Checksum function.
+    pub fn checksum(&self, bytes: &[u8]) -> T {
+        self.digest().update(bytes).finalize()
+    }
+}
+
+impl<T, B> From<CRC<T, B>> for Params<T, B> {
+    fn from(crc: CRC<T, B>) -> Self {
+        Self {
+            algorithm: crc.algorithm,
+            table: crc.table,
+        }
+    }
+}
+
+impl<'a, T: CrcWidth, B: Table<T>> Digest<'a, T, B> {
+    /// Create `Digest` with the initialized value.
+    pub fn new(algorithm: &'a Algorithm<T>, table: &'a B) -> Self {
+        Self {
+            algorithm,
+            table,
+            value: T::initialize(algorithm),
+            len: 0,
+        }
+    }
+
+    /// Algorithm of the digest.
+    pub fn algorithm(&self) -> &'a Algorithm<T> {
+        self.algorithm
+    }
+
+    /// Value which is not finalized.
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Number of bytes calculated since the value is initialized.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no bytes are calculated since the value is initialized.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Initialize value.
+    pub fn reset(&mut self) -> &mut Self {
+        self.value = T::initialize(self.algorithm);
+        self.len = 0;
+        self
+    }
+
+    /// Calculate bytes.
+    pub fn update(&mut self, bytes: &[u8]) -> &mut Self {
+        self.value = self.table.calc_bytes(self.algorithm, self.value, bytes);
+        self.len += bytes.len();
+        self
+    }
+
+    /// Calculate `len` zero bytes in O(log `len`), like [`CRC::calc_zeros`].
+    pub fn update_zeros(&mut self, len: usize) -> &mut Self {
+        self.value = T::shift_zeros(self.algorithm, self.value, len);
+        self.len += len;
+        self
+    }
+
+    /// Finalize value.
+    /// Change value to checksum.
+    pub fn finalize(&self) -> T {
+        T::finalize(self.algorithm, self.value)
+    }
+
+    /// Finalize to endian bytes.
+    pub fn finalize_to_endian_bytes(&self) -> T::Bytes {
+        T::finalize_to_endian_bytes(self.algorithm, self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Endian, Nibble, CHECK_BYTES};
+
+    #[test]
+    fn digest() {
+        // CRC-24/OPENPGP
+        let mut crc =
+            CRC::<u32>::new_with_width(Endian::Big, 24, 0x864cfb, 0xb704ce, false, false, 0);
+        let params = Params::from(crc);
+        assert_eq!(params.checksum(CHECK_BYTES), 0x21cf02);
+
+        let mut digest = params.digest();
+        for (i, chunk) in CHECK_BYTES.chunks(2).enumerate() {
+            digest.update(chunk);
+            crc.calc_bytes(chunk);
+            assert_eq!(digest.value(), crc.value, "{}", i);
+        }
+        assert_eq!(
+            digest.finalize_to_endian_bytes(),
+            crc.finalize_to_endian_bytes()
+        );
+        assert_eq!(digest.len(), CHECK_BYTES.len());
+        digest
+            .reset()
+            .update(b"1234")
+            .update_zeros(100)
+            .update(b"56789");
+        let mut bytes = [0; 109];
+        bytes[..4].copy_from_slice(b"1234");
+        bytes[104..].copy_from_slice(b"56789");
+        assert_eq!(digest.finalize(), params.checksum(&bytes));
+        assert_eq!(digest.len(), bytes.len());
+        assert_eq!(digest.reset().value(), params.digest().value());
+        assert!(digest.is_empty());
+
+        let params = Params::<u32, Nibble<u32>>::create(crc.algorithm);
+        assert_eq!(params.checksum(CHECK_BYTES), 0x21cf02);
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{table::CRC_32_ISO_HDLC, Algorithm, CrcError, MismatchError};
+use core::convert::TryInto;
+
+/// CRC of a chunk, over the chunk type and the chunk data but not the length.
+///
+/// ```
+/// use mycrc::png;
+///
+/// assert_eq!(png::chunk_crc(b"IEND", &[]), 0xae426082);
+/// ```
+pub fn chunk_crc(chunk_type: &[u8; 4], data: &[u8]) -> u32 {
+    let value = Algorithm::<u32>::calc_bytes_with_values(true, !0, chunk_type, &CRC_32_ISO_HDLC);
+    !Algorithm::<u32>::calc_bytes_with_values(true, value, data, &CRC_32_ISO_HDLC)
+}
+
+/// Compare the CRC of a chunk with `crc`, see [`chunk_crc`].
+pub fn verify_chunk(chunk_type: &[u8; 4], data: &[u8], crc: u32) -> Result<(), MismatchError<u32>> {
+    let actual = chunk_crc(chunk_type, data);
+    if actual == crc {
+        Ok(())
+    } else {
+        Err(MismatchError {
+            expected: crc,
+            actual,
+        })
+    }
+}
+
+/// Verify a chunk as in the file [length + chunk type + chunk data + CRC], all big-endian,
+/// and return the chunk type and the chunk data.
+///
+/// Returns [`CrcError::TooShort`] if `chunk` is shorter than its length tells.
+///
+/// ```
+/// use mycrc::png;
+///
+/// let chunk = b"\0\0\0\0IEND\xae\x42\x60\x82";
+/// assert_eq!(png::verify_raw_chunk(chunk), Ok((b"IEND", &[][..])));
+/// ```
+pub fn verify_raw_chunk(chunk: &[u8]) -> Result<(&[u8; 4], &[u8]), CrcError<u32>> {
+    let too_short = |expected| CrcError::TooShort {
+        expected,
+        actual: chunk.len(),
+    };
+    if chunk.len() < 12 {
+        return Err(too_short(12));
+    }
+    let len = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]) as usize;
+    let end = len.checked_add(12).ok_or(too_short(usize::MAX))?;
+    if chunk.len() < end {
+        return Err(too_short(end));
+    }
+
+    let chunk_type = chunk[4..8].try_into().expect("chunk type is 4 bytes");
+    let data = &chunk[8..end - 4];
+    let crc = chunk[end - 4..end].try_into().expect("CRC is 4 bytes");
+    verify_chunk(chunk_type, data, u32::from_be_bytes(crc))?;
+    Ok((chunk_type, data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Endian, CRC};
+
+    #[test]
+    fn chunk() {
+        // IHDR of a 1x1 8-bit grayscale image.
+        let ihdr = b"\0\0\0\x0dIHDR\0\0\0\x01\0\0\0\x01\x08\x00\0\0\0\x3a\x7e\x9b\x55";
+        let (chunk_type, data) = verify_raw_chunk(ihdr).unwrap();
+        assert_eq!(chunk_type, b"IHDR");
+        assert_eq!(data.len(), 13);
+        assert_eq!(chunk_crc(b"IHDR", data), 0x3a7e9b55);
+        // The CRC-32 of the type and the data.
+        let mut crc = CRC::<u32>::new(Endian::Big, 0x04c11db7, !0, true, true, !0);
+        assert_eq!(crc.checksum(&ihdr[4..21]), 0x3a7e9b55);
+
+        assert!(matches!(
+            verify_chunk(b"IEND", &[], 0),
+            Err(MismatchError {
+                expected: 0,
+                actual: 0xae426082
+            })
+        ));
+        let mut corrupted = *ihdr;
+        corrupted[10] ^= 1;
+        assert!(matches!(
+            verify_raw_chunk(&corrupted),
+            Err(CrcError::Mismatch(_))
+        ));
+        assert_eq!(
+            verify_raw_chunk(&ihdr[..24]),
+            Err(CrcError::TooShort {
+                expected: 25,
+                actual: 24
+            })
+        );
+        assert_eq!(
+            verify_raw_chunk(&ihdr[..11]),
+            Err(CrcError::TooShort {
+                expected: 12,
+                actual: 11
+            })
+        );
+    }
+}
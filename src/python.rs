@@ -0,0 +1,123 @@
+use crate::{bindings, catalog, CRC};
+use pyo3::{exceptions::PyValueError, prelude::*};
+use std::vec::Vec;
+
+/// CRC of up to 64 bits, `mycrc.Crc` in Python.
+///
+/// ```python
+/// import mycrc
+///
+/// crc = mycrc.Crc("CRC-32/ISCSI")
+/// crc.update(b"1234")
+/// crc.update(b"56789")
+/// assert crc.finalize() == 0xe3069283
+/// ```
+#[pyclass(name = "Crc", module = "mycrc", skip_from_py_object)]
+#[derive(Clone, Debug)]
+pub struct PyCrc {
+    crc: CRC<u64>,
+}
+
+#[pymethods]
+impl PyCrc {
+    /// The algorithm of [`catalog::by_name`], with the initialized value.
+    #[new]
+    pub fn new(name: &str) -> PyResult<Self> {
+        Ok(Self {
+            crc: bindings::by_name(name).map_err(PyValueError::new_err)?,
+        })
+    }
+
+    /// Your own algorithm of `width` bits, with the initialized value.
+    #[staticmethod]
+    pub fn with_params(
+        width: u8,
+        poly: u64,
+        init: u64,
+        refin: bool,
+        refout: bool,
+        xorout: u64,
+    ) -> PyResult<Self> {
+        Ok(Self {
+            crc: bindings::with_params(width, poly, init, refin, refout, xorout)
+                .map_err(PyValueError::new_err)?,
+        })
+    }
+
+    /// Width of the algorithm.
+    #[getter]
+    pub fn width(&self) -> u8 {
+        self.crc.algorithm.width
+    }
+
+    /// Check of the algorithm.
+    #[getter]
+    pub fn check(&self) -> u64 {
+        self.crc.algorithm.check
+    }
+
+    /// Initialize value.
+    pub fn reset(&mut self) {
+        self.crc.initialize();
+    }
+
+    /// Calculate bytes.
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.crc.calc_bytes(bytes);
+    }
+
+    /// Checksum of the bytes since the value is initialized. The value is not changed.
+    pub fn finalize(&self) -> u64 {
+        self.crc.finalize()
+    }
+
+    /// Checksum function.
+    pub fn checksum(&mut self, bytes: &[u8]) -> u64 {
+        self.crc.checksum(bytes)
+    }
+}
+
+/// Names of the algorithms of [`catalog::ALL`] of up to 64 bits.
+#[pyfunction]
+pub fn names() -> Vec<&'static str> {
+    catalog::ALL
+        .iter()
+        .filter(|(_, algorithm, _, _)| algorithm.width() <= 64)
+        .map(|&(name, _, _, _)| name)
+        .collect()
+}
+
+/// `mycrc` module of Python.
+#[pymodule]
+fn mycrc(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyCrc>()?;
+    module.add_function(wrap_pyfunction!(names, module)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CHECK_BYTES;
+
+    #[test]
+    fn python() {
+        let mut crc = PyCrc::new("crc-24/openpgp").unwrap();
+        assert_eq!(crc.width(), 24);
+        crc.update(&CHECK_BYTES[..4]);
+        crc.update(&CHECK_BYTES[4..]);
+        assert_eq!(crc.finalize(), crc.check());
+        crc.reset();
+        assert_eq!(crc.checksum(CHECK_BYTES), 0x21cf02);
+
+        // CRC-16/ARC
+        let mut crc = PyCrc::with_params(16, 0x8005, 0, true, true, 0).unwrap();
+        assert_eq!(crc.checksum(CHECK_BYTES), 0xbb3d);
+
+        assert!(names().contains(&"CRC-32/ISCSI"));
+        assert!(!names().contains(&"CRC-82/DARC"));
+        for name in names() {
+            assert!(PyCrc::new(name).is_ok(), "{}", name);
+        }
+    }
+}
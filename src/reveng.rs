@@ -0,0 +1,249 @@
+use crate::{Algorithm, Endian};
+use core::{convert::TryFrom, mem};
+
+/// Iterator of the algorithms of `width` bits that explain all `(message, checksum)` samples,
+/// like [CRC RevEng](https://reveng.sourceforge.io/).
+///
+/// Every polynomial with the `x^0` term and every `refin` and `refout` are tried.
+/// `init` and `xorout` are not brute-forced but solved from the samples, since CRCs are affine.
+/// Samples of the same length must have checksums that differ like the checksums of the messages from zero,
+/// which rejects most polynomials quickly, so give some samples of the same length.
+/// Samples of other lengths tell `init` from `xorout`; if all samples are of the same length, `init` is 0.
+/// Only one of the algorithms that differ in `init` and `xorout` but explain the samples is given.
+///
+/// The search is exhaustive, `2^(width - 1)` polynomials, so it is practical up to about 24 bits.
+/// For wider algorithms, give a candidate polynomial to [`Solver::with_poly`].
+///
+/// `endian` of the algorithms is [`Endian::Little`] if `refout` is `true`, otherwise [`Endian::Big`].
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// use mycrc::{catalog::CRC_8_MAXIM_DOW, reveng::Solver, CRC};
+///
+/// let mut crc8 = CRC::<u8>::from_algorithm(CRC_8_MAXIM_DOW);
+/// let messages: [&[u8]; 5] = [b"hello", b"world", b"crc", b"123456789", b""];
+/// let samples = messages.map(|bytes| (bytes, crc8.checksum(bytes)));
+///
+/// let mut solver = Solver::new(8, &samples);
+/// assert!(solver.next().unwrap().is_equivalent_to(&CRC_8_MAXIM_DOW));
+/// assert_eq!(solver.next(), None);
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Solver<'a, T> {
+    width: u8,
+    samples: &'a [(&'a [u8], T)],
+    /// Polynomial being tried.
+    poly: u128,
+    last_poly: u128,
+    /// `refin` and `refout` being tried, as bits 1 and 0.
+    step: u8,
+    /// Table of `poly` and `refin`.
+    table: [u128; 256],
+    done: bool,
+}
+
+impl<'a, T: Copy + Into<u128> + TryFrom<u128>> Solver<'a, T> {
+    /// Search all polynomials of `width` bits.
+    /// Nothing is found if `samples` is empty.
+    ///
+    /// # Panics
+    /// Panics if `width` is 0 or greater than the bits of `T`.
+    pub fn new(width: u8, samples: &'a [(&'a [u8], T)]) -> Self {
+        assert!(
+            width != 0 && width as usize <= 8 * mem::size_of::<T>(),
+            "width is out of range"
+        );
+        Self {
+            width,
+            samples,
+            poly: 1,
+            last_poly: Algorithm::<u128>::mask(width),
+            step: 0,
+            table: [0; 256],
+            done: samples.is_empty(),
+        }
+    }
+
+    /// Search only `poly` in normal notation.
+    pub fn with_poly(mut self, poly: T) -> Self {
+        self.poly = poly.into();
+        self.last_poly = self.poly;
+        self.step = 0;
+        self
+    }
+
+    /// `init` and `xorout` that explain the samples with `self.poly`, `refin` and `refout`.
+    fn solve(&self, refin: bool, refout: bool) -> Option<(u128, u128)> {
+        let (width, poly) = (self.width, self.poly);
+        // Checksums from zero without `xorout`, and of `init` after `len` bytes.
+        let linear = |bytes: &[u8]| {
+            let value = Algorithm::<u128>::calc_bytes_with_values(refin, 0, bytes, &self.table);
+            Algorithm::<u128>::optional_reflection_with_width(width, refin, refout, value)
+        };
+        let shifted = |init: u128, len: usize| {
+            let value = Algorithm::<u128>::initialize_with_width(width, init, refin);
+            let value = Algorithm::<u128>::shift_zeros(width, poly, refin, value, len);
+            Algorithm::<u128>::optional_reflection_with_width(width, refin, refout, value)
+        };
+
+        let (bytes0, checksum0) = self.samples[0];
+        let diff0 = checksum0.into() ^ linear(bytes0);
+        // `init` and `xorout` cancel out between samples of the same length.
+        for &(bytes, checksum) in &self.samples[1..] {
+            if bytes.len() == bytes0.len() && checksum.into() ^ linear(bytes) != diff0 {
+                return None;
+            }
+        }
+
+        // Samples of other lengths give `shifted(init, len) ^ shifted(init, len0) == diff ^ diff0`.
+        // Each bit of them is an equation of the bits of `init`, as a mask of the bits and the right-hand side.
+        // Equations are eliminated into rows indexed by the highest bit of the mask.
+        let mut rows = [(0u128, false); 128];
+        for &(bytes, checksum) in &self.samples[1..] {
+            if bytes.len() == bytes0.len() {
+                continue;
+            }
+            let mut changes = [0; 128];
+            for (i, change) in changes.iter_mut().enumerate().take(width as usize) {
+                *change = shifted(1 << i, bytes.len()) ^ shifted(1 << i, bytes0.len());
+            }
+            let rhs = checksum.into() ^ linear(bytes) ^ diff0;
+            for bit in 0..width {
+                let mut mask = 0;
+                for (i, change) in changes.iter().enumerate().take(width as usize) {
+                    mask |= (change >> bit & 1) << i;
+                }
+                let mut rhs = rhs >> bit & 1 == 1;
+                while mask != 0 {
+                    let row = &mut rows[(127 - mask.leading_zeros()) as usize];
+                    if row.0 == 0 {
+                        *row = (mask, rhs);
+                        break;
+                    }
+                    mask ^= row.0;
+                    rhs ^= row.1;
+                }
+                if mask == 0 && rhs {
+                    return None;
+                }
+            }
+        }
+        // Undetermined bits are 0, which gives an equivalent algorithm for the lengths of the samples.
+        let mut init = 0;
+        for (bit, &(mask, rhs)) in rows.iter().enumerate() {
+            if mask != 0 && rhs ^ ((mask & init).count_ones() % 2 == 1) {
+                init |= 1 << bit;
+            }
+        }
+        let xorout = diff0 ^ shifted(init, bytes0.len());
+
+        let init_value = Algorithm::<u128>::initialize_with_width(width, init, refin);
+        let explains = self.samples.iter().all(|&(bytes, checksum)| {
+            let value =
+                Algorithm::<u128>::calc_bytes_with_values(refin, init_value, bytes, &self.table);
+            Algorithm::<u128>::finalize_with_width(width, refin, refout, xorout, value)
+                == checksum.into()
+        });
+        if explains {
+            Some((init, xorout))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Copy + Into<u128> + TryFrom<u128>> Iterator for Solver<'_, T> {
+    type Item = Algorithm<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let (poly, refin, refout) = (self.poly, self.step & 2 != 0, self.step & 1 != 0);
+            if !refout {
+                self.table = Algorithm::<u128>::create_table_with_width(self.width, poly, refin);
+            }
+            let found = self.solve(refin, refout);
+
+            self.step += 1;
+            if self.step == 4 {
+                self.step = 0;
+                if poly == self.last_poly {
+                    self.done = true;
+                } else {
+                    self.poly += 2;
+                }
+            }
+
+            if let Some((init, xorout)) = found {
+                let endian = if refout { Endian::Little } else { Endian::Big };
+                let (algorithm, _, _) = Algorithm::<u128>::new_with_width(
+                    endian, self.width, poly, init, refin, refout, xorout,
+                );
+                // Every parameter is of `width` bits, which fit in `T`.
+                let narrow = |n: u128| T::try_from(n).ok().expect("parameter is wider than T");
+                return Some(Algorithm {
+                    endian,
+                    width: self.width,
+                    poly: narrow(poly),
+                    init: narrow(init),
+                    refin,
+                    refout,
+                    xorout: narrow(xorout),
+                    check: narrow(algorithm.check),
+                    residue: narrow(algorithm.residue),
+                });
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CRC;
+
+    #[test]
+    fn solver() {
+        // CRC-16/MODBUS
+        let (modbus, _, _) = Algorithm::<u16>::new(Endian::Little, 0x8005, 0xffff, true, true, 0);
+        let mut crc = CRC::<u16>::create(modbus);
+        let messages: [&[u8]; 4] = [b"hello", b"world", b"123456789", b""];
+        let samples = messages.map(|bytes| (bytes, crc.checksum(bytes)));
+        let mut solver = Solver::new(16, &samples);
+        // `x + 1` divides the polynomial, so `init` is determined up to an equivalent algorithm.
+        assert!(solver.next().unwrap().is_equivalent_to(&modbus));
+        assert_eq!(solver.next(), None);
+
+        // CRC-12/UMTS, whose `refin` and `refout` differ.
+        let (umts, _, _) =
+            Algorithm::<u16>::new_with_width(Endian::Little, 12, 0x80f, 0, false, true, 0);
+        let mut crc = CRC::<u16>::create(umts);
+        let samples = messages.map(|bytes| (bytes, crc.checksum(bytes)));
+        assert_eq!(Solver::new(12, &samples).next(), Some(umts));
+
+        // CRC-32/ISO-HDLC
+        let (hdlc, _, _) = Algorithm::<u32>::new(Endian::Little, 0x04c11db7, !0, true, true, !0);
+        let mut crc = CRC::<u32>::create(hdlc);
+        let samples = messages.map(|bytes| (bytes, crc.checksum(bytes)));
+        let mut solver = Solver::new(32, &samples).with_poly(0x04c11db7);
+        assert_eq!(solver.next(), Some(hdlc));
+        assert_eq!(solver.next(), None);
+        assert_eq!(Solver::new(32, &samples).with_poly(0x1edc6f41).next(), None);
+
+        // Samples of the same length can't tell `init` from `xorout`.
+        let samples = [b"hello", b"world"].map(|bytes| (&bytes[..], crc.checksum(bytes)));
+        let found = Solver::new(32, &samples)
+            .with_poly(0x04c11db7)
+            .next()
+            .unwrap();
+        assert_eq!(found.init, 0);
+        assert_eq!(
+            CRC::<u32>::create(found).checksum(b"01234"),
+            crc.checksum(b"01234")
+        );
+
+        assert_eq!(Solver::<u8>::new(8, &[]).next(), None);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{CrcWidth, Table, CRC};
+
+/// CRC of the last `N` bytes, updated in O(1) per byte.
+///
+/// When the window is full, the oldest byte is removed with a table of its contribution after `N` bytes.
+/// `N` bytes are kept to know the oldest byte, so no allocation is needed.
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// use mycrc::{catalog::CRC_32_ISCSI, RollingCrc, CRC};
+///
+/// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+/// let mut rolling = RollingCrc::<u32, 9>::new(crc32);
+/// for &byte in b"abc123456789" {
+///     rolling.push(byte);
+/// }
+/// assert_eq!(rolling.checksum(), crc32.checksum(b"123456789"));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RollingCrc<T, const N: usize, B = [T; 256]> {
+    crc: CRC<T, B>,
+    /// Contribution of a byte after `N` more bytes.
+    out_table: [T; 256],
+    /// Initialized value after `N` zero bytes.
+    init_after_window: T,
+    window: [u8; N],
+    /// Index of the oldest byte when the window is full.
+    pos: usize,
+    len: usize,
+}
+
+impl<T, const N: usize, B> RollingCrc<T, N, B>
+where
+    T: CrcWidth,
+    B: Table<T>,
+{
+    /// Create an empty window of the algorithm of `crc`.
+    ///
+    /// # Panics
+    /// Panics if `N` is 0.
+    pub fn new(crc: CRC<T, B>) -> Self {
+        assert!(N != 0, "N is 0");
+        let algorithm = &crc.algorithm;
+        let mut out_table = [T::default(); 256];
+        for (byte, out) in out_table.iter_mut().enumerate() {
+            let value = crc.table.calc_bytes(algorithm, T::default(), &[byte as u8]);
+            *out = T::shift_zeros(algorithm, value, N);
+        }
+        Self {
+            init_after_window: T::shift_zeros(algorithm, T::initialize(algorithm), N),
+            crc: CRC {
+                value: T::default(),
+                len: 0,
+                ..crc
+            },
+            out_table,
+            window: [0; N],
+            pos: 0,
+            len: 0,
+        }
+    }
+
+    /// Append `byte` to the window.
+    /// Returns the oldest byte removed from the window if it was full.
+    pub fn push(&mut self, byte: u8) -> Option<u8> {
+        self.crc.calc_byte(byte);
+        let removed = if self.len == N {
+            let oldest = self.window[self.pos];
+            self.crc.value = self.crc.value ^ self.out_table[oldest as usize];
+            Some(oldest)
+        } else {
+            self.len += 1;
+            None
+        };
+        self.window[self.pos] = byte;
+        self.pos = (self.pos + 1) % N;
+        removed
+    }
+
+    /// Checksum of the bytes in the window.
+    pub fn checksum(&self) -> T {
+        let algorithm = &self.crc.algorithm;
+        let init = if self.len == N {
+            self.init_after_window
+        } else {
+            T::shift_zeros(algorithm, T::initialize(algorithm), self.len)
+        };
+        T::finalize(algorithm, self.crc.value ^ init)
+    }
+
+    /// Number of bytes in the window, up to `N`.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no bytes are pushed.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the window has `N` bytes.
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Empty the window.
+    pub fn clear(&mut self) {
+        self.crc.value = T::default();
+        self.pos = 0;
+        self.len = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_bytes, Endian};
+
+    #[test]
+    fn rolling() {
+        fn assert_rolling<T, const N: usize>(mut crc: CRC<T>)
+        where
+            T: CrcWidth,
+        {
+            let mut rolling = RollingCrc::<T, N>::new(crc);
+            assert_eq!(rolling.checksum(), crc.checksum(&[]));
+            let bytes: [u8; 100] = test_bytes();
+            for (i, &byte) in bytes.iter().enumerate() {
+                let removed = rolling.push(byte);
+                let start = (i + 1).saturating_sub(N);
+                assert_eq!(removed, i.checked_sub(N).map(|j| bytes[j]));
+                assert_eq!(rolling.len(), i + 1 - start);
+                assert_eq!(rolling.checksum(), crc.checksum(&bytes[start..=i]), "{}", i);
+            }
+            rolling.clear();
+            assert!(rolling.is_empty());
+            rolling.push(bytes[0]);
+            assert_eq!(rolling.checksum(), crc.checksum(&bytes[..1]));
+        }
+
+        // CRC-16/IBM-3740
+        let crc16 = CRC::<u16>::new(Endian::Big, 0x1021, 0xffff, false, false, 0);
+        assert_rolling::<u16, 16>(crc16);
+        // CRC-24/BLE
+        let crc24 =
+            CRC::<u32>::new_with_width(Endian::Little, 24, 0x00065b, 0x555555, true, true, 0);
+        assert_rolling::<u32, 1>(crc24);
+        // CRC-32/ISO-HDLC
+        let crc32 = CRC::<u32>::new(Endian::Little, 0x04c11db7, !0, true, true, !0);
+        assert_rolling::<u32, 48>(crc32);
+    }
+}
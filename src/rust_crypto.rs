@@ -0,0 +1,74 @@
+use crate::{Endian, Table, CRC};
+use digest::{
+    consts::{U1, U16, U2, U4, U8},
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update,
+};
+
+macro_rules! rust_crypto_impl {
+    ( $( $t:ty => $size:ty ),* ) => ($(
+        impl<B: Table<$t>> OutputSizeUser for CRC<$t, B> {
+            /// Size of the type, also if `width` is narrower.
+            type OutputSize = $size;
+        }
+
+        impl<B: Table<$t>> Update for CRC<$t, B> {
+            fn update(&mut self, data: &[u8]) {
+                self.calc_bytes(data);
+            }
+        }
+
+        impl<B: Table<$t>> FixedOutput for CRC<$t, B> {
+            /// Checksum in `endian` of the algorithm.
+            fn finalize_into(self, out: &mut Output<Self>) {
+                let checksum = self.finalize();
+                let bytes = match self.algorithm.endian {
+                    Endian::Big => checksum.to_be_bytes(),
+                    Endian::Little => checksum.to_le_bytes(),
+                    Endian::Native => checksum.to_ne_bytes(),
+                };
+                out.copy_from_slice(&bytes);
+            }
+        }
+
+        impl<B: Table<$t>> Reset for CRC<$t, B> {
+            fn reset(&mut self) {
+                self.initialize();
+            }
+        }
+
+        impl<B: Table<$t>> FixedOutputReset for CRC<$t, B> {
+            fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+                FixedOutput::finalize_into(*self, out);
+                self.initialize();
+            }
+        }
+
+        impl<B: Table<$t>> HashMarker for CRC<$t, B> {}
+    )*)
+}
+
+rust_crypto_impl!(u8 => U1, u16 => U2, u32 => U4, u64 => U8, u128 => U16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CHECK_BYTES;
+
+    #[test]
+    fn rust_crypto() {
+        fn hash<D: Update + FixedOutputReset>(digest: &mut D, bytes: &[u8]) -> Output<D> {
+            digest.update(&bytes[..4]);
+            digest.update(&bytes[4..]);
+            digest.finalize_fixed_reset()
+        }
+
+        // CRC-16/ARC
+        let mut crc16 = CRC::<u16>::new(Endian::Little, 0x8005, 0, true, true, 0);
+        assert_eq!(hash(&mut crc16, CHECK_BYTES)[..], [0x3d, 0xbb]);
+        assert!(crc16.is_empty());
+        // CRC-24/OPENPGP
+        let mut crc24 =
+            CRC::<u32>::new_with_width(Endian::Big, 24, 0x864cfb, 0xb704ce, false, false, 0);
+        assert_eq!(hash(&mut crc24, CHECK_BYTES)[..], [0, 0x21, 0xcf, 0x02]);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{Algorithm, CrcWidth, CreateTable, Endian, CRC};
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Parameters of [`Algorithm`], without `check` and `residue`.
+#[derive(Deserialize)]
+#[serde(rename = "Algorithm")]
+struct Params<T> {
+    endian: Endian,
+    width: u8,
+    poly: T,
+    init: T,
+    refin: bool,
+    refout: bool,
+    xorout: T,
+}
+
+/// Deserialize the parameters, and compute `check` and `residue` with [`Algorithm::try_new`].
+impl<'de, T: CrcWidth + Deserialize<'de>> Deserialize<'de> for Algorithm<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Params {
+            endian,
+            width,
+            poly,
+            init,
+            refin,
+            refout,
+            xorout,
+        } = Params::deserialize(deserializer)?;
+        T::try_new(endian, width, poly, init, refin, refout, xorout).map_err(D::Error::custom)
+    }
+}
+
+/// Serialized form of [`CRC`], without the table.
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "CRC", bound(deserialize = "T: CrcWidth + Deserialize<'de>"))]
+struct Repr<T> {
+    algorithm: Algorithm<T>,
+    /// Register of `width` bits, see [`CRC::raw_value`].
+    value: T,
+    len: usize,
+}
+
+/// Serialize the algorithm, the register of `width` bits and [`CRC::len`], but not the table.
+impl<T: CrcWidth + Serialize, B> Serialize for CRC<T, B> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Repr {
+            algorithm: self.algorithm,
+            value: T::raw_value(&self.algorithm, self.value),
+            len: self.len,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserialize the algorithm and the value, and create the table.
+impl<'de, T: CrcWidth + Deserialize<'de>, B: CreateTable<T>> Deserialize<'de> for CRC<T, B> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Repr {
+            algorithm,
+            value,
+            len,
+        } = Repr::deserialize(deserializer)?;
+        Ok(Self {
+            algorithm,
+            value: T::from_raw_value(&algorithm, value),
+            table: B::create(&algorithm),
+            len,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CrcState, Endian, Nibble};
+
+    #[test]
+    fn serde() {
+        // CRC-24/OPENPGP
+        let mut crc =
+            CRC::<u32>::new_with_width(Endian::Big, 24, 0x864cfb, 0xb704ce, false, false, 0);
+        crc.calc_bytes(b"1234");
+        let json = serde_json::to_string(&crc).unwrap();
+        assert!(json.contains(&serde_json::to_string(&crc.raw_value()).unwrap()));
+
+        let mut other: CRC<u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(other, crc);
+        let mut nibble: CRC<u32, Nibble<u32>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(nibble.calc_bytes(b"56789").finalize(), 0x21cf02);
+        assert_eq!(other.calc_bytes(b"56789").finalize(), 0x21cf02);
+
+        let algorithm: Algorithm<u32> =
+            serde_json::from_str(&serde_json::to_string(&crc.algorithm).unwrap()).unwrap();
+        assert_eq!(algorithm, crc.algorithm);
+        let state: CrcState<u32> =
+            serde_json::from_str(&serde_json::to_string(&crc.state()).unwrap()).unwrap();
+        assert_eq!(state, crc.state());
+    }
+
+    #[test]
+    fn invalid() {
+        // CRC-8/SMBUS
+        let crc = CRC::<u8>::new(Endian::Big, 0x07, 0x00, false, false, 0x00);
+        let json = serde_json::to_string(&crc).unwrap();
+        let parse = |from: &str, to: &str| serde_json::from_str::<CRC<u8>>(&json.replace(from, to));
+        assert!(parse("\"width\":8", "\"width\":0").is_err());
+        assert!(parse("\"width\":8", "\"width\":9").is_err());
+        assert!(parse("\"width\":8", "\"width\":2").is_err());
+        assert!(parse("\"poly\":7", "\"poly\":6").is_err());
+
+        // CRC-12/UMTS
+        let crc = CRC::<u16>::new_with_width(Endian::Big, 12, 0x80f, 0, false, true, 0);
+        let json = serde_json::to_string(&crc).unwrap();
+        let parse =
+            |from: &str, to: &str| serde_json::from_str::<CRC<u16>>(&json.replace(from, to));
+        assert!(parse("\"width\":12", "\"width\":17").is_err());
+        assert!(parse("\"poly\":2063", "\"poly\":6159").is_err());
+        assert!(parse("\"init\":0", "\"init\":4096").is_err());
+        assert!(parse("\"xorout\":0", "\"xorout\":4096").is_err());
+
+        // `check` and `residue` are computed from the parameters.
+        assert!(json.contains("\"check\":3503"));
+        let json = json.replace("\"check\":3503", "\"check\":0");
+        assert_eq!(serde_json::from_str::<CRC<u16>>(&json).unwrap(), crc);
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{Algorithm, CrcWidth, CreateTable, SliceBy, Table};
+use core::simd::{num::SimdUint, Simd};
+
+/// Slicing-by-16 tables whose 16 lookups per round are gathered with [`core::simd`].
+///
+/// Requires the `portable-simd` feature and a nightly compiler.
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// use mycrc::{catalog::CRC_32_ISCSI, SimdSlice16, CRC};
+///
+/// let mut crc32 = CRC::<u32, SimdSlice16<u32>>::create(CRC_32_ISCSI);
+/// assert_eq!(crc32.checksum(b"123456789"), 0xe3069283);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SimdSlice16<T> {
+    pub slice: SliceBy<T, 16>,
+}
+
+/// Index of the table of each byte in the flattened tables.
+/// The first byte uses the last table.
+const OFFSETS: Simd<usize, 16> = {
+    let mut offsets = [0; 16];
+    let mut i = 0;
+    while i < 16 {
+        offsets[i] = (15 - i) * 256;
+        i += 1;
+    }
+    Simd::from_array(offsets)
+};
+
+macro_rules! simd_slice16_impl {
+    ( $( $t:ty ),* ) => ($(
+        impl CreateTable<$t> for SimdSlice16<$t> {
+            fn create(algorithm: &Algorithm<$t>) -> Self {
+                Self {
+                    slice: SliceBy::create(algorithm),
+                }
+            }
+        }
+
+        impl Table<$t> for SimdSlice16<$t> {
+            fn calc_bytes(&self, algorithm: &Algorithm<$t>, mut value: $t, bytes: &[u8]) -> $t {
+                let tables = self.slice.tables.as_flattened();
+                let mut chunks = bytes.chunks_exact(16);
+                for chunk in &mut chunks {
+                    // The value is XORed with the first bytes.
+                    let mut first = [0; 16];
+                    if algorithm.refin {
+                        first[..core::mem::size_of::<$t>()].copy_from_slice(&value.to_le_bytes());
+                    } else {
+                        first[..core::mem::size_of::<$t>()].copy_from_slice(&value.to_be_bytes());
+                    }
+                    let chunk = Simd::<u8, 16>::from_slice(chunk) ^ Simd::from_array(first);
+                    let indices = chunk.cast::<usize>() + OFFSETS;
+                    value = Simd::<$t, 16>::gather_or_default(tables, indices).reduce_xor();
+                }
+
+                <$t>::calc_bytes_with_tables(algorithm, value, chunks.remainder(), &self.slice.tables)
+            }
+        }
+    )*)
+}
+
+simd_slice16_impl!(u32, u64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_same_checksums, test_bytes, Endian, CRC};
+
+    fn assert_simd<T: CrcWidth>(mut crc: CRC<T>)
+    where
+        SimdSlice16<T>: CreateTable<T>,
+    {
+        let mut simd = CRC::<T, SimdSlice16<T>>::create(crc.algorithm);
+        assert_same_checksums(&mut crc, &mut simd, &test_bytes::<100>());
+    }
+
+    #[test]
+    fn simd() {
+        // CRC-32/ISCSI
+        assert_simd(CRC::<u32>::new(
+            Endian::Little,
+            0x1edc6f41,
+            !0,
+            true,
+            true,
+            !0,
+        ));
+        // CRC-24/OPENPGP
+        assert_simd(CRC::<u32>::new_with_width(
+            Endian::Big,
+            24,
+            0x864cfb,
+            0xb704ce,
+            false,
+            false,
+            0,
+        ));
+        // CRC-64/XZ
+        assert_simd(CRC::<u64>::new(
+            Endian::Little,
+            0x42f0e1eba9ea3693,
+            !0,
+            true,
+            true,
+            !0,
+        ));
+        // CRC-40/GSM
+        assert_simd(CRC::<u64>::new_with_width(
+            Endian::Big,
+            40,
+            0x0004820009,
+            0,
+            false,
+            false,
+            !0 >> 24,
+        ));
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{CrcWidth, StateError, Table, CRC};
+
+/// Snapshot of the value of a [`CRC`], without the algorithm and the table.
+///
+/// A few bytes to save, e.g. to resume a long transfer after a restart.
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// use mycrc::{catalog::CRC_32_ISCSI, CRC};
+///
+/// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+/// let state = crc32.calc_bytes(b"1234").state();
+///
+/// let mut crc32 = CRC::<u32>::from_algorithm(CRC_32_ISCSI);
+/// crc32.restore(state).unwrap();
+/// assert_eq!(crc32.calc_bytes(b"56789").finalize(), 0xe3069283);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CrcState<T> {
+    /// Format of the state, [`CrcState::VERSION`].
+    pub version: u8,
+    /// `width` of the algorithm.
+    pub width: u8,
+    /// `value` of the CRC.
+    pub value: T,
+    /// Number of bytes, see [`CRC::len`].
+    pub len: usize,
+}
+
+impl<T> CrcState<T> {
+    /// Current format of the state.
+    /// It changes when `value` is kept in another way.
+    pub const VERSION: u8 = 1;
+}
+
+impl<T: CrcWidth, B: Table<T>> CRC<T, B> {
+    /// Snapshot of the value.
+    pub fn state(&self) -> CrcState<T> {
+        CrcState {
+            version: CrcState::<T>::VERSION,
+            width: self.algorithm.width,
+            value: self.value,
+            len: self.len,
+        }
+    }
+
+    /// Restore the value from [`CRC::state`].
+    ///
+    /// The algorithm must be the same as that of the snapshot, but only `width` can be checked.
+    pub fn restore(&mut self, state: CrcState<T>) -> Result<&mut Self, StateError> {
+        if state.version != CrcState::<T>::VERSION {
+            return Err(StateError::Version {
+                expected: CrcState::<T>::VERSION,
+                actual: state.version,
+            });
+        }
+        if state.width != self.algorithm.width {
+            return Err(StateError::Width {
+                expected: self.algorithm.width,
+                actual: state.width,
+            });
+        }
+
+        self.value = state.value;
+        self.len = state.len;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Endian;
+
+    #[test]
+    fn restore() {
+        // CRC-24/OPENPGP
+        let mut crc =
+            CRC::<u32>::new_with_width(Endian::Big, 24, 0x864cfb, 0xb704ce, false, false, 0);
+        let state = crc.calc_bytes(b"1234").state();
+        let mut other =
+            CRC::<u32>::new_with_width(Endian::Big, 24, 0x864cfb, 0xb704ce, false, false, 0);
+        assert_eq!(other.restore(state).unwrap().len(), 4);
+        assert_eq!(other.calc_bytes(b"56789").finalize(), 0x21cf02);
+
+        let old = CrcState {
+            version: 0,
+            ..state
+        };
+        assert_eq!(
+            other.restore(old),
+            Err(StateError::Version {
+                expected: 1,
+                actual: 0
+            })
+        );
+        let mut crc32 = CRC::<u32>::new(Endian::Little, 0x04c11db7, !0, true, true, !0);
+        assert_eq!(
+            crc32.restore(state),
+            Err(StateError::Width {
+                expected: 32,
+                actual: 24
+            })
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{
+    Algorithm, Bitwise, CrcWidth, CreateTable, Hardware, Nibble, Slice16, Slice8, Table, CRC,
+};
+
+/// How bytes are calculated, from the smallest to the fastest.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Strategy {
+    /// No table, see [`Bitwise`].
+    Bitwise,
+    /// Table of 16 entries, see [`Nibble`].
+    Nibble,
+    /// Table of 256 entries, `[T; 256]`.
+    #[default]
+    Byte,
+    /// Slicing-by-8 tables, see [`Slice8`].
+    Slice8,
+    /// Slicing-by-16 tables, see [`Slice16`].
+    Slice16,
+    /// CRC instructions of the CPU if supported, see [`Hardware`].
+    Hardware,
+}
+
+/// Table of any [`Strategy`], chosen at runtime.
+///
+/// Its size is that of the largest table, [`Slice16<T>`].
+/// To save memory, choose `B` of [`CRC<T, B>`] at compile time instead.
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// use mycrc::{catalog::CRC_32_ISCSI, AnyTable, Strategy, CRC};
+///
+/// let mut crc32 = CRC::<u32, AnyTable<u32>>::with_strategy(CRC_32_ISCSI, Strategy::Nibble);
+/// assert_eq!(crc32.table.strategy(), Strategy::Nibble);
+/// assert_eq!(crc32.checksum(b"123456789"), 0xe3069283);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AnyTable<T> {
+    /// [`Strategy::Bitwise`]
+    Bitwise(Bitwise),
+    /// [`Strategy::Nibble`]
+    Nibble(Nibble<T>),
+    /// [`Strategy::Byte`]
+    Byte([T; 256]),
+    /// [`Strategy::Slice8`]
+    Slice8(Slice8<T>),
+    /// [`Strategy::Slice16`]
+    Slice16(Slice16<T>),
+    /// [`Strategy::Hardware`]
+    Hardware(Hardware<T>),
+}
+
+impl<T: CrcWidth> AnyTable<T>
+where
+    Hardware<T>: CreateTable<T>,
+{
+    /// Create the table of `strategy`.
+    pub fn new(algorithm: &Algorithm<T>, strategy: Strategy) -> Self {
+        match strategy {
+            Strategy::Bitwise => Self::Bitwise(Bitwise),
+            Strategy::Nibble => Self::Nibble(Nibble::create(algorithm)),
+            Strategy::Byte => Self::Byte(CreateTable::create(algorithm)),
+            Strategy::Slice8 => Self::Slice8(Slice8::create(algorithm)),
+            Strategy::Slice16 => Self::Slice16(Slice16::create(algorithm)),
+            Strategy::Hardware => Self::Hardware(Hardware::create(algorithm)),
+        }
+    }
+}
+
+impl<T> AnyTable<T> {
+    /// Strategy of the table.
+    pub fn strategy(&self) -> Strategy {
+        match self {
+            Self::Bitwise(_) => Strategy::Bitwise,
+            Self::Nibble(_) => Strategy::Nibble,
+            Self::Byte(_) => Strategy::Byte,
+            Self::Slice8(_) => Strategy::Slice8,
+            Self::Slice16(_) => Strategy::Slice16,
+            Self::Hardware(_) => Strategy::Hardware,
+        }
+    }
+}
+
+impl<T: CrcWidth> CreateTable<T> for AnyTable<T>
+where
+    Hardware<T>: CreateTable<T>,
+{
+    /// Create the table of [`Strategy::default`].
+    fn create(algorithm: &Algorithm<T>) -> Self {
+        Self::new(algorithm, Strategy::default())
+    }
+}
+
+impl<T: CrcWidth> Table<T> for AnyTable<T>
+where
+    Hardware<T>: Table<T>,
+{
+    fn calc_bytes(&self, algorithm: &Algorithm<T>, value: T, bytes: &[u8]) -> T {
+        match self {
+            Self::Bitwise(table) => table.calc_bytes(algorithm, value, bytes),
+            Self::Nibble(table) => table.calc_bytes(algorithm, value, bytes),
+            Self::Byte(table) => table.calc_bytes(algorithm, value, bytes),
+            Self::Slice8(table) => table.calc_bytes(algorithm, value, bytes),
+            Self::Slice16(table) => table.calc_bytes(algorithm, value, bytes),
+            Self::Hardware(table) => table.calc_bytes(algorithm, value, bytes),
+        }
+    }
+}
+
+impl<T: CrcWidth> CRC<T, AnyTable<T>>
+where
+    Hardware<T>: CreateTable<T>,
+{
+    /// The algorithm initializes the value and creates the table of `strategy`.
+    pub fn with_strategy(algorithm: Algorithm<T>, strategy: Strategy) -> Self {
+        Self {
+            algorithm,
+            value: T::initialize(&algorithm),
+            table: AnyTable::new(&algorithm, strategy),
+            len: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_bytes, Endian};
+
+    #[test]
+    fn strategies() {
+        let strategies = [
+            Strategy::Bitwise,
+            Strategy::Nibble,
+            Strategy::Byte,
+            Strategy::Slice8,
+            Strategy::Slice16,
+            Strategy::Hardware,
+        ];
+        let bytes: [u8; 300] = test_bytes();
+
+        // CRC-16/ARC
+        let mut crc16 = CRC::<u16>::new(Endian::Little, 0x8005, 0, true, true, 0);
+        // CRC-64/XZ
+        let mut crc64 = CRC::<u64>::new(Endian::Little, 0x42f0e1eba9ea3693, !0, true, true, !0);
+        for strategy in strategies {
+            let mut other = CRC::<u16, AnyTable<u16>>::with_strategy(crc16.algorithm, strategy);
+            assert_eq!(other.table.strategy(), strategy);
+            assert_eq!(other.checksum(&bytes), crc16.checksum(&bytes));
+
+            let mut other = CRC::<u64, AnyTable<u64>>::with_strategy(crc64.algorithm, strategy);
+            assert_eq!(other.checksum(&bytes), crc64.checksum(&bytes));
+        }
+    }
+}
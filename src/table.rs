@@ -0,0 +1,473 @@
+use crate::{Algorithm, CrcWidth};
+use core::{fmt::Debug, hash::Hash, mem};
+
+/// Table of CRC-32/ISO-HDLC, shared by [`png`](crate::png) and [`deflate`](crate::deflate).
+pub(crate) static CRC_32_ISO_HDLC: [u32; 256] = Algorithm::<u32>::create_table(0x04c11db7, true);
+
+/// Table of CRC-32/ISCSI, for the guard of [`Guarded`].
+static CRC_32_ISCSI: [u32; 256] = Algorithm::<u32>::create_table(0x1edc6f41, true);
+
+/// Table used by [`CRC<T, B>`](crate::CRC) to calculate bytes.
+///
+/// `[T; 256]` is the default, one lookup per byte.
+/// A reference to a table is also a table, see [`CrcRef`](crate::CrcRef).
+pub trait Table<T: CrcWidth>: Copy + Debug + Eq + Hash {
+    /// Calculate bytes with value.
+    fn calc_bytes(&self, algorithm: &Algorithm<T>, value: T, bytes: &[u8]) -> T;
+}
+
+/// [`Table`] created from the algorithm at runtime, used by [`CRC::create`](crate::CRC::create).
+pub trait CreateTable<T: CrcWidth>: Table<T> {
+    /// Create the table of `algorithm`.
+    fn create(algorithm: &Algorithm<T>) -> Self;
+}
+
+impl<T: CrcWidth, B: Table<T>> Table<T> for &B {
+    fn calc_bytes(&self, algorithm: &Algorithm<T>, value: T, bytes: &[u8]) -> T {
+        (*self).calc_bytes(algorithm, value, bytes)
+    }
+}
+
+impl<T: CrcWidth> CreateTable<T> for [T; 256] {
+    fn create(algorithm: &Algorithm<T>) -> Self {
+        T::create_table(algorithm)
+    }
+}
+
+impl<T: CrcWidth> Table<T> for [T; 256] {
+    fn calc_bytes(&self, algorithm: &Algorithm<T>, value: T, bytes: &[u8]) -> T {
+        T::calc_bytes(algorithm, value, bytes, self)
+    }
+}
+
+/// Slicing-by-`N` tables, `N` bytes per lookup round.
+///
+/// Takes `N` times the memory of `[T; 256]`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(transparent)]
+pub struct SliceBy<T, const N: usize> {
+    pub tables: [[T; 256]; N],
+}
+
+/// Slicing-by-8 tables.
+pub type Slice8<T> = SliceBy<T, 8>;
+
+/// Slicing-by-16 tables.
+///
+/// For large buffers. `Slice16<u32>` takes 16KB.
+pub type Slice16<T> = SliceBy<T, 16>;
+
+impl<T, const N: usize> SliceBy<T, N> {
+    pub(crate) const VALID_N: () = assert!(N >= 1, "N must be at least 1");
+}
+
+impl<T: CrcWidth, const N: usize> CreateTable<T> for SliceBy<T, N> {
+    fn create(algorithm: &Algorithm<T>) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_N;
+
+        Self {
+            tables: T::create_tables(algorithm),
+        }
+    }
+}
+
+impl<T: CrcWidth, const N: usize> Table<T> for SliceBy<T, N> {
+    fn calc_bytes(&self, algorithm: &Algorithm<T>, value: T, bytes: &[u8]) -> T {
+        T::calc_bytes_with_tables(algorithm, value, bytes, &self.tables)
+    }
+}
+
+/// Table of 16 entries, two lookups per byte.
+///
+/// For small microcontrollers. `Nibble<u32>` takes 64 bytes instead of 1KB.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(transparent)]
+pub struct Nibble<T> {
+    pub table: [T; 16],
+}
+
+impl<T: CrcWidth> CreateTable<T> for Nibble<T> {
+    fn create(algorithm: &Algorithm<T>) -> Self {
+        Self {
+            table: T::create_nibble_table(algorithm),
+        }
+    }
+}
+
+impl<T: CrcWidth> Table<T> for Nibble<T> {
+    fn calc_bytes(&self, algorithm: &Algorithm<T>, value: T, bytes: &[u8]) -> T {
+        T::calc_bytes_with_nibble_table(algorithm, value, bytes, &self.table)
+    }
+}
+
+/// No table, eight shifts per byte.
+///
+/// For targets where even [`Nibble`] is too large.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Bitwise;
+
+impl<T: CrcWidth> CreateTable<T> for Bitwise {
+    fn create(_algorithm: &Algorithm<T>) -> Self {
+        Self
+    }
+}
+
+impl<T: CrcWidth> Table<T> for Bitwise {
+    fn calc_bytes(&self, algorithm: &Algorithm<T>, value: T, bytes: &[u8]) -> T {
+        T::calc_bytes_bitwise(algorithm, value, bytes)
+    }
+}
+
+/// Table with three interleaved streams, combined with [`Algorithm::combine_values`].
+///
+/// Bytes are split into three streams which are calculated in the same loop,
+/// so that the lookups of the streams run in parallel on out-of-order CPUs.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct Interleaved<T> {
+    pub table: [T; 256],
+    /// `x^(8 * Interleaved::LANE)` modulo the polynomial.
+    pub xpow: T,
+}
+
+impl<T> Interleaved<T> {
+    /// Length of each stream.
+    pub const LANE: usize = 512;
+}
+
+macro_rules! interleaved_impl {
+    ( $( $t:ty ),* ) => ($(
+        impl CreateTable<$t> for Interleaved<$t> {
+            fn create(algorithm: &Algorithm<$t>) -> Self {
+                Self {
+                    table: Algorithm::<$t>::create_table_with_width(algorithm.width, algorithm.poly, algorithm.refin),
+                    xpow: Algorithm::<$t>::xpow8n(algorithm.width, algorithm.poly, Self::LANE),
+                }
+            }
+        }
+
+        impl Table<$t> for Interleaved<$t> {
+            fn calc_bytes(&self, algorithm: &Algorithm<$t>, mut value: $t, bytes: &[u8]) -> $t {
+                let (width, poly, refin) = (algorithm.width, algorithm.poly, algorithm.refin);
+                let calc = |value: $t, byte: u8| {
+                    Algorithm::<$t>::calc_bytes_with_values(refin, value, &[byte], &self.table)
+                };
+
+                let mut chunks = bytes.chunks_exact(3 * Self::LANE);
+                for chunk in &mut chunks {
+                    let (a, rest) = chunk.split_at(Self::LANE);
+                    let (b, c) = rest.split_at(Self::LANE);
+                    let mut values = [value, 0, 0];
+                    for i in 0..Self::LANE {
+                        values[0] = calc(values[0], a[i]);
+                        values[1] = calc(values[1], b[i]);
+                        values[2] = calc(values[2], c[i]);
+                    }
+                    value = Algorithm::<$t>::mul_xpow(width, poly, refin, values[0], self.xpow) ^ values[1];
+                    value = Algorithm::<$t>::mul_xpow(width, poly, refin, value, self.xpow) ^ values[2];
+                }
+
+                Algorithm::<$t>::calc_bytes_with_values(refin, value, chunks.remainder(), &self.table)
+            }
+        }
+    )*)
+}
+
+interleaved_impl!(u8, u16, u32, u64, u128);
+
+/// Table that skips blocks of zero bytes with a precomputed operator.
+///
+/// For storage and firmware images with large zero-filled regions.
+/// Each zero block of [`SkipZeros::BLOCK`] bytes costs one multiplication instead of `BLOCK` lookups of `B`.
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// use mycrc::{catalog::CRC_32_ISCSI, SkipZeros, Slice8, CRC};
+///
+/// let mut crc32 = CRC::<u32, SkipZeros<u32, Slice8<u32>>>::create(CRC_32_ISCSI);
+/// assert_eq!(crc32.checksum(b"123456789"), 0xe3069283);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SkipZeros<T, B = [T; 256]> {
+    pub table: B,
+    /// `x^(8 * SkipZeros::BLOCK)` modulo the polynomial.
+    pub xpow: T,
+}
+
+impl<T, B> SkipZeros<T, B> {
+    /// Length of each block checked for zeros.
+    pub const BLOCK: usize = 512;
+}
+
+impl<T: CrcWidth, B: CreateTable<T>> CreateTable<T> for SkipZeros<T, B> {
+    fn create(algorithm: &Algorithm<T>) -> Self {
+        Self {
+            table: B::create(algorithm),
+            xpow: T::xpow8n(algorithm, Self::BLOCK),
+        }
+    }
+}
+
+impl<T: CrcWidth, B: Table<T>> Table<T> for SkipZeros<T, B> {
+    fn calc_bytes(&self, algorithm: &Algorithm<T>, mut value: T, bytes: &[u8]) -> T {
+        // Start of the bytes not calculated yet.
+        let mut start = 0;
+        for (i, block) in bytes.chunks_exact(Self::BLOCK).enumerate() {
+            // No early return, so that the check is vectorized.
+            if block.iter().fold(0, |acc, byte| acc | byte) == 0 {
+                let end = i * Self::BLOCK;
+                value = self.table.calc_bytes(algorithm, value, &bytes[start..end]);
+                value = T::mul_xpow(algorithm, value, self.xpow);
+                start = end + Self::BLOCK;
+            }
+        }
+
+        self.table.calc_bytes(algorithm, value, &bytes[start..])
+    }
+}
+
+/// `[T; 256]` stored with a guard CRC-32/ISCSI of its entries, which is verified before every use.
+///
+/// For functional safety (e.g. ISO 26262, IEC 61508), where a table corrupted in flash or RAM is a single point of failure.
+/// If the guard doesn't match, bytes are calculated bit by bit without the table, so the checksum stays correct,
+/// and [`Guarded::is_intact`] reports the failure.
+/// Verifying costs a CRC-32 of the table per call, so prefer few large calls.
+/// See also [`CRC::finalize_checked`](crate::CRC::finalize_checked).
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// use mycrc::{catalog::CRC_32_ISCSI, Guarded, CRC};
+///
+/// let mut crc32 = CRC::<u32, Guarded<u32>>::create(CRC_32_ISCSI);
+/// crc32.table.table[0x80] ^= 1;
+/// assert!(!crc32.table.is_intact());
+/// assert_eq!(crc32.checksum(b"123456789"), 0xe3069283);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Guarded<T> {
+    pub table: [T; 256],
+    /// CRC-32/ISCSI of the entries in little-endian bytes.
+    pub guard: u32,
+}
+
+impl<T: Copy + Into<u128>> Guarded<T> {
+    /// Guard of `table`.
+    fn guard_of(table: &[T; 256]) -> u32 {
+        let mut value = !0;
+        for &entry in table {
+            let bytes = entry.into().to_le_bytes();
+            let bytes = &bytes[..mem::size_of::<T>()];
+            value = Algorithm::<u32>::calc_bytes_with_values(true, value, bytes, &CRC_32_ISCSI);
+        }
+        !value
+    }
+
+    /// Returns `true` if the table matches the guard.
+    pub fn is_intact(&self) -> bool {
+        Self::guard_of(&self.table) == self.guard
+    }
+}
+
+impl<T: CrcWidth> CreateTable<T> for Guarded<T> {
+    fn create(algorithm: &Algorithm<T>) -> Self {
+        let table = T::create_table(algorithm);
+        Self {
+            guard: Self::guard_of(&table),
+            table,
+        }
+    }
+}
+
+impl<T: CrcWidth> Table<T> for Guarded<T> {
+    fn calc_bytes(&self, algorithm: &Algorithm<T>, value: T, bytes: &[u8]) -> T {
+        if self.is_intact() {
+            T::calc_bytes(algorithm, value, bytes, &self.table)
+        } else {
+            T::calc_bytes_bitwise(algorithm, value, bytes)
+        }
+    }
+}
+
+// SAFETY: The tables are `repr(transparent)` or `repr(C)` without padding, only of `T`.
+// With the `bytemuck` feature, a table can be written into a binary blob or DMA'd with `bytemuck::bytes_of`.
+#[cfg(feature = "bytemuck")]
+mod pod {
+    use super::{Interleaved, Nibble, SliceBy};
+    use bytemuck::{Pod, Zeroable};
+
+    unsafe impl<T: Zeroable, const N: usize> Zeroable for SliceBy<T, N> {}
+    unsafe impl<T: Pod, const N: usize> Pod for SliceBy<T, N> {}
+    unsafe impl<T: Zeroable> Zeroable for Nibble<T> {}
+    unsafe impl<T: Pod> Pod for Nibble<T> {}
+    unsafe impl<T: Zeroable> Zeroable for Interleaved<T> {}
+    unsafe impl<T: Pod> Pod for Interleaved<T> {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_same_checksums, test_bytes, Endian, CRC};
+
+    fn assert_table<T: CrcWidth, B: Table<T>>(mut crc: CRC<T>, mut other: CRC<T, B>) {
+        assert_same_checksums(&mut crc, &mut other, &test_bytes::<64>());
+        // Longer than the streams of `Interleaved`.
+        let bytes: [u8; 3 * 3 * Interleaved::<()>::LANE + 5] =
+            core::array::from_fn(|i| (i as u8).wrapping_mul(0x3b) ^ (i >> 8) as u8);
+        assert_eq!(other.checksum(&bytes), crc.checksum(&bytes));
+        // Zero blocks of `SkipZeros` between other bytes.
+        let (mut bytes, len) = (bytes, bytes.len());
+        bytes[100..100 + 3 * SkipZeros::<(), ()>::BLOCK].fill(0);
+        bytes[len - 2 * SkipZeros::<(), ()>::BLOCK..].fill(0);
+        assert_eq!(other.checksum(&bytes), crc.checksum(&bytes));
+        crc.checksum(&bytes[..1]);
+        other.checksum(&bytes[..1]);
+        assert_eq!(
+            other.calc_bytes(&bytes[1..]).finalize(),
+            crc.calc_bytes(&bytes[1..]).finalize()
+        );
+    }
+
+    // Compare each table with `[T; 256]`.
+    macro_rules! assert_tables {
+        ($t:ty, $($arg:expr),*) => {
+            let crc = CRC::<$t>::new_with_width($($arg),*);
+            assert_table(crc, CRC::<$t, SliceBy<$t, 1>>::new_with_width($($arg),*));
+            assert_table(crc, CRC::<$t, SliceBy<$t, 3>>::new_with_width($($arg),*));
+            assert_table(crc, CRC::<$t, Slice8<$t>>::new_with_width($($arg),*));
+            assert_table(crc, CRC::<$t, Slice16<$t>>::new_with_width($($arg),*));
+            assert_table(crc, CRC::<$t, SliceBy<$t, 20>>::new_with_width($($arg),*));
+            assert_table(crc, CRC::<$t, Nibble<$t>>::new_with_width($($arg),*));
+            assert_table(crc, CRC::<$t, Bitwise>::new_with_width($($arg),*));
+            assert_table(crc, CRC::<$t, Interleaved<$t>>::create(crc.algorithm));
+            assert_table(crc, CRC::<$t, SkipZeros<$t>>::create(crc.algorithm));
+            assert_table(crc, crc.as_crc_ref());
+            assert_table(crc, CRC::<$t, SkipZeros<$t, Nibble<$t>>>::create(crc.algorithm));
+            assert_table(crc, CRC::<$t, Guarded<$t>>::create(crc.algorithm));
+        };
+    }
+
+    #[test]
+    fn tables() {
+        // CRC-3/GSM
+        assert_tables!(u8, Endian::Big, 3, 0x3, 0x0, false, false, 0x7);
+        // CRC-8/ROHC
+        assert_tables!(u8, Endian::Little, 8, 0x07, 0xff, true, true, 0x00);
+        // CRC-12/UMTS
+        assert_tables!(u16, Endian::Big, 12, 0x80f, 0x000, false, true, 0x000);
+        // CRC-32/ISCSI
+        assert_tables!(
+            u32,
+            Endian::Little,
+            32,
+            0x1edc6f41,
+            0xffffffff,
+            true,
+            true,
+            0xffffffff
+        );
+        // CRC-40/GSM
+        assert_tables!(
+            u64,
+            Endian::Big,
+            40,
+            0x0004820009,
+            0x0000000000,
+            false,
+            false,
+            0xffffffffff
+        );
+        // CRC-64/XZ
+        assert_tables!(
+            u64,
+            Endian::Little,
+            64,
+            0x42f0e1eba9ea3693,
+            u64::MAX,
+            true,
+            true,
+            u64::MAX
+        );
+        // CRC-82/DARC
+        assert_tables!(
+            u128,
+            Endian::Little,
+            82,
+            0x0308c0111011401440411,
+            0,
+            true,
+            true,
+            0
+        );
+    }
+
+    #[test]
+    fn new() {
+        // CRC-16/ARC
+        let crc = CRC::<u16>::new(Endian::Little, 0x8005, 0, true, true, 0);
+        assert_table(
+            crc,
+            CRC::<u16, Slice8<u16>>::new(Endian::Little, 0x8005, 0, true, true, 0),
+        );
+        assert_table(
+            crc,
+            CRC::<u16, Nibble<u16>>::new(Endian::Little, 0x8005, 0, true, true, 0),
+        );
+        assert_table(
+            crc,
+            CRC::<u16, Bitwise>::new(Endian::Little, 0x8005, 0, true, true, 0),
+        );
+    }
+
+    #[test]
+    fn guarded() {
+        // CRC-16/ARC
+        let (arc, _, _) = Algorithm::<u16>::new(Endian::Little, 0x8005, 0, true, true, 0);
+        let mut crc = CRC::<u16, Guarded<u16>>::create(arc);
+        assert!(crc.table.is_intact());
+        assert_eq!(crc.checksum(b"123456789"), 0xbb3d);
+
+        // Corrupted entries are not used.
+        crc.table.table[0x31] ^= 0x8000;
+        assert!(!crc.table.is_intact());
+        assert_eq!(crc.checksum(b"123456789"), 0xbb3d);
+        crc.table.guard ^= 1;
+        assert!(!crc.table.is_intact());
+
+        // The guard is CRC-32/ISCSI of the entries.
+        let crc = CRC::<u16, Guarded<u16>>::create(arc);
+        let mut iscsi = CRC::<u32>::new(Endian::Little, 0x1edc6f41, !0, true, true, !0);
+        for entry in crc.table.table {
+            iscsi.calc_bytes(&entry.to_le_bytes());
+        }
+        assert_eq!(crc.table.guard, iscsi.finalize());
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn pod() {
+        // CRC-32/ISO-HDLC
+        let crc = CRC::<u32, Slice8<u32>>::new(Endian::Little, 0x04c11db7, !0, true, true, !0);
+        let bytes = bytemuck::bytes_of(&crc.table);
+        assert_eq!(bytes.len(), 8 * 256 * 4);
+        assert_eq!(
+            bytemuck::pod_read_unaligned::<Slice8<u32>>(bytes),
+            crc.table
+        );
+
+        let table = Interleaved::<u32>::create(&crc.algorithm);
+        let bytes = bytemuck::bytes_of(&table);
+        assert_eq!(bytes.len(), 257 * 4);
+        assert_eq!(
+            bytemuck::pod_read_unaligned::<Interleaved<u32>>(bytes),
+            table
+        );
+        // CRC-16/ARC
+        let crc = CRC::<u16, Nibble<u16>>::new(Endian::Little, 0x8005, 0, true, true, 0);
+        assert_eq!(bytemuck::bytes_of(&crc.table).len(), 16 * 2);
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{Algorithm, CrcWidth, HwRegister};
+use core::iter::FusedIterator;
+
+/// Input bit and the register after it, see [`LfsrTrace`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct LfsrStep<T> {
+    /// Index of the bit in the order the bits are shifted in, so the byte is `index / 8`.
+    pub index: usize,
+    /// Bit shifted in.
+    pub bit: bool,
+    /// Register after the bit, with the conventions of [`HwRegister`].
+    pub register: T,
+}
+
+/// Iterator of the shift register after each bit of bytes, from [`CRC::trace`](crate::CRC::trace).
+///
+/// Bits of each byte are shifted in least significant first if `refin`, like the hardware does,
+/// so the steps can be compared with a capture of a CRC block bit by bit.
+///
+/// ```
+/// # #[cfg(feature = "catalog")]
+/// # {
+/// use mycrc::{catalog::CRC_8_SMBUS, HwRegister, CRC};
+///
+/// let mut crc8 = CRC::<u8>::from_algorithm(CRC_8_SMBUS);
+/// let register = HwRegister::default();
+/// let steps: Vec<_> = crc8.initialize().trace(b"1", register).map(|step| step.register).collect();
+/// assert_eq!(steps, [0x00, 0x00, 0x07, 0x09, 0x12, 0x24, 0x48, 0x97]);
+/// assert_eq!(crc8.calc_bytes(b"1").finalize(), 0x97);
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct LfsrTrace<'a, T> {
+    algorithm: Algorithm<T>,
+    value: T,
+    bytes: &'a [u8],
+    index: usize,
+    register: HwRegister,
+}
+
+impl<'a, T> LfsrTrace<'a, T> {
+    pub(crate) fn new(
+        algorithm: Algorithm<T>,
+        value: T,
+        bytes: &'a [u8],
+        register: HwRegister,
+    ) -> Self {
+        Self {
+            algorithm,
+            value,
+            bytes,
+            index: 0,
+            register,
+        }
+    }
+}
+
+impl<T: CrcWidth> Iterator for LfsrTrace<'_, T> {
+    type Item = LfsrStep<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let byte = *self.bytes.get(self.index / 8)?;
+        let j = self.index % 8;
+        // Least significant bit first if `refin`.
+        let bit = if self.algorithm.refin {
+            byte >> j & 1 == 1
+        } else {
+            byte >> (7 - j) & 1 == 1
+        };
+        self.value = T::calc_bit(&self.algorithm, self.value, bit);
+        let step = LfsrStep {
+            index: self.index,
+            bit,
+            register: T::to_hw_register(&self.algorithm, self.value, self.register),
+        };
+        self.index += 1;
+        Some(step)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = 8 * self.bytes.len() - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<T: CrcWidth> ExactSizeIterator for LfsrTrace<'_, T> {}
+
+impl<T: CrcWidth> FusedIterator for LfsrTrace<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Endian, HwRegister, CRC};
+
+    #[test]
+    fn trace() {
+        // CRC-12/UMTS, whose `refin` and `refout` differ.
+        let mut crc = CRC::<u16>::new_with_width(Endian::Big, 12, 0x80f, 0, false, true, 0);
+        // CRC-16/KERMIT
+        let mut kermit = CRC::<u16>::new(Endian::Little, 0x1021, 0, true, true, 0);
+        for crc in [&mut crc, &mut kermit] {
+            for register in [false, true].map(|reflected| HwRegister {
+                reflected,
+                xorout: false,
+            }) {
+                crc.initialize().calc_bytes(b"12");
+                let trace = crc.trace(b"345", register);
+                assert_eq!(trace.len(), 24);
+                let last = trace.last().unwrap();
+                assert_eq!(last.index, 23);
+                // The most significant bit of `b'5'` is 0 and the least is 1.
+                assert_eq!(last.bit, !crc.algorithm.refin);
+                crc.calc_bytes(b"345");
+                assert_eq!(last.register, crc.to_hw_register(register));
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{Algorithm, CrcWidth};
+use std::{vec, vec::Vec};
+
+/// Message and its checksum, see [`Algorithm::test_vectors`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TestVector<T> {
+    /// Description of the message, e.g. `"incrementing 256"`.
+    pub name: &'static str,
+    pub message: Vec<u8>,
+    pub checksum: T,
+}
+
+impl<T: CrcWidth> Algorithm<T> {
+    /// Messages of lengths and patterns that exercise an implementation, with their checksums.
+    ///
+    /// The vectors are always the same, to compare with another implementation, e.g. in C or VHDL:
+    /// empty, single bytes `0x00` and `0xff`, `b"123456789"`, 256 bytes of `0x00` and of `0xff`,
+    /// and incrementing bytes from `0x00` of 255, 256 and 257 bytes.
+    /// Checksums are calculated bit by bit.
+    ///
+    /// Requires the `std` feature.
+    ///
+    /// ```
+    /// # #[cfg(feature = "catalog")]
+    /// # {
+    /// use mycrc::catalog::CRC_32_ISCSI;
+    ///
+    /// let vectors = CRC_32_ISCSI.test_vectors();
+    /// let check = vectors.iter().find(|vector| vector.name == "check").unwrap();
+    /// assert_eq!(check.checksum, 0xe3069283);
+    /// # }
+    /// ```
+    pub fn test_vectors(&self) -> Vec<TestVector<T>> {
+        let incrementing = |len: usize| (0..len).map(|i| i as u8).collect();
+        let messages: [(&'static str, Vec<u8>); 9] = [
+            ("empty", Vec::new()),
+            ("0x00", vec![0x00]),
+            ("0xff", vec![0xff]),
+            ("check", b"123456789".to_vec()),
+            ("zeros 256", vec![0x00; 256]),
+            ("ones 256", vec![0xff; 256]),
+            ("incrementing 255", incrementing(255)),
+            ("incrementing 256", incrementing(256)),
+            ("incrementing 257", incrementing(257)),
+        ];
+        IntoIterator::into_iter(messages)
+            .map(|(name, message)| {
+                let value = T::calc_bytes_bitwise(self, T::initialize(self), &message);
+                TestVector {
+                    name,
+                    checksum: T::finalize(self, value),
+                    message,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Endian, CRC};
+
+    #[test]
+    fn test_vectors() {
+        // CRC-12/UMTS
+        let mut crc = CRC::<u16>::new_with_width(Endian::Big, 12, 0x80f, 0, false, true, 0);
+        let vectors = crc.algorithm.test_vectors();
+        assert_eq!(vectors.len(), 9);
+        assert_eq!(vectors[3].checksum, crc.algorithm.check);
+        assert_eq!(vectors[8].message.len(), 257);
+        assert_eq!(vectors[8].message[256], 0x00);
+        for vector in vectors {
+            assert_eq!(crc.checksum(&vector.message), vector.checksum);
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{bindings, CRC};
+use wasm_bindgen::prelude::*;
+
+/// CRC of up to 64 bits, `Crc` in JavaScript.
+///
+/// ```js
+/// const crc = new Crc("CRC-32/ISCSI");
+/// crc.update(new TextEncoder().encode("123456789"));
+/// crc.finalize() === 0xe3069283n;
+/// ```
+#[wasm_bindgen(js_name = Crc)]
+#[derive(Clone, Debug)]
+pub struct WasmCrc {
+    crc: CRC<u64>,
+}
+
+#[wasm_bindgen(js_class = Crc)]
+impl WasmCrc {
+    /// The algorithm of [`catalog::by_name`](crate::catalog::by_name), with the initialized value.
+    #[wasm_bindgen(constructor)]
+    pub fn new(name: &str) -> Result<WasmCrc, JsError> {
+        Ok(Self {
+            crc: bindings::by_name(name).map_err(JsError::new)?,
+        })
+    }
+
+    /// Your own algorithm of `width` bits, with the initialized value.
+    #[wasm_bindgen(js_name = withParams)]
+    pub fn with_params(
+        width: u8,
+        poly: u64,
+        init: u64,
+        refin: bool,
+        refout: bool,
+        xorout: u64,
+    ) -> Result<WasmCrc, JsError> {
+        Ok(Self {
+            crc: bindings::with_params(width, poly, init, refin, refout, xorout)
+                .map_err(|e| JsError::new(&e))?,
+        })
+    }
+
+    /// Width of the algorithm.
+    #[wasm_bindgen(getter)]
+    pub fn width(&self) -> u8 {
+        self.crc.algorithm.width
+    }
+
+    /// Check of the algorithm.
+    #[wasm_bindgen(getter)]
+    pub fn check(&self) -> u64 {
+        self.crc.algorithm.check
+    }
+
+    /// Initialize value.
+    pub fn reset(&mut self) {
+        self.crc.initialize();
+    }
+
+    /// Calculate bytes.
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.crc.calc_bytes(bytes);
+    }
+
+    /// Checksum of the bytes since the value is initialized. The value is not changed.
+    pub fn finalize(&self) -> u64 {
+        self.crc.finalize()
+    }
+
+    /// Checksum function.
+    pub fn checksum(&mut self, bytes: &[u8]) -> u64 {
+        self.crc.checksum(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CHECK_BYTES;
+
+    #[test]
+    fn wasm() {
+        let mut crc = WasmCrc::new("crc-24/openpgp").unwrap();
+        assert_eq!(crc.width(), 24);
+        crc.update(&CHECK_BYTES[..4]);
+        crc.update(&CHECK_BYTES[4..]);
+        assert_eq!(crc.finalize(), crc.check());
+        crc.reset();
+        assert_eq!(crc.checksum(CHECK_BYTES), 0x21cf02);
+
+        // CRC-16/ARC
+        let mut crc = WasmCrc::with_params(16, 0x8005, 0, true, true, 0).unwrap();
+        assert_eq!(crc.checksum(CHECK_BYTES), 0xbb3d);
+    }
+}
@@ -0,0 +1,287 @@
+use crate::{Algorithm, ChecksumBytes, Endian, HwRegister, ParamError};
+use core::{
+    convert::TryFrom,
+    fmt::Debug,
+    hash::Hash,
+    mem,
+    ops::{BitXor, Deref},
+};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Unsigned integer that holds the value of [`CRC<T>`](crate::CRC).
+///
+/// Implemented for `u8`, `u16`, `u32`, `u64` and `u128`, so that downstream code can be generic over `CRC<T>`.
+/// It is sealed, so it cannot be implemented for other types.
+///
+/// ```
+/// use mycrc::{CrcWidth, CRC};
+///
+/// fn verify<T: CrcWidth>(crc: &mut CRC<T>, bytes: &[u8]) -> bool {
+///     crc.is_error_free_bytes(bytes)
+/// }
+/// ```
+pub trait CrcWidth:
+    Copy
+    + Debug
+    + Default
+    + Eq
+    + Hash
+    + BitXor<Output = Self>
+    + Into<u128>
+    + TryFrom<u128>
+    + sealed::Sealed
+    + 'static
+{
+    /// Checksum bytes.
+    type Bytes: Copy + Debug + Eq + Hash + AsRef<[u8]> + Deref<Target = [u8]>;
+
+    /// Initialize value.
+    fn initialize(algorithm: &Algorithm<Self>) -> Self;
+
+    /// Create table.
+    fn create_table(algorithm: &Algorithm<Self>) -> [Self; 256];
+
+    /// Calculate bytes with value.
+    fn calc_bytes(
+        algorithm: &Algorithm<Self>,
+        value: Self,
+        bytes: &[u8],
+        table: &[Self; 256],
+    ) -> Self;
+
+    /// Create tables for slicing-by-`N`.
+    fn create_tables<const N: usize>(algorithm: &Algorithm<Self>) -> [[Self; 256]; N];
+
+    /// Calculate bytes with value and slicing-by-`N` tables.
+    fn calc_bytes_with_tables<const N: usize>(
+        algorithm: &Algorithm<Self>,
+        value: Self,
+        bytes: &[u8],
+        tables: &[[Self; 256]; N],
+    ) -> Self;
+
+    /// Create table of 16 entries.
+    fn create_nibble_table(algorithm: &Algorithm<Self>) -> [Self; 16];
+
+    /// Calculate bytes with value and a table of 16 entries.
+    fn calc_bytes_with_nibble_table(
+        algorithm: &Algorithm<Self>,
+        value: Self,
+        bytes: &[u8],
+        table: &[Self; 16],
+    ) -> Self;
+
+    /// Calculate bytes with value bit by bit.
+    fn calc_bytes_bitwise(algorithm: &Algorithm<Self>, value: Self, bytes: &[u8]) -> Self;
+
+    /// `x^(8 * len)` modulo the polynomial, to be reused with [`CrcWidth::mul_xpow`].
+    fn xpow8n(algorithm: &Algorithm<Self>, len: usize) -> Self;
+
+    /// Calculate zero bytes with value and `xpow` from [`CrcWidth::xpow8n`].
+    fn mul_xpow(algorithm: &Algorithm<Self>, value: Self, xpow: Self) -> Self;
+
+    /// Calculate `len` zero bytes with value, in O(log `len`).
+    fn shift_zeros(algorithm: &Algorithm<Self>, value: Self, len: usize) -> Self;
+
+    /// Combine `value1` of the first bytes and `value2` of the following `len2` bytes calculated from zero.
+    fn combine_values(algorithm: &Algorithm<Self>, value1: Self, value2: Self, len2: usize)
+        -> Self;
+
+    /// Combine `checksum1` of the first bytes and `checksum2` of the following `len2` bytes.
+    fn combine(algorithm: &Algorithm<Self>, checksum1: Self, checksum2: Self, len2: usize) -> Self;
+
+    /// Checksum after the byte at `offset` of `len` bytes is changed.
+    fn patch(
+        algorithm: &Algorithm<Self>,
+        checksum: Self,
+        offset: usize,
+        old_byte: u8,
+        new_byte: u8,
+        len: usize,
+    ) -> Self;
+
+    /// Checksum of `len` bytes after `zeros` zero bytes are prepended to them.
+    fn prepend_zeros(algorithm: &Algorithm<Self>, checksum: Self, len: usize, zeros: usize)
+        -> Self;
+
+    /// Overwrite bytes at `offset` so that the checksum of `bytes` is `checksum`.
+    fn forge(algorithm: &Algorithm<Self>, bytes: &mut [u8], offset: usize, checksum: Self) -> bool;
+
+    /// Calculate bit with value.
+    fn calc_bit(algorithm: &Algorithm<Self>, value: Self, bit: bool) -> Self;
+
+    /// Optional reflection.
+    fn optional_reflection(algorithm: &Algorithm<Self>, value: Self) -> Self;
+
+    /// Register of `width` bits from value.
+    fn raw_value(algorithm: &Algorithm<Self>, value: Self) -> Self;
+
+    /// Value from register of `width` bits.
+    fn from_raw_value(algorithm: &Algorithm<Self>, raw: Self) -> Self;
+
+    /// Register of a peripheral from value.
+    fn to_hw_register(algorithm: &Algorithm<Self>, value: Self, register: HwRegister) -> Self;
+
+    /// Value from register of a peripheral.
+    fn from_hw_register(
+        algorithm: &Algorithm<Self>,
+        hw_register: Self,
+        register: HwRegister,
+    ) -> Self;
+
+    /// Finalize value.
+    fn finalize(algorithm: &Algorithm<Self>, value: Self) -> Self;
+
+    /// Inverse of [`CrcWidth::finalize`].
+    fn unfinalize(algorithm: &Algorithm<Self>, checksum: Self) -> Self;
+
+    /// From endian bytes.
+    fn from_endian_bytes(bytes: &[u8], endian: Endian) -> Self;
+
+    /// Finalize to endian bytes.
+    fn finalize_to_endian_bytes(algorithm: &Algorithm<Self>, value: Self) -> Self::Bytes;
+
+    /// Calculate residue.
+    fn residue(algorithm: &Algorithm<Self>) -> Self;
+
+    /// Algorithm of the parameters, see [`Algorithm::try_new`].
+    fn try_new(
+        endian: Endian,
+        width: u8,
+        poly: Self,
+        init: Self,
+        refin: bool,
+        refout: bool,
+        xorout: Self,
+    ) -> Result<Algorithm<Self>, ParamError>;
+}
+
+macro_rules! crc_width_impl {
+    ( $( $t:ty ),* ) => ($(
+        impl sealed::Sealed for $t {}
+
+        impl CrcWidth for $t {
+            type Bytes = ChecksumBytes<{ mem::size_of::<$t>() }>;
+
+            fn initialize(algorithm: &Algorithm<Self>) -> Self {
+                Algorithm::<$t>::initialize_with_width(algorithm.width, algorithm.init, algorithm.refin)
+            }
+
+            fn create_table(algorithm: &Algorithm<Self>) -> [Self; 256] {
+                Algorithm::<$t>::create_table_with_width(algorithm.width, algorithm.poly, algorithm.refin)
+            }
+
+            fn calc_bytes(algorithm: &Algorithm<Self>, value: Self, bytes: &[u8], table: &[Self; 256]) -> Self {
+                Algorithm::<$t>::calc_bytes_with_values(algorithm.refin, value, bytes, table)
+            }
+
+            fn create_tables<const N: usize>(algorithm: &Algorithm<Self>) -> [[Self; 256]; N] {
+                Algorithm::<$t>::create_tables(algorithm.width, algorithm.poly, algorithm.refin)
+            }
+
+            fn calc_bytes_with_tables<const N: usize>(algorithm: &Algorithm<Self>, value: Self, bytes: &[u8], tables: &[[Self; 256]; N]) -> Self {
+                match <&[[Self; 256]; 8]>::try_from(&tables[..]) {
+                    Ok(tables) => Algorithm::<$t>::calc_bytes_with_words(algorithm.refin, value, bytes, tables),
+                    Err(_) => Algorithm::<$t>::calc_bytes_with_tables(algorithm.refin, value, bytes, tables),
+                }
+            }
+
+            fn create_nibble_table(algorithm: &Algorithm<Self>) -> [Self; 16] {
+                Algorithm::<$t>::create_nibble_table(algorithm.width, algorithm.poly, algorithm.refin)
+            }
+
+            fn calc_bytes_with_nibble_table(algorithm: &Algorithm<Self>, value: Self, bytes: &[u8], table: &[Self; 16]) -> Self {
+                Algorithm::<$t>::calc_bytes_with_nibble_table(algorithm.refin, value, bytes, table)
+            }
+
+            fn calc_bytes_bitwise(algorithm: &Algorithm<Self>, value: Self, bytes: &[u8]) -> Self {
+                Algorithm::<$t>::calc_bytes_bitwise(algorithm.width, algorithm.poly, algorithm.refin, value, bytes)
+            }
+
+            fn xpow8n(algorithm: &Algorithm<Self>, len: usize) -> Self {
+                Algorithm::<$t>::xpow8n(algorithm.width, algorithm.poly, len)
+            }
+
+            fn mul_xpow(algorithm: &Algorithm<Self>, value: Self, xpow: Self) -> Self {
+                Algorithm::<$t>::mul_xpow(algorithm.width, algorithm.poly, algorithm.refin, value, xpow)
+            }
+
+            fn shift_zeros(algorithm: &Algorithm<Self>, value: Self, len: usize) -> Self {
+                Algorithm::<$t>::shift_zeros(algorithm.width, algorithm.poly, algorithm.refin, value, len)
+            }
+
+            fn combine_values(algorithm: &Algorithm<Self>, value1: Self, value2: Self, len2: usize) -> Self {
+                Algorithm::<$t>::combine_values(algorithm.width, algorithm.poly, algorithm.refin, value1, value2, len2)
+            }
+
+            fn combine(algorithm: &Algorithm<Self>, checksum1: Self, checksum2: Self, len2: usize) -> Self {
+                algorithm.combine(checksum1, checksum2, len2)
+            }
+
+            fn patch(algorithm: &Algorithm<Self>, checksum: Self, offset: usize, old_byte: u8, new_byte: u8, len: usize) -> Self {
+                algorithm.patch(checksum, offset, old_byte, new_byte, len)
+            }
+
+            fn prepend_zeros(algorithm: &Algorithm<Self>, checksum: Self, len: usize, zeros: usize) -> Self {
+                algorithm.prepend_zeros(checksum, len, zeros)
+            }
+
+            fn forge(algorithm: &Algorithm<Self>, bytes: &mut [u8], offset: usize, checksum: Self) -> bool {
+                algorithm.forge(bytes, offset, checksum)
+            }
+
+            fn calc_bit(algorithm: &Algorithm<Self>, value: Self, bit: bool) -> Self {
+                Algorithm::<$t>::calc_bit_with_value(algorithm.width, algorithm.poly, algorithm.refin, value, bit)
+            }
+
+            fn optional_reflection(algorithm: &Algorithm<Self>, value: Self) -> Self {
+                Algorithm::<$t>::optional_reflection_with_width(algorithm.width, algorithm.refin, algorithm.refout, value)
+            }
+
+            fn raw_value(algorithm: &Algorithm<Self>, value: Self) -> Self {
+                Algorithm::<$t>::raw_value(algorithm.width, algorithm.refin, value)
+            }
+
+            fn from_raw_value(algorithm: &Algorithm<Self>, raw: Self) -> Self {
+                Algorithm::<$t>::from_raw_value(algorithm.width, algorithm.refin, raw)
+            }
+
+            fn to_hw_register(algorithm: &Algorithm<Self>, value: Self, register: HwRegister) -> Self {
+                algorithm.to_hw_register(value, register)
+            }
+
+            fn from_hw_register(algorithm: &Algorithm<Self>, hw_register: Self, register: HwRegister) -> Self {
+                algorithm.from_hw_register(hw_register, register)
+            }
+
+            fn finalize(algorithm: &Algorithm<Self>, value: Self) -> Self {
+                Algorithm::<$t>::finalize_with_width(algorithm.width, algorithm.refin, algorithm.refout, algorithm.xorout, value)
+            }
+
+            fn unfinalize(algorithm: &Algorithm<Self>, checksum: Self) -> Self {
+                Algorithm::<$t>::unfinalize(algorithm.width, algorithm.refin, algorithm.refout, algorithm.xorout, checksum)
+            }
+
+            fn from_endian_bytes(bytes: &[u8], endian: Endian) -> Self {
+                Algorithm::<$t>::from_endian_bytes(bytes, endian)
+            }
+
+            fn finalize_to_endian_bytes(algorithm: &Algorithm<Self>, value: Self) -> Self::Bytes {
+                Algorithm::<$t>::finalize_to_endian_bytes_with_width(algorithm.endian, algorithm.width, algorithm.refin, algorithm.refout, algorithm.xorout, value)
+            }
+
+            fn residue(algorithm: &Algorithm<Self>) -> Self {
+                Algorithm::<$t>::residue(algorithm.width, algorithm.poly, algorithm.refout, algorithm.xorout)
+            }
+
+            fn try_new(endian: Endian, width: u8, poly: Self, init: Self, refin: bool, refout: bool, xorout: Self) -> Result<Algorithm<Self>, ParamError> {
+                Algorithm::<$t>::try_new(endian, width, poly, init, refin, refout, xorout).map(|(algorithm, _, _)| algorithm)
+            }
+        }
+    )*)
+}
+
+crc_width_impl!(u8, u16, u32, u64, u128);